@@ -4,5 +4,8 @@ pub mod pitch;
 #[cfg(feature = "rhythm")]
 pub mod rhythm;
 
+#[cfg(feature = "midi")]
+pub mod midi;
+
 mod macros;
 pub mod traits;
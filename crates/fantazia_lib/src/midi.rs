@@ -0,0 +1,6 @@
+mod base;
+pub mod err;
+mod read;
+mod write;
+
+pub use base::*;
@@ -0,0 +1,209 @@
+use crate::pitch::edo12::Pitch;
+
+/// Standard MIDI File, holding the header information and all track chunks.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Smf {
+    pub format: Format,
+    pub timing: Timing,
+    pub tracks: Vec<Track>,
+    /// Chunks other than `MThd` and `MTrk`, each with the number of tracks written before it.
+    pub other_chunks: Vec<(usize, RawChunk)>,
+}
+
+impl Smf {
+    pub fn new(format: Format, timing: Timing) -> Self {
+        Smf {
+            format,
+            timing,
+            tracks: Vec::new(),
+            other_chunks: Vec::new(),
+        }
+    }
+}
+
+/// Chunk of an unknown type, kept as it is.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct RawChunk {
+    pub id: [u8; 4],
+    pub data: Vec<u8>,
+}
+
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Format {
+    /// Format 0: a single multi-channel track.
+    SingleTrack = 0,
+    /// Format 1: simultaneous tracks of one sequence.
+    MultiTrack = 1,
+    /// Format 2: independent single-track sequences.
+    MultiSequence = 2,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Timing {
+    /// Metrical time, in ticks per quarter note.
+    TicksPerQuarter(u16),
+    /// Time-code-based time, in ticks per SMPTE frame.
+    Timecode { fps: u8, ticks_per_frame: u8 },
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Timing::TicksPerQuarter(480)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Track {
+    pub events: Vec<TrackEvent>,
+}
+
+impl Track {
+    pub fn new() -> Self {
+        Track { events: Vec::new() }
+    }
+
+    /// Iterates over the events together with their absolute time in ticks.
+    pub fn iter_absolute(&self) -> impl Iterator<Item = (u64, &TrackEvent)> {
+        self.events.iter().scan(0u64, |time, event| {
+            *time += event.delta as u64;
+            Some((*time, event))
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TrackEvent {
+    /// Time since the previous event in ticks.
+    pub delta: u32,
+    pub kind: EventKind,
+    /// Whether the status byte is omitted when writing, provided it is the same as that of the
+    /// previous channel message.
+    pub running_status: bool,
+    /// Widths of variable-length quantities that were not minimally encoded in the file read.
+    pub vlq_widths: VlqWidths,
+}
+
+impl TrackEvent {
+    pub fn new(delta: u32, kind: EventKind) -> Self {
+        TrackEvent {
+            delta,
+            kind,
+            running_status: false,
+            vlq_widths: VlqWidths::default(),
+        }
+    }
+}
+
+/// Number of bytes used to write the variable-length quantities of an event, where `0` stands
+/// for the minimal encoding.
+///
+/// Some files pad these values with leading `0x80` bytes, which is kept so that they are
+/// written back unchanged.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub struct VlqWidths {
+    /// Width of the delta time.
+    pub delta: u8,
+    /// Width of the data length of system exclusive and meta events.
+    pub len: u8,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EventKind {
+    Midi {
+        channel: u8,
+        message: MidiMessage,
+    },
+    /// System exclusive message starting with `F0`. The data excludes the leading `F0` byte.
+    SysEx(Vec<u8>),
+    /// Escape sequence starting with `F7`, used for split system exclusive messages or arbitrary
+    /// bytes.
+    Escape(Vec<u8>),
+    Meta(MetaEvent),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MidiMessage {
+    NoteOff {
+        pitch: Pitch,
+        velocity: u8,
+    },
+    NoteOn {
+        pitch: Pitch,
+        velocity: u8,
+    },
+    PolyPressure {
+        pitch: Pitch,
+        pressure: u8,
+    },
+    ControlChange {
+        controller: u8,
+        value: u8,
+    },
+    ProgramChange(u8),
+    ChannelPressure(u8),
+    /// 14-bit pitch bend value, centered at `0x2000`.
+    PitchBend(u16),
+}
+
+impl MidiMessage {
+    /// Upper nibble of the status byte.
+    pub fn status(&self) -> u8 {
+        use MidiMessage::*;
+        match self {
+            NoteOff { .. } => 0x80,
+            NoteOn { .. } => 0x90,
+            PolyPressure { .. } => 0xa0,
+            ControlChange { .. } => 0xb0,
+            ProgramChange(_) => 0xc0,
+            ChannelPressure(_) => 0xd0,
+            PitchBend(_) => 0xe0,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MetaEvent {
+    /// Text events of types `01` to `0F`, such as track names and lyrics.
+    Text {
+        kind: u8,
+        data: Vec<u8>,
+    },
+    EndOfTrack,
+    /// Microseconds per quarter note.
+    Tempo(u32),
+    TimeSignature {
+        num: u8,
+        /// Denominator as a power of 2.
+        den_log2: u8,
+        clocks_per_click: u8,
+        thirty_seconds_per_quarter: u8,
+    },
+    KeySignature {
+        /// Signed count of sharps (positive) or flats (negative).
+        sharps: i8,
+        minor: bool,
+    },
+    Other {
+        kind: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl MetaEvent {
+    pub const TEMPO: u8 = 0x51;
+    pub const END_OF_TRACK: u8 = 0x2f;
+    pub const TIME_SIGNATURE: u8 = 0x58;
+    pub const KEY_SIGNATURE: u8 = 0x59;
+
+    pub fn kind(&self) -> u8 {
+        use MetaEvent::*;
+        match self {
+            &Text { kind, .. } | &Other { kind, .. } => kind,
+            EndOfTrack => Self::END_OF_TRACK,
+            Tempo(_) => Self::TEMPO,
+            TimeSignature { .. } => Self::TIME_SIGNATURE,
+            KeySignature { .. } => Self::KEY_SIGNATURE,
+        }
+    }
+}
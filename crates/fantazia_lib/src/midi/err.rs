@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+use crate::pitch::edo12::Pitch;
+
+#[derive(Debug, Error)]
+pub enum ReadSmfError {
+    #[error("Unexpected end of input.")]
+    UnexpectedEof,
+    #[error("Invalid chunk type: expected `{expected}`, found `{found}`.")]
+    InvalidChunkType {
+        expected: &'static str,
+        found: String,
+    },
+    #[error("Invalid header length: {0}.")]
+    InvalidHeaderLength(u32),
+    #[error("Invalid file format: {0}.")]
+    InvalidFormat(u16),
+    #[error("Variable-length quantity longer than 4 bytes.")]
+    VlqTooLong,
+    #[error("Running status used without a preceding status byte.")]
+    MissingStatus,
+    #[error("Invalid status byte: `{0:#04x}`.")]
+    InvalidStatus(u8),
+    #[error("Invalid data byte: `{0:#04x}`.")]
+    InvalidDataByte(u8),
+}
+
+#[derive(Debug, Error)]
+pub enum WriteSmfError {
+    #[error("Pitch out of MIDI key range: `{0}`.")]
+    KeyOutOfRange(Pitch),
+    #[error("Value out of range for `{0}`.")]
+    ValueOutOfRange(&'static str),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,256 @@
+use super::{
+    EventKind, Format, MetaEvent, MidiMessage, RawChunk, Smf, Timing, Track, TrackEvent, VlqWidths,
+    err::ReadSmfError, write::vlq_width,
+};
+use crate::pitch::edo12::{Pitch, SpellingPolicy};
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn peek(&self) -> Result<u8, ReadSmfError> {
+        self.bytes
+            .get(self.pos)
+            .copied()
+            .ok_or(ReadSmfError::UnexpectedEof)
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ReadSmfError> {
+        let end = self.pos.checked_add(n).ok_or(ReadSmfError::UnexpectedEof)?;
+        let result = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(ReadSmfError::UnexpectedEof)?;
+        self.pos = end;
+        Ok(result)
+    }
+
+    fn u8(&mut self) -> Result<u8, ReadSmfError> {
+        let result = self.peek()?;
+        self.pos += 1;
+        Ok(result)
+    }
+
+    fn data_byte(&mut self) -> Result<u8, ReadSmfError> {
+        match self.u8()? {
+            b @ 0x80.. => Err(ReadSmfError::InvalidDataByte(b)),
+            b => Ok(b),
+        }
+    }
+
+    fn u16(&mut self) -> Result<u16, ReadSmfError> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, ReadSmfError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// Reads a variable-length quantity, along with its width if it is not minimally encoded
+    /// and `0` otherwise.
+    fn vlq(&mut self) -> Result<(u32, u8), ReadSmfError> {
+        let mut result = 0u32;
+        for width in 1..=4 {
+            let b = self.u8()?;
+            result = (result << 7) | (b & 0x7f) as u32;
+            if b & 0x80 == 0 {
+                let width = if width == vlq_width(result) { 0 } else { width };
+                return Ok((result, width));
+            }
+        }
+        Err(ReadSmfError::VlqTooLong)
+    }
+
+    fn chunk(&mut self, expected: &'static str) -> Result<Reader<'a>, ReadSmfError> {
+        let id = self.take(4)?;
+        if id != expected.as_bytes() {
+            return Err(ReadSmfError::InvalidChunkType {
+                expected,
+                found: String::from_utf8_lossy(id).into_owned(),
+            });
+        }
+        let len = self.u32()? as usize;
+        Ok(Reader::new(self.take(len)?))
+    }
+}
+
+impl Smf {
+    /// Parses a Standard MIDI File, spelling note pitches with sharps.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ReadSmfError> {
+        Self::parse_with_spelling(bytes, SpellingPolicy::Sharps)
+    }
+
+    /// Parses a Standard MIDI File, spelling note pitches according to `policy`.
+    ///
+    /// Chunks other than `MThd` and `MTrk` are kept as [`RawChunk`]s.
+    pub fn parse_with_spelling(bytes: &[u8], policy: SpellingPolicy) -> Result<Self, ReadSmfError> {
+        let mut reader = Reader::new(bytes);
+        let mut header = reader.chunk("MThd")?;
+        if header.bytes.len() != 6 {
+            return Err(ReadSmfError::InvalidHeaderLength(header.bytes.len() as u32));
+        }
+        let format = match header.u16()? {
+            0 => Format::SingleTrack,
+            1 => Format::MultiTrack,
+            2 => Format::MultiSequence,
+            n => return Err(ReadSmfError::InvalidFormat(n)),
+        };
+        let n_tracks = header.u16()?;
+        let division = header.u16()?;
+        let timing = if division & 0x8000 == 0 {
+            Timing::TicksPerQuarter(division)
+        } else {
+            let [fps, ticks_per_frame] = division.to_be_bytes();
+            Timing::Timecode {
+                fps: (fps as i8).unsigned_abs(),
+                ticks_per_frame,
+            }
+        };
+        let mut tracks = Vec::with_capacity(n_tracks as usize);
+        let mut other_chunks = Vec::new();
+        while !reader.is_empty() {
+            if reader.bytes[reader.pos..].starts_with(b"MTrk") {
+                tracks.push(read_track(&mut reader.chunk("MTrk")?, policy)?);
+            } else {
+                let id = reader.take(4)?.try_into().unwrap();
+                let len = reader.u32()? as usize;
+                let data = reader.take(len)?.to_vec();
+                other_chunks.push((tracks.len(), RawChunk { id, data }));
+            }
+        }
+        Ok(Smf {
+            format,
+            timing,
+            tracks,
+            other_chunks,
+        })
+    }
+}
+
+fn read_track(reader: &mut Reader, policy: SpellingPolicy) -> Result<Track, ReadSmfError> {
+    let mut events = Vec::new();
+    let mut status: Option<u8> = None;
+    while !reader.is_empty() {
+        let (delta, delta_width) = reader.vlq()?;
+        let mut len_width = 0;
+        let (kind, running_status) = match reader.peek()? {
+            0x80..=0xef => {
+                let b = reader.u8()?;
+                status = Some(b);
+                (read_midi_event(reader, b, policy)?, false)
+            }
+            0xf0 | 0xf7 => {
+                let b = reader.u8()?;
+                let (len, width) = reader.vlq()?;
+                len_width = width;
+                let data = reader.take(len as usize)?.to_vec();
+                let kind = if b == 0xf0 {
+                    EventKind::SysEx(data)
+                } else {
+                    EventKind::Escape(data)
+                };
+                (kind, false)
+            }
+            0xff => {
+                reader.u8()?;
+                let kind = reader.data_byte()?;
+                let (len, width) = reader.vlq()?;
+                len_width = width;
+                let data = reader.take(len as usize)?;
+                (EventKind::Meta(read_meta_event(kind, data)), false)
+            }
+            b @ 0xf1..=0xfe => return Err(ReadSmfError::InvalidStatus(b)),
+            _ => {
+                let b = status.ok_or(ReadSmfError::MissingStatus)?;
+                (read_midi_event(reader, b, policy)?, true)
+            }
+        };
+        events.push(TrackEvent {
+            delta,
+            kind,
+            running_status,
+            vlq_widths: VlqWidths {
+                delta: delta_width,
+                len: len_width,
+            },
+        });
+    }
+    Ok(Track { events })
+}
+
+fn read_midi_event(
+    reader: &mut Reader,
+    status: u8,
+    policy: SpellingPolicy,
+) -> Result<EventKind, ReadSmfError> {
+    use MidiMessage::*;
+    let pitch = |key: u8| Pitch::from_midi_key(key, policy).unwrap();
+    let message = match status & 0xf0 {
+        0x80 => NoteOff {
+            pitch: pitch(reader.data_byte()?),
+            velocity: reader.data_byte()?,
+        },
+        0x90 => NoteOn {
+            pitch: pitch(reader.data_byte()?),
+            velocity: reader.data_byte()?,
+        },
+        0xa0 => PolyPressure {
+            pitch: pitch(reader.data_byte()?),
+            pressure: reader.data_byte()?,
+        },
+        0xb0 => ControlChange {
+            controller: reader.data_byte()?,
+            value: reader.data_byte()?,
+        },
+        0xc0 => ProgramChange(reader.data_byte()?),
+        0xd0 => ChannelPressure(reader.data_byte()?),
+        _ => {
+            let lsb = reader.data_byte()? as u16;
+            let msb = reader.data_byte()? as u16;
+            PitchBend(msb << 7 | lsb)
+        }
+    };
+    Ok(EventKind::Midi {
+        channel: status & 0x0f,
+        message,
+    })
+}
+
+fn read_meta_event(kind: u8, data: &[u8]) -> MetaEvent {
+    use MetaEvent::*;
+    match (kind, data) {
+        (0x01..=0x0f, _) => Text {
+            kind,
+            data: data.to_vec(),
+        },
+        (MetaEvent::END_OF_TRACK, []) => EndOfTrack,
+        (MetaEvent::TEMPO, &[a, b, c]) => Tempo(u32::from_be_bytes([0, a, b, c])),
+        (
+            MetaEvent::TIME_SIGNATURE,
+            &[num, den_log2, clocks_per_click, thirty_seconds_per_quarter],
+        ) => TimeSignature {
+            num,
+            den_log2,
+            clocks_per_click,
+            thirty_seconds_per_quarter,
+        },
+        (MetaEvent::KEY_SIGNATURE, &[sharps, minor @ (0 | 1)]) => KeySignature {
+            sharps: sharps as i8,
+            minor: minor == 1,
+        },
+        _ => Other {
+            kind,
+            data: data.to_vec(),
+        },
+    }
+}
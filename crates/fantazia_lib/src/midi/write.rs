@@ -0,0 +1,201 @@
+use std::io::Write;
+
+use super::{EventKind, MetaEvent, MidiMessage, Smf, Timing, Track, err::WriteSmfError};
+use crate::pitch::edo12::Pitch;
+
+const MAX_VLQ: u32 = 0x0fff_ffff;
+
+/// Number of bytes in the minimal encoding of `value` as a variable-length quantity.
+pub(crate) fn vlq_width(value: u32) -> u8 {
+    match value {
+        0..0x80 => 1,
+        0x80..0x4000 => 2,
+        0x4000..0x20_0000 => 3,
+        _ => 4,
+    }
+}
+
+/// Pushes `value` as a variable-length quantity of `width` bytes, padded with leading `0x80`
+/// bytes, or minimally encoded if `width` is too small for it (e.g. `0`).
+fn push_vlq(buf: &mut Vec<u8>, value: u32, width: u8) -> Result<(), WriteSmfError> {
+    if value > MAX_VLQ || width > 4 {
+        return Err(WriteSmfError::ValueOutOfRange("variable-length quantity"));
+    }
+    let width = width.max(vlq_width(value));
+    let mut shift = 7 * (width as u32 - 1);
+    while shift > 0 {
+        buf.push(0x80 | (value >> shift) as u8 & 0x7f);
+        shift -= 7;
+    }
+    buf.push(value as u8 & 0x7f);
+    Ok(())
+}
+
+fn push_data_byte(buf: &mut Vec<u8>, value: u8, name: &'static str) -> Result<(), WriteSmfError> {
+    if value >= 0x80 {
+        return Err(WriteSmfError::ValueOutOfRange(name));
+    }
+    buf.push(value);
+    Ok(())
+}
+
+fn push_key(buf: &mut Vec<u8>, pitch: Pitch) -> Result<(), WriteSmfError> {
+    buf.push(
+        pitch
+            .midi_key()
+            .ok_or(WriteSmfError::KeyOutOfRange(pitch))?,
+    );
+    Ok(())
+}
+
+fn push_with_len(buf: &mut Vec<u8>, data: &[u8], width: u8) -> Result<(), WriteSmfError> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| WriteSmfError::ValueOutOfRange("variable-length quantity"))?;
+    push_vlq(buf, len, width)?;
+    buf.extend_from_slice(data);
+    Ok(())
+}
+
+fn push_midi_message(buf: &mut Vec<u8>, message: &MidiMessage) -> Result<(), WriteSmfError> {
+    use MidiMessage::*;
+    match *message {
+        NoteOff { pitch, velocity } | NoteOn { pitch, velocity } => {
+            push_key(buf, pitch)?;
+            push_data_byte(buf, velocity, "velocity")
+        }
+        PolyPressure { pitch, pressure } => {
+            push_key(buf, pitch)?;
+            push_data_byte(buf, pressure, "pressure")
+        }
+        ControlChange { controller, value } => {
+            push_data_byte(buf, controller, "controller")?;
+            push_data_byte(buf, value, "controller value")
+        }
+        ProgramChange(program) => push_data_byte(buf, program, "program"),
+        ChannelPressure(pressure) => push_data_byte(buf, pressure, "pressure"),
+        PitchBend(value) => {
+            if value >= 0x4000 {
+                return Err(WriteSmfError::ValueOutOfRange("pitch bend"));
+            }
+            buf.push(value as u8 & 0x7f);
+            buf.push((value >> 7) as u8);
+            Ok(())
+        }
+    }
+}
+
+fn push_meta_event(buf: &mut Vec<u8>, event: &MetaEvent, width: u8) -> Result<(), WriteSmfError> {
+    use MetaEvent::*;
+    buf.push(0xff);
+    push_data_byte(buf, event.kind(), "meta event type")?;
+    match event {
+        Text { data, .. } | Other { data, .. } => push_with_len(buf, data, width),
+        EndOfTrack => push_with_len(buf, &[], width),
+        &Tempo(tempo) => {
+            if tempo >= 1 << 24 {
+                return Err(WriteSmfError::ValueOutOfRange("tempo"));
+            }
+            push_with_len(buf, &tempo.to_be_bytes()[1..], width)
+        }
+        &TimeSignature {
+            num,
+            den_log2,
+            clocks_per_click,
+            thirty_seconds_per_quarter,
+        } => push_with_len(
+            buf,
+            &[num, den_log2, clocks_per_click, thirty_seconds_per_quarter],
+            width,
+        ),
+        &KeySignature { sharps, minor } => push_with_len(buf, &[sharps as u8, minor as u8], width),
+    }
+}
+
+fn track_bytes(track: &Track) -> Result<Vec<u8>, WriteSmfError> {
+    let mut buf = Vec::new();
+    let mut prev_status: Option<u8> = None;
+    for event in &track.events {
+        let widths = event.vlq_widths;
+        push_vlq(&mut buf, event.delta, widths.delta)?;
+        match &event.kind {
+            EventKind::Midi { channel, message } => {
+                if *channel >= 0x10 {
+                    return Err(WriteSmfError::ValueOutOfRange("channel"));
+                }
+                let status = message.status() | channel;
+                if !(event.running_status && prev_status == Some(status)) {
+                    buf.push(status);
+                }
+                prev_status = Some(status);
+                push_midi_message(&mut buf, message)?;
+            }
+            EventKind::SysEx(data) => {
+                buf.push(0xf0);
+                push_with_len(&mut buf, data, widths.len)?;
+            }
+            EventKind::Escape(data) => {
+                buf.push(0xf7);
+                push_with_len(&mut buf, data, widths.len)?;
+            }
+            EventKind::Meta(meta) => push_meta_event(&mut buf, meta, widths.len)?,
+        }
+    }
+    Ok(buf)
+}
+
+fn write_chunk<W: Write>(writer: &mut W, id: &[u8; 4], data: &[u8]) -> Result<(), WriteSmfError> {
+    let len =
+        u32::try_from(data.len()).map_err(|_| WriteSmfError::ValueOutOfRange("chunk length"))?;
+    writer.write_all(id)?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(data)?;
+    Ok(())
+}
+
+impl Smf {
+    /// Writes the file to `writer`.
+    ///
+    /// Files read by [`Smf::parse`] are written back byte by byte, including chunks of unknown
+    /// types and variable-length quantities that are not minimally encoded.
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), WriteSmfError> {
+        let n_tracks = u16::try_from(self.tracks.len())
+            .map_err(|_| WriteSmfError::ValueOutOfRange("track count"))?;
+        let division = match self.timing {
+            Timing::TicksPerQuarter(ticks) if ticks < 0x8000 => ticks,
+            Timing::TicksPerQuarter(_) => {
+                return Err(WriteSmfError::ValueOutOfRange("ticks per quarter"));
+            }
+            Timing::Timecode {
+                fps,
+                ticks_per_frame,
+            } => {
+                if !(1..=0x7f).contains(&fps) {
+                    return Err(WriteSmfError::ValueOutOfRange("frames per second"));
+                }
+                u16::from_be_bytes([(fps as i8).wrapping_neg() as u8, ticks_per_frame])
+            }
+        };
+        writer.write_all(b"MThd")?;
+        writer.write_all(&6u32.to_be_bytes())?;
+        writer.write_all(&(self.format as u16).to_be_bytes())?;
+        writer.write_all(&n_tracks.to_be_bytes())?;
+        writer.write_all(&division.to_be_bytes())?;
+        let mut other_chunks = self.other_chunks.iter().peekable();
+        for (i, track) in self.tracks.iter().enumerate() {
+            while let Some((_, chunk)) = other_chunks.next_if(|(pos, _)| *pos <= i) {
+                write_chunk(&mut writer, &chunk.id, &chunk.data)?;
+            }
+            write_chunk(&mut writer, b"MTrk", &track_bytes(track)?)?;
+        }
+        for (_, chunk) in other_chunks {
+            write_chunk(&mut writer, &chunk.id, &chunk.data)?;
+        }
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, WriteSmfError> {
+        let mut buf = Vec::new();
+        self.write(&mut buf)?;
+        Ok(buf)
+    }
+}
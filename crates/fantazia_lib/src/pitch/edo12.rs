@@ -1,17 +1,42 @@
 mod arith;
 mod base;
+mod chord;
 mod cmp;
 mod co5;
 mod constants;
+mod enharmonic;
 mod interval;
+mod key;
+mod midi_key;
+pub mod naming;
+pub mod notation;
 mod parsing;
+mod pcset;
 mod qual;
 mod repr;
+mod roman;
+mod scale;
+mod solfege;
+mod spelling;
+mod tone_row;
 pub mod traits;
+mod transpose;
+mod tuning;
 pub mod utils;
 
 #[cfg(feature = "proc-macro-support")]
 mod tokenize;
 
 pub use base::*;
+pub use chord::*;
 pub use interval::*;
+pub use key::*;
+pub use midi_key::*;
+pub use pcset::*;
+pub use roman::*;
+pub use scale::*;
+pub use solfege::*;
+pub use spelling::*;
+pub use tone_row::*;
+pub use transpose::*;
+pub use tuning::*;
@@ -1,11 +1,14 @@
-use std::{iter::Sum, ops::Add};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
 
 use malachite_base::num::{
     arithmetic::traits::{CheckedAdd, ModAdd},
     basic::traits::Zero as _,
 };
 
-use super::super::{OInterval, OIntervalDeg, OPitch, OStep};
+use super::super::{Interval, OInterval, OIntervalDeg, OPitch, OStep, Pitch};
 use crate::{impl_add_assign_by_add, impl_sum_bisect, pitch::edo12::Acci};
 
 macro_rules! impl_add_by_mod {
@@ -29,7 +32,7 @@ impl Add for OPitch {
     fn add(self, rhs: Self) -> Self::Output {
         let mut step = self.step as u8 + rhs.step as u8;
         let mut tone = self.tone + rhs.tone;
-        if step > 7 {
+        if step >= 7 {
             tone -= 12;
             step -= 7;
         }
@@ -65,6 +68,22 @@ impl Add<Acci> for OPitch {
     }
 }
 
+impl Add<OInterval> for OPitch {
+    type Output = Self;
+
+    fn add(self, rhs: OInterval) -> Self::Output {
+        self + OPitch::from(rhs)
+    }
+}
+
+impl Add<Interval> for Pitch {
+    type Output = Self;
+
+    fn add(self, rhs: Interval) -> Self::Output {
+        self + Pitch::from(rhs)
+    }
+}
+
 impl Add for OInterval {
     type Output = Self;
 
@@ -95,7 +114,7 @@ impl CheckedAdd for OPitch {
     fn checked_add(self, rhs: Self) -> Option<Self::Output> {
         let mut step = self.step as u8 + rhs.step as u8;
         let mut tone = self.tone.checked_add(rhs.tone)?;
-        if step > 7 {
+        if step >= 7 {
             tone = tone.checked_sub(12)?;
             step -= 7;
         }
@@ -132,6 +151,14 @@ impl CheckedAdd<Acci> for OPitch {
     }
 }
 
+impl CheckedAdd<OInterval> for OPitch {
+    type Output = Self;
+
+    fn checked_add(self, other: OInterval) -> Option<Self::Output> {
+        self.checked_add(OPitch::from(other))
+    }
+}
+
 impl CheckedAdd for OInterval {
     type Output = Self;
 
@@ -156,5 +183,17 @@ impl Sum for OInterval {
     }
 }
 
+impl AddAssign<OInterval> for OPitch {
+    fn add_assign(&mut self, rhs: OInterval) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Interval> for Pitch {
+    fn add_assign(&mut self, rhs: Interval) {
+        *self = *self + rhs;
+    }
+}
+
 impl_add_assign_by_add!(OPitch, OInterval);
 impl_sum_bisect!(OPitch, OPitch::ZERO);
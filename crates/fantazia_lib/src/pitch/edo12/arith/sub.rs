@@ -1,8 +1,8 @@
-use std::{i8, ops::Sub};
+use std::ops::{Sub, SubAssign};
 
 use malachite_base::num::arithmetic::traits::CheckedSub;
 
-use super::super::{Acci, OInterval, OPitch, OStep};
+use super::super::{Acci, Interval, OInterval, OPitch, OStep, Pitch};
 use crate::impl_sub_assign_by_sub;
 
 impl Sub for OStep {
@@ -53,6 +53,20 @@ impl Sub<Acci> for OPitch {
     }
 }
 
+impl Sub<OInterval> for OPitch {
+    type Output = Self;
+    fn sub(self, rhs: OInterval) -> Self::Output {
+        self - OPitch::from(rhs)
+    }
+}
+
+impl Sub<Interval> for Pitch {
+    type Output = Self;
+    fn sub(self, rhs: Interval) -> Self::Output {
+        self - Pitch::from(rhs)
+    }
+}
+
 impl Sub for OInterval {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
@@ -116,6 +130,13 @@ impl CheckedSub<Acci> for OPitch {
     }
 }
 
+impl CheckedSub<OInterval> for OPitch {
+    type Output = Self;
+    fn checked_sub(self, rhs: OInterval) -> Option<Self::Output> {
+        self.checked_sub(OPitch::from(rhs))
+    }
+}
+
 impl CheckedSub for OInterval {
     type Output = Self;
     fn checked_sub(self, rhs: Self) -> Option<Self::Output> {
@@ -139,4 +160,16 @@ impl CheckedSub<Acci> for OInterval {
     }
 }
 
+impl SubAssign<OInterval> for OPitch {
+    fn sub_assign(&mut self, rhs: OInterval) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<Interval> for Pitch {
+    fn sub_assign(&mut self, rhs: Interval) {
+        *self = *self - rhs;
+    }
+}
+
 impl_sub_assign_by_sub!(OPitch, OInterval);
@@ -150,7 +150,7 @@ impl OPitch {
     }
 
     pub fn acci(&self) -> Acci {
-        (self.tone as i8 - self.step.diatonic_tone() as i8).into()
+        (self.tone - self.step.diatonic_tone()).into()
     }
 
     pub fn is_diatonic(&self) -> bool {
@@ -0,0 +1,6 @@
+mod base;
+mod parsing;
+mod repr;
+
+pub use base::*;
+pub use repr::*;
@@ -0,0 +1,166 @@
+use std::collections::BTreeSet;
+
+use super::super::{OInterval, OIntervalDeg, OPitch, Pitch, Step};
+
+/// Chord given by a root, the intervals of its members above the root and an optional bass.
+///
+/// Extensions beyond the octave are stored as their octave-reduced intervals, e.g. a ninth is
+/// stored as a second.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Chord {
+    pub(crate) root: OPitch,
+    pub(crate) intervals: BTreeSet<OInterval>,
+    pub(crate) bass: Option<OPitch>,
+}
+
+/// Position of a chord member when the chord is stacked in thirds.
+fn tertian_order(interval: &OInterval, has_third: bool) -> u8 {
+    use OIntervalDeg::*;
+    match interval.deg {
+        Unison => 0,
+        Third => 1,
+        Fourth | Second if !has_third => 1,
+        Fifth => 2,
+        Seventh => 3,
+        Second => 4,
+        Fourth => 5,
+        Sixth => 6,
+    }
+}
+
+macro_rules! chord_constructors {
+    ($($(#[$meta:meta])* $name:ident => [$($interval:ident),*]);*$(;)?) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(root: OPitch) -> Self {
+                Self::new(root, [$(OInterval::$interval),*])
+            }
+        )*
+    };
+}
+
+impl Chord {
+    /// Creates a chord in root position. The unison is always included.
+    pub fn new(root: OPitch, intervals: impl IntoIterator<Item = OInterval>) -> Self {
+        let mut intervals: BTreeSet<_> = intervals.into_iter().collect();
+        intervals.insert(OInterval::UNISON);
+        Chord {
+            root,
+            intervals,
+            bass: None,
+        }
+    }
+
+    chord_constructors! {
+        major => [MAJOR_THIRD, PERFECT_FIFTH];
+        minor => [MINOR_THIRD, PERFECT_FIFTH];
+        diminished => [MINOR_THIRD, DIMINISHED_FIFTH];
+        augmented => [MAJOR_THIRD, AUGMENTED_FIFTH];
+        sus2 => [MAJOR_SECOND, PERFECT_FIFTH];
+        sus4 => [PERFECT_FOURTH, PERFECT_FIFTH];
+        major6 => [MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+        minor6 => [MINOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+        dominant7 => [MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH];
+        major7 => [MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SEVENTH];
+        minor7 => [MINOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH];
+        minor_major7 => [MINOR_THIRD, PERFECT_FIFTH, MAJOR_SEVENTH];
+        half_diminished7 => [MINOR_THIRD, DIMINISHED_FIFTH, MINOR_SEVENTH];
+        diminished7 => [MINOR_THIRD, DIMINISHED_FIFTH, DIMINISHED_SEVENTH];
+        augmented7 => [MAJOR_THIRD, AUGMENTED_FIFTH, MINOR_SEVENTH];
+        augmented_major7 => [MAJOR_THIRD, AUGMENTED_FIFTH, MAJOR_SEVENTH];
+        dominant7_sus4 => [PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SEVENTH];
+        dominant9 => [MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH, MAJOR_SECOND];
+        major9 => [MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SEVENTH, MAJOR_SECOND];
+        minor9 => [MINOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH, MAJOR_SECOND];
+        /// Major triad with an added ninth.
+        major_add9 => [MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SECOND];
+        /// Minor triad with an added ninth.
+        minor_add9 => [MINOR_THIRD, PERFECT_FIFTH, MAJOR_SECOND];
+    }
+
+    pub fn root(&self) -> OPitch {
+        self.root
+    }
+
+    /// Lowest sounding pitch of the chord, which is the root unless a bass is specified.
+    pub fn bass(&self) -> OPitch {
+        self.bass.unwrap_or(self.root)
+    }
+
+    pub fn intervals(&self) -> &BTreeSet<OInterval> {
+        &self.intervals
+    }
+
+    pub fn contains(&self, interval: &OInterval) -> bool {
+        self.intervals.contains(interval)
+    }
+
+    pub fn with_interval(mut self, interval: OInterval) -> Self {
+        self.intervals.insert(interval);
+        self
+    }
+
+    pub fn without_interval(mut self, interval: OInterval) -> Self {
+        if interval != OInterval::UNISON {
+            self.intervals.remove(&interval);
+        }
+        self
+    }
+
+    /// Specifies the bass, creating a slash chord if `bass` is not the root.
+    pub fn with_bass(mut self, bass: OPitch) -> Self {
+        self.bass = if bass == self.root { None } else { Some(bass) };
+        self
+    }
+
+    /// Chord members in root position, stacked in thirds.
+    pub fn chord_tones(&self) -> Vec<OPitch> {
+        let has_third = self.intervals.iter().any(|i| i.deg == OIntervalDeg::Third);
+        let mut intervals: Vec<_> = self.intervals.iter().copied().collect();
+        intervals.sort_by_key(|i| (tertian_order(i, has_third), *i));
+        intervals.into_iter().map(|i| self.root + i).collect()
+    }
+
+    /// Index of the bass among the chord members stacked in thirds, i.e. `0` for root position,
+    /// `1` for first inversion and so on. Returns `None` if the bass is not a chord member.
+    pub fn inversion(&self) -> Option<usize> {
+        let bass = self.bass();
+        self.chord_tones().into_iter().position(|p| p == bass)
+    }
+
+    /// Puts the `n`th chord member (counting in thirds from the root) in the bass. Returns `None`
+    /// if the chord has no more than `n` members.
+    pub fn inverted(self, n: usize) -> Option<Self> {
+        let bass = *self.chord_tones().get(n)?;
+        Some(self.with_bass(bass))
+    }
+
+    /// Spelled chord members, starting from the bass and stacked in thirds above the root.
+    ///
+    /// A bass which is not a chord member is prepended.
+    pub fn pitches(&self) -> Vec<OPitch> {
+        let mut tones = self.chord_tones();
+        match self.inversion() {
+            Some(n) => tones.rotate_left(n),
+            None => tones.insert(0, self.bass()),
+        }
+        tones
+    }
+
+    /// Close voicing of the chord in ascending order, with the bass in the given octave.
+    pub fn voicing(&self, octave: i8) -> Vec<Pitch> {
+        let mut prev: Option<Step> = None;
+        self.pitches()
+            .into_iter()
+            .map(|opitch| {
+                let mut pitch = Pitch::from_opitch_and_octave(opitch, octave);
+                if let Some(prev) = prev {
+                    let octaves = (prev.0 - pitch.step.0).div_euclid(7) + 1;
+                    pitch = Pitch::from_opitch_and_octave(opitch, octave + octaves);
+                }
+                prev = Some(pitch.step);
+                pitch
+            })
+            .collect()
+    }
+}
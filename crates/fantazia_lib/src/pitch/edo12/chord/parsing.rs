@@ -0,0 +1,328 @@
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use super::super::{Acci, OInterval, OPitch, OStep};
+use super::Chord;
+
+pub mod err {
+    use thiserror::Error;
+
+    use super::super::super::parsing::err::{ParseAcciError, ParseOStepError};
+
+    #[derive(Debug, Error)]
+    pub enum ParseChordRootError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error(transparent)]
+        InvalidOStep(#[from] ParseOStepError),
+        #[error(transparent)]
+        InvalidAcci(#[from] ParseAcciError),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ParseChordError {
+        #[error("Invalid chord root: {0}")]
+        InvalidRoot(#[from] ParseChordRootError),
+        #[error("Invalid bass: {0}")]
+        InvalidBass(ParseChordRootError),
+        #[error("Unclosed parenthesis in chord symbol.")]
+        UnclosedParen,
+        #[error("Invalid chord suffix: `{0}`.")]
+        InvalidSuffix(String),
+    }
+}
+
+const SHARPS: [char; 2] = ['#', '♯'];
+const FLATS: [char; 2] = ['b', '♭'];
+
+/// Parses a root or bass at the start of `s`, returning the rest of the input.
+///
+/// Accidentals may be written as `#`/`b`, `♯`/`♭` or as in fantazia notation (`+`, `-`, `=` or
+/// `[n]`).
+fn parse_root(s: &str) -> Result<(OPitch, &str), err::ParseChordRootError> {
+    let mut chars = s.char_indices();
+    let (_, first) = chars.next().ok_or(err::ParseChordRootError::EmptyInput)?;
+    let step_len = first.len_utf8();
+    let step: OStep = s[..step_len].parse()?;
+    let s = &s[step_len..];
+    if s.starts_with(['+', '-', '=', '[']) {
+        let len = if s.starts_with('[') {
+            s.find(']').map_or(s.len(), |idx| idx + 1)
+        } else {
+            s.find(|ch| !matches!(ch, '+' | '-' | '='))
+                .unwrap_or(s.len())
+        };
+        let acci: Acci = s[..len].parse()?;
+        return Ok((OPitch::new(step, acci), &s[len..]));
+    }
+    let mut acci = 0i8;
+    let mut len = 0;
+    for ch in s.chars() {
+        if SHARPS.contains(&ch) {
+            acci += 1;
+        } else if FLATS.contains(&ch) {
+            acci -= 1;
+        } else {
+            break;
+        }
+        len += ch.len_utf8();
+    }
+    Ok((OPitch::new(step, Acci(acci)), &s[len..]))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Quality {
+    Major,
+    Minor,
+    Diminished,
+    HalfDiminished,
+    Augmented,
+}
+
+enum Item {
+    Sus(u8),
+    Add(OInterval),
+    Alter(i8, u8),
+    No(u8),
+    MajorSeventh,
+}
+
+fn strip_any<'a>(s: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes.iter().find_map(|prefix| s.strip_prefix(prefix))
+}
+
+fn strip_number(s: &str) -> Option<(u8, &str)> {
+    let len = s.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(s.len());
+    let n = s[..len].parse().ok()?;
+    Some((n, &s[len..]))
+}
+
+fn interval_by_number(n: u8) -> Option<OInterval> {
+    match n {
+        2 | 9 => Some(OInterval::MAJOR_SECOND),
+        4 | 11 => Some(OInterval::PERFECT_FOURTH),
+        6 | 13 => Some(OInterval::MAJOR_SIXTH),
+        _ => None,
+    }
+}
+
+fn parse_item(s: &str) -> Option<(Item, &str)> {
+    if let Some(s) = s.strip_prefix("sus") {
+        return match s.strip_prefix(['2', '4']) {
+            Some(rest) => Some((Item::Sus(s.as_bytes()[0] - b'0'), rest)),
+            None => Some((Item::Sus(4), s)),
+        };
+    }
+    if let Some(s) = s.strip_prefix("add") {
+        if let Some((n, rest)) = strip_number(s) {
+            return Some((Item::Add(interval_by_number(n)?), rest));
+        }
+        let len = s.find([',', ' ', ')', '/']).unwrap_or(s.len());
+        let interval: OInterval = s[..len].parse().ok()?;
+        return Some((Item::Add(interval), &s[len..]));
+    }
+    if let Some(s) = strip_any(s, &["no", "omit"]) {
+        let (n, rest) = strip_number(s)?;
+        return matches!(n, 3 | 5).then_some((Item::No(n), rest));
+    }
+    if let Some(s) = strip_any(s, &["maj7", "Maj7", "M7", "Δ7"]) {
+        return Some((Item::MajorSeventh, s));
+    }
+    let acci_len = s
+        .find(|ch| !(SHARPS.contains(&ch) || FLATS.contains(&ch)))
+        .unwrap_or(s.len());
+    let acci = s[..acci_len]
+        .chars()
+        .map(|ch| if SHARPS.contains(&ch) { 1 } else { -1 })
+        .sum::<i8>();
+    let (n, rest) = strip_number(&s[acci_len..])?;
+    if acci_len == 0 {
+        return Some((Item::Add(interval_by_number(n)?), rest));
+    }
+    matches!((acci, n), (-1 | 1, 5 | 9 | 11 | 13) | (-2, 7)).then_some((Item::Alter(acci, n), rest))
+}
+
+fn parse_suffix(s: &str) -> Result<BTreeSet<OInterval>, err::ParseChordError> {
+    use Quality::*;
+    let invalid = || err::ParseChordError::InvalidSuffix(s.to_string());
+    let mut rest = s;
+
+    let mut quality = Major;
+    let mut major_seventh = false;
+    // a lone `Δ` stands for a major seventh chord
+    let mut delta = false;
+    if let Some(s) = strip_any(rest, &["dim", "°"]) {
+        quality = Diminished;
+        rest = s;
+    } else if let Some(s) = strip_any(rest, &["ø", "Ø"]) {
+        quality = HalfDiminished;
+        rest = s;
+    } else if let Some(s) = strip_any(rest, &["aug", "+"]) {
+        quality = Augmented;
+        rest = s;
+    } else if let Some(s) = strip_any(rest, &["min", "mi", "m", "−"])
+        && !rest.starts_with("maj")
+    {
+        quality = Minor;
+        rest = s;
+    }
+    if let Some(s) = strip_any(rest, &["maj", "Maj", "M", "Δ"]) {
+        major_seventh = true;
+        delta = rest.starts_with('Δ');
+        rest = s;
+    }
+
+    let mut number = None;
+    if let Some(s) = strip_any(rest, &["6/9", "69"]) {
+        number = Some(69);
+        rest = s;
+    } else if let Some((n, s)) = strip_number(rest) {
+        if !matches!(n, 5 | 6 | 7 | 9 | 11 | 13) {
+            return Err(invalid());
+        }
+        number = Some(n);
+        rest = s;
+    }
+
+    let mut items = Vec::new();
+    let mut depth = 0;
+    while !rest.is_empty() {
+        if let Some(s) = rest.strip_prefix('(') {
+            depth += 1;
+            rest = s;
+        } else if let Some(s) = rest.strip_prefix(')') {
+            if depth == 0 {
+                return Err(invalid());
+            }
+            depth -= 1;
+            rest = s;
+        } else if let Some(s) = rest.strip_prefix([',', ' ']) {
+            rest = s;
+        } else {
+            let (item, s) = parse_item(rest).ok_or_else(invalid)?;
+            items.push(item);
+            rest = s;
+        }
+    }
+    if depth > 0 {
+        return Err(err::ParseChordError::UnclosedParen);
+    }
+
+    let is_minor = matches!(quality, Minor | Diminished | HalfDiminished);
+    let mut intervals = BTreeSet::from([OInterval::UNISON]);
+    intervals.insert(if is_minor {
+        OInterval::MINOR_THIRD
+    } else {
+        OInterval::MAJOR_THIRD
+    });
+    intervals.insert(match quality {
+        Diminished | HalfDiminished => OInterval::DIMINISHED_FIFTH,
+        Augmented => OInterval::AUGMENTED_FIFTH,
+        _ => OInterval::PERFECT_FIFTH,
+    });
+    let has_seventh = quality == HalfDiminished
+        || matches!(number, Some(7 | 9 | 11 | 13))
+        || (delta && number.is_none());
+    if has_seventh {
+        intervals.insert(match quality {
+            _ if major_seventh => OInterval::MAJOR_SEVENTH,
+            Diminished => OInterval::DIMINISHED_SEVENTH,
+            _ => OInterval::MINOR_SEVENTH,
+        });
+    }
+    let extensions: &[OInterval] = match number {
+        Some(5) => {
+            intervals.remove(&OInterval::MAJOR_THIRD);
+            &[]
+        }
+        Some(6) => &[OInterval::MAJOR_SIXTH],
+        Some(69) => &[OInterval::MAJOR_SIXTH, OInterval::MAJOR_SECOND],
+        Some(9) => &[OInterval::MAJOR_SECOND],
+        Some(11) => &[OInterval::MAJOR_SECOND, OInterval::PERFECT_FOURTH],
+        Some(13) if is_minor => &[
+            OInterval::MAJOR_SECOND,
+            OInterval::PERFECT_FOURTH,
+            OInterval::MAJOR_SIXTH,
+        ],
+        Some(13) => &[OInterval::MAJOR_SECOND, OInterval::MAJOR_SIXTH],
+        _ => &[],
+    };
+    intervals.extend(extensions);
+
+    let mut replace = |removed: &[OInterval], inserted: Option<OInterval>| {
+        for interval in removed {
+            intervals.remove(interval);
+        }
+        intervals.extend(inserted);
+    };
+    const THIRDS: [OInterval; 2] = [OInterval::MAJOR_THIRD, OInterval::MINOR_THIRD];
+    const FIFTHS: [OInterval; 3] = [
+        OInterval::PERFECT_FIFTH,
+        OInterval::DIMINISHED_FIFTH,
+        OInterval::AUGMENTED_FIFTH,
+    ];
+    const SEVENTHS: [OInterval; 3] = [
+        OInterval::MINOR_SEVENTH,
+        OInterval::MAJOR_SEVENTH,
+        OInterval::DIMINISHED_SEVENTH,
+    ];
+    for item in items {
+        match item {
+            Item::Sus(2) => replace(&THIRDS, Some(OInterval::MAJOR_SECOND)),
+            Item::Sus(_) => replace(&THIRDS, Some(OInterval::PERFECT_FOURTH)),
+            Item::Add(interval) => replace(&[], Some(interval)),
+            Item::No(3) => replace(&THIRDS, None),
+            Item::No(_) => replace(&FIFTHS, None),
+            Item::MajorSeventh => replace(&SEVENTHS, Some(OInterval::MAJOR_SEVENTH)),
+            Item::Alter(acci, n) => {
+                let (removed, inserted): (&[OInterval], _) = match (acci, n) {
+                    // only the unaltered degree gives way, so that e.g. `b9` and `#9` coexist
+                    (-1, 5) => (&[OInterval::PERFECT_FIFTH], OInterval::DIMINISHED_FIFTH),
+                    (1, 5) => (&[OInterval::PERFECT_FIFTH], OInterval::AUGMENTED_FIFTH),
+                    (-2, _) => (&SEVENTHS, OInterval::DIMINISHED_SEVENTH),
+                    (-1, 9) => (&[OInterval::MAJOR_SECOND], OInterval::MINOR_SECOND),
+                    (1, 9) => (&[OInterval::MAJOR_SECOND], OInterval::AUGMENTED_SECOND),
+                    (1, 11) => (&[OInterval::PERFECT_FOURTH], OInterval::AUGMENTED_FOURTH),
+                    (-1, 13) => (&[OInterval::MAJOR_SIXTH], OInterval::MINOR_SIXTH),
+                    _ => return Err(invalid()),
+                };
+                replace(removed, Some(inserted));
+            }
+        }
+    }
+    Ok(intervals)
+}
+
+impl FromStr for Chord {
+    type Err = err::ParseChordError;
+
+    /// Parses lead-sheet chord symbols such as `Cmaj7`, `F#m7b5`, `Bb13(#11)`, `G7sus4/D`,
+    /// `C/E` and `Dm(add9)`, as well as the jazz symbols produced by
+    /// [`ChordNotation::Jazz`](super::ChordNotation::Jazz).
+    ///
+    /// A `+` or `-` directly following the root is read as an accidental, so augmented and minor
+    /// chords are written e.g. `Caug` and `Cm`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (root, rest) = parse_root(s)?;
+        let (suffix, bass) = match rest.rfind('/') {
+            Some(idx) => match parse_root(&rest[idx + 1..]) {
+                Ok((bass, "")) => (&rest[..idx], Some(bass)),
+                Ok(_) if rest[..idx].ends_with('6') => (rest, None),
+                Ok(_) => return Err(err::ParseChordError::InvalidSuffix(rest.to_string())),
+                Err(_) if rest[..idx].ends_with('6') => (rest, None),
+                Err(err) => return Err(err::ParseChordError::InvalidBass(err)),
+            },
+            None => (rest, None),
+        };
+        let intervals = parse_suffix(suffix)?;
+        let chord = Chord {
+            root,
+            intervals,
+            bass: None,
+        };
+        Ok(match bass {
+            Some(bass) => chord.with_bass(bass),
+            None => chord,
+        })
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter, Write};
+
+use super::super::{Acci, OInterval, OPitch};
+use super::Chord;
+
+/// Style of chord symbols produced by [`Chord::display_with`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub enum ChordNotation {
+    /// ASCII symbols such as `Bbmaj7`, `F#m7b5`, `Cdim7` and `Caug`.
+    #[default]
+    Plain,
+    /// Jazz symbols such as `B♭Δ7`, `F♯ø7`, `C°7` and `D−7`.
+    ///
+    /// Augmented triads are still written as `Caug`, since `C+` is read as C sharp.
+    Jazz,
+}
+
+impl ChordNotation {
+    fn sharp(self) -> &'static str {
+        match self {
+            ChordNotation::Plain => "#",
+            ChordNotation::Jazz => "♯",
+        }
+    }
+
+    fn flat(self) -> &'static str {
+        match self {
+            ChordNotation::Plain => "b",
+            ChordNotation::Jazz => "♭",
+        }
+    }
+
+    pub(crate) fn fmt_acci(self, acci: Acci, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sym = if acci.0 > 0 {
+            self.sharp()
+        } else {
+            self.flat()
+        };
+        for _ in 0..acci.0.unsigned_abs() {
+            f.write_str(sym)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn fmt_pitch(self, pitch: OPitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", pitch.step)?;
+        self.fmt_acci(pitch.acci(), f)
+    }
+}
+
+/// Chord symbol in a given notation, created by [`Chord::display_with`].
+pub struct ChordDisplay<'a> {
+    chord: &'a Chord,
+    notation: ChordNotation,
+}
+
+impl Chord {
+    pub fn display_with(&self, notation: ChordNotation) -> ChordDisplay<'_> {
+        ChordDisplay {
+            chord: self,
+            notation,
+        }
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.display_with(ChordNotation::Plain).fmt(f)
+    }
+}
+
+impl Display for ChordDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use ChordNotation::*;
+        let notation = self.notation;
+        let chord = self.chord;
+        let mut rest: BTreeSet<OInterval> = chord.intervals.clone();
+        rest.remove(&OInterval::UNISON);
+        let mut take = |interval: OInterval| rest.remove(&interval);
+
+        notation.fmt_pitch(chord.root, f)?;
+
+        let is_power_chord =
+            chord.intervals.len() == 2 && chord.contains(&OInterval::PERFECT_FIFTH);
+        if is_power_chord {
+            f.write_char('5')?;
+            return fmt_bass(chord, notation, f);
+        }
+
+        let third = [OInterval::MAJOR_THIRD, OInterval::MINOR_THIRD]
+            .into_iter()
+            .find(|&i| take(i));
+        let sus = match third {
+            Some(_) => None,
+            None if take(OInterval::PERFECT_FOURTH) => Some('4'),
+            None if take(OInterval::MAJOR_SECOND) => Some('2'),
+            None => None,
+        };
+        let fifth = [
+            OInterval::PERFECT_FIFTH,
+            OInterval::DIMINISHED_FIFTH,
+            OInterval::AUGMENTED_FIFTH,
+        ]
+        .into_iter()
+        .find(|&i| take(i));
+        let seventh = [
+            OInterval::MINOR_SEVENTH,
+            OInterval::MAJOR_SEVENTH,
+            OInterval::DIMINISHED_SEVENTH,
+        ]
+        .into_iter()
+        .find(|&i| take(i));
+        let is_minor = third == Some(OInterval::MINOR_THIRD);
+
+        let mut fifth_alteration: Option<OInterval> = None;
+        let mut extra: Vec<String> = Vec::new();
+        match (third, fifth, seventh) {
+            (Some(OInterval::MINOR_THIRD), Some(OInterval::DIMINISHED_FIFTH), None)
+            | (
+                Some(OInterval::MINOR_THIRD),
+                Some(OInterval::DIMINISHED_FIFTH),
+                Some(OInterval::DIMINISHED_SEVENTH),
+            ) => f.write_str(if notation == Jazz { "°" } else { "dim" })?,
+            (
+                Some(OInterval::MINOR_THIRD),
+                Some(OInterval::DIMINISHED_FIFTH),
+                Some(OInterval::MINOR_SEVENTH),
+            ) if notation == Jazz => f.write_str("ø")?,
+            (Some(OInterval::MAJOR_THIRD), Some(OInterval::AUGMENTED_FIFTH), None) => {
+                f.write_str("aug")?
+            }
+            _ => {
+                if is_minor {
+                    f.write_str(if notation == Jazz { "−" } else { "m" })?;
+                }
+                match fifth {
+                    Some(OInterval::PERFECT_FIFTH) => (),
+                    None => extra.push("no5".to_string()),
+                    alteration => fifth_alteration = alteration,
+                }
+                match seventh {
+                    Some(OInterval::MAJOR_SEVENTH) => f.write_str(match (notation, is_minor) {
+                        (Jazz, _) => "Δ",
+                        (Plain, true) => "Maj",
+                        (Plain, false) => "maj",
+                    })?,
+                    Some(OInterval::DIMINISHED_SEVENTH) => {
+                        extra.push(format!("{0}{0}7", notation.flat()))
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        if seventh.is_some() {
+            let mut number = 7;
+            if take(OInterval::MAJOR_SECOND) {
+                number = 9;
+                let has_eleventh = chord.contains(&OInterval::PERFECT_FOURTH);
+                if chord.contains(&OInterval::MAJOR_SIXTH) && is_minor == has_eleventh {
+                    number = 13;
+                    take(OInterval::MAJOR_SIXTH);
+                    take(OInterval::PERFECT_FOURTH);
+                } else if take(OInterval::PERFECT_FOURTH) {
+                    number = 11;
+                }
+            }
+            write!(f, "{number}")?;
+        } else if take(OInterval::MAJOR_SIXTH) {
+            f.write_char('6')?;
+            if take(OInterval::MAJOR_SECOND) {
+                f.write_str("/9")?;
+            }
+        }
+
+        match fifth_alteration {
+            Some(OInterval::DIMINISHED_FIFTH) => {
+                write!(f, "{}5", notation.flat())?;
+                // both alterations of the fifth, e.g. `C7b5#5`
+                if take(OInterval::AUGMENTED_FIFTH) {
+                    write!(f, "{}5", notation.sharp())?;
+                }
+            }
+            Some(OInterval::AUGMENTED_FIFTH) => write!(f, "{}5", notation.sharp())?,
+            _ => (),
+        }
+        if let Some(n) = sus {
+            write!(f, "sus{n}")?;
+        }
+
+        let mut alterations: Vec<String> = rest
+            .iter()
+            .map(|&interval| match interval {
+                OInterval::MINOR_SECOND => format!("{}9", notation.flat()),
+                OInterval::AUGMENTED_SECOND => format!("{}9", notation.sharp()),
+                OInterval::MAJOR_SECOND => "add9".to_string(),
+                OInterval::PERFECT_FOURTH => "add11".to_string(),
+                OInterval::AUGMENTED_FOURTH => format!("{}11", notation.sharp()),
+                OInterval::AUGMENTED_FIFTH => format!("{}5", notation.sharp()),
+                OInterval::MINOR_SIXTH => format!("{}13", notation.flat()),
+                OInterval::MAJOR_SIXTH => "add13".to_string(),
+                interval => format!("add{interval}"),
+            })
+            .collect();
+        alterations.append(&mut extra);
+        if third.is_none() && sus.is_none() {
+            alterations.push("no3".to_string());
+        }
+        if !alterations.is_empty() {
+            write!(f, "({})", alterations.join(","))?;
+        }
+        fmt_bass(chord, notation, f)
+    }
+}
+
+fn fmt_bass(chord: &Chord, notation: ChordNotation, f: &mut Formatter<'_>) -> std::fmt::Result {
+    if let Some(bass) = chord.bass {
+        f.write_char('/')?;
+        notation.fmt_pitch(bass, f)?;
+    }
+    Ok(())
+}
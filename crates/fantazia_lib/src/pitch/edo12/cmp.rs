@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod cmp;
 mod cmp_abs;
 mod eq_abs;
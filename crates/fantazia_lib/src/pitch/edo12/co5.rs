@@ -16,6 +16,12 @@ impl Co5Order for OStep {
 
 impl FromCo5Order<i8> for OStep {
     fn from_co5_order(co5_order: i8) -> Self {
+        Self::from_co5_order(co5_order as i16)
+    }
+}
+
+impl FromCo5Order<i16> for OStep {
+    fn from_co5_order(co5_order: i16) -> Self {
         (co5_order.mod_op(7) as u8)
             .mod_mul(4, 7)
             .try_into()
@@ -32,8 +38,15 @@ impl Co5Order for OPitch {
 
 impl FromCo5Order<i8> for OPitch {
     fn from_co5_order(co5_order: i8) -> Self {
+        Self::from_co5_order(co5_order as i16)
+    }
+}
+
+/// Wider orders for computations near the ends of the `i8` range, e.g. in theoretical keys.
+impl FromCo5Order<i16> for OPitch {
+    fn from_co5_order(co5_order: i16) -> Self {
         let step = OStep::from_co5_order(co5_order);
-        let tone = step.diatonic_tone() + (co5_order + 1).div_mod(7).0;
+        let tone = step.diatonic_tone() + (co5_order + 1).div_mod(7).0 as i8;
         OPitch { step, tone }
     }
 }
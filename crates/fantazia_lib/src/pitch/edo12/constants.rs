@@ -78,6 +78,85 @@ impl Zero for OStep {
     const ZERO: Self = OStep::C;
 }
 
+impl OInterval {
+    pub const UNISON: Self = OInterval {
+        deg: OIntervalDeg::Unison,
+        qual: IntervalQual::Perfect,
+    };
+    pub const AUGMENTED_UNISON: Self = OInterval {
+        deg: OIntervalDeg::Unison,
+        qual: IntervalQual::Augmented(1),
+    };
+    pub const MINOR_SECOND: Self = OInterval {
+        deg: OIntervalDeg::Second,
+        qual: IntervalQual::Minor,
+    };
+    pub const MAJOR_SECOND: Self = OInterval {
+        deg: OIntervalDeg::Second,
+        qual: IntervalQual::Major,
+    };
+    pub const AUGMENTED_SECOND: Self = OInterval {
+        deg: OIntervalDeg::Second,
+        qual: IntervalQual::Augmented(1),
+    };
+    pub const MINOR_THIRD: Self = OInterval {
+        deg: OIntervalDeg::Third,
+        qual: IntervalQual::Minor,
+    };
+    pub const MAJOR_THIRD: Self = OInterval {
+        deg: OIntervalDeg::Third,
+        qual: IntervalQual::Major,
+    };
+    pub const PERFECT_FOURTH: Self = OInterval {
+        deg: OIntervalDeg::Fourth,
+        qual: IntervalQual::Perfect,
+    };
+    pub const DIMINISHED_FOURTH: Self = OInterval {
+        deg: OIntervalDeg::Fourth,
+        qual: IntervalQual::Diminished(1),
+    };
+    pub const AUGMENTED_FOURTH: Self = OInterval {
+        deg: OIntervalDeg::Fourth,
+        qual: IntervalQual::Augmented(1),
+    };
+    pub const DIMINISHED_FIFTH: Self = OInterval {
+        deg: OIntervalDeg::Fifth,
+        qual: IntervalQual::Diminished(1),
+    };
+    pub const PERFECT_FIFTH: Self = OInterval {
+        deg: OIntervalDeg::Fifth,
+        qual: IntervalQual::Perfect,
+    };
+    pub const AUGMENTED_FIFTH: Self = OInterval {
+        deg: OIntervalDeg::Fifth,
+        qual: IntervalQual::Augmented(1),
+    };
+    pub const MINOR_SIXTH: Self = OInterval {
+        deg: OIntervalDeg::Sixth,
+        qual: IntervalQual::Minor,
+    };
+    pub const MAJOR_SIXTH: Self = OInterval {
+        deg: OIntervalDeg::Sixth,
+        qual: IntervalQual::Major,
+    };
+    pub const AUGMENTED_SIXTH: Self = OInterval {
+        deg: OIntervalDeg::Sixth,
+        qual: IntervalQual::Augmented(1),
+    };
+    pub const DIMINISHED_SEVENTH: Self = OInterval {
+        deg: OIntervalDeg::Seventh,
+        qual: IntervalQual::Diminished(1),
+    };
+    pub const MINOR_SEVENTH: Self = OInterval {
+        deg: OIntervalDeg::Seventh,
+        qual: IntervalQual::Minor,
+    };
+    pub const MAJOR_SEVENTH: Self = OInterval {
+        deg: OIntervalDeg::Seventh,
+        qual: IntervalQual::Major,
+    };
+}
+
 impl Zero for OIntervalDeg {
     const ZERO: Self = OIntervalDeg::Unison;
 }
@@ -0,0 +1,158 @@
+use super::{
+    Interval, IntervalQual, OInterval, OIntervalDeg, OPitch, OStep, Pitch, Step,
+    traits::{AbsQual as _, Co5Order as _, Qual as _},
+};
+
+const OSTEPS: [OStep; 7] = [
+    OStep::C,
+    OStep::D,
+    OStep::E,
+    OStep::F,
+    OStep::G,
+    OStep::A,
+    OStep::B,
+];
+
+/// Number of times a quality is augmented or diminished, `0` for perfect, major and minor.
+fn qual_alteration(qual: IntervalQual) -> u8 {
+    use IntervalQual::*;
+    match qual {
+        Augmented(n) | Diminished(n) => n,
+        _ => 0,
+    }
+}
+
+/// Spelling among `candidates` which minimizes `cost` and then the distance to `co5_order` on
+/// the line of fifths.
+fn simplest_by<T: Copy, K: Ord>(
+    candidates: impl Iterator<Item = T>,
+    co5_order: i8,
+    cost: impl Fn(T) -> K,
+    co5: impl Fn(T) -> i8,
+) -> T {
+    candidates
+        .min_by_key(|&c| (cost(c), (co5(c) as i16 - co5_order as i16).abs()))
+        .unwrap()
+}
+
+impl OPitch {
+    /// Enharmonically equivalent pitch on `step`, using the smallest accidental possible.
+    ///
+    /// Ties between a tritone above and below are resolved towards the flat side, i.e. the
+    /// accidental lies in `-6..6`.
+    pub fn respell_to_step(&self, step: OStep) -> Self {
+        let acci = (self.tone - step.diatonic_tone() + 6).rem_euclid(12) - 6;
+        OPitch::from_step_and_tone(step, step.diatonic_tone() + acci)
+    }
+
+    /// Enharmonically equivalent spellings with at most `max_acci` sharps or flats, one per step
+    /// in the order C to B. `self` is included if its accidental is within the limit.
+    pub fn enharmonics(&self, max_acci: u8) -> impl Iterator<Item = Self> + use<> {
+        let pitch = *self;
+        OSTEPS
+            .into_iter()
+            .map(move |step| pitch.respell_to_step(step))
+            .filter(move |p| p.acci().0.unsigned_abs() <= max_acci)
+    }
+
+    /// Enharmonically equivalent spelling with the fewest sharps or flats. Ties are resolved by
+    /// the distance to `self` on the line of fifths, so that e.g. `C♭♭` becomes `B♭` but `B♯♯`
+    /// becomes `C♯`.
+    pub fn simplest_spelling(&self) -> Self {
+        simplest_by(
+            OSTEPS.into_iter().map(|step| self.respell_to_step(step)),
+            self.co5_order(),
+            |p| p.acci().0.unsigned_abs(),
+            |p| p.co5_order(),
+        )
+    }
+}
+
+impl Pitch {
+    fn with_opitch(&self, opitch: OPitch) -> Self {
+        let octave = (self.tone - opitch.tone).div_euclid(12);
+        Pitch {
+            step: Step::from_ostep_and_octave(opitch.step, octave),
+            tone: self.tone,
+        }
+    }
+
+    /// Enharmonically equivalent pitch on `step`, adjusting the octave as needed, e.g. `B♯3`
+    /// becomes `C4`. See [`OPitch::respell_to_step`].
+    pub fn respell_to_step(&self, step: OStep) -> Self {
+        self.with_opitch(OPitch::from(*self).respell_to_step(step))
+    }
+
+    /// See [`OPitch::enharmonics`].
+    pub fn enharmonics(&self, max_acci: u8) -> impl Iterator<Item = Self> + use<> {
+        let pitch = *self;
+        OPitch::from(pitch)
+            .enharmonics(max_acci)
+            .map(move |opitch| pitch.with_opitch(opitch))
+    }
+
+    /// See [`OPitch::simplest_spelling`].
+    pub fn simplest_spelling(&self) -> Self {
+        self.with_opitch(OPitch::from(*self).simplest_spelling())
+    }
+}
+
+impl OInterval {
+    /// Enharmonically equivalent interval of degree `deg`, e.g. a diminished fifth for an
+    /// augmented fourth.
+    pub fn respell_to_deg(&self, deg: OIntervalDeg) -> Self {
+        OPitch::from(*self).respell_to_step(deg.into()).into()
+    }
+
+    /// Enharmonically equivalent intervals which are at most `max_alteration` times augmented or
+    /// diminished, one per degree in ascending order.
+    pub fn enharmonics(&self, max_alteration: u8) -> impl Iterator<Item = Self> + use<> {
+        let pitch = OPitch::from(*self);
+        OSTEPS
+            .into_iter()
+            .map(move |step| OInterval::from(pitch.respell_to_step(step)))
+            .filter(move |i| qual_alteration(i.qual) <= max_alteration)
+    }
+
+    /// Enharmonically equivalent interval which is augmented or diminished the fewest times.
+    /// Ties are resolved as in [`OPitch::simplest_spelling`], so an augmented fourth stays
+    /// as it is while a doubly augmented fourth becomes a perfect fifth.
+    pub fn simplest_spelling(&self) -> Self {
+        let pitch = OPitch::from(*self);
+        simplest_by(
+            OSTEPS.into_iter().map(|step| pitch.respell_to_step(step)),
+            pitch.co5_order(),
+            |p| qual_alteration(p.qual()),
+            |p| p.co5_order(),
+        )
+        .into()
+    }
+}
+
+impl Interval {
+    /// Enharmonically equivalent interval whose degree reduces to `deg`, adjusting the octave
+    /// as needed. See [`OInterval::respell_to_deg`].
+    pub fn respell_to_deg(&self, deg: OIntervalDeg) -> Self {
+        Pitch::from(*self).respell_to_step(deg.into()).into()
+    }
+
+    /// See [`OInterval::enharmonics`].
+    pub fn enharmonics(&self, max_alteration: u8) -> impl Iterator<Item = Self> + use<> {
+        Pitch::from(*self)
+            .enharmonics(u8::MAX)
+            .map(Interval::from)
+            .filter(move |i| qual_alteration(i.abs_qual()) <= max_alteration)
+    }
+
+    /// See [`OInterval::simplest_spelling`].
+    pub fn simplest_spelling(&self) -> Self {
+        let pitch = Pitch::from(*self);
+        simplest_by(
+            OSTEPS.into_iter().map(|step| pitch.respell_to_step(step)),
+            OPitch::from(pitch).co5_order(),
+            |p| qual_alteration(Interval::from(p).abs_qual()),
+            |p| OPitch::from(p).co5_order(),
+        )
+        .into()
+    }
+}
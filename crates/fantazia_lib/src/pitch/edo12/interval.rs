@@ -34,7 +34,7 @@ impl_from_mod!(OIntervalDeg, 7, u8; u8, i8, u16, i16, u32, i32, u64, i64, u128,
 
 impl From<OStep> for OIntervalDeg {
     fn from(value: OStep) -> Self {
-        OIntervalDeg::try_from(u8::try_from(value).unwrap()).unwrap()
+        OIntervalDeg::try_from(u8::from(value)).unwrap()
     }
 }
 
@@ -149,6 +149,10 @@ impl OInterval {
         Self::try_from_deg_and_qual(deg, qual).unwrap()
     }
 
+    /// # Safety
+    ///
+    /// `qual` must be a valid quality for `deg`, i.e. perfect (or augmented / diminished) for
+    /// unisons, fourths and fifths and major / minor (or augmented / diminished) otherwise.
     pub const unsafe fn from_deg_and_qual_unchecked(deg: OIntervalDeg, qual: IntervalQual) -> Self {
         OInterval { deg, qual }
     }
@@ -206,6 +210,10 @@ impl Interval {
         }
     }
 
+    /// # Safety
+    ///
+    /// `qual` must be a valid quality for `deg`, i.e. perfect (or augmented / diminished) for
+    /// unisons, fourths and fifths and major / minor (or augmented / diminished) otherwise.
     pub const unsafe fn from_deg_and_qual_unchecked(deg: IntervalDeg, qual: IntervalQual) -> Self {
         Interval { deg, qual }
     }
@@ -0,0 +1,127 @@
+use super::{
+    Acci, Mode, OPitch, OStep, Scale, SpellingPolicy,
+    traits::{Co5Order as _, FromCo5Order as _},
+};
+
+/// Key signature given by a signed count of sharps (positive) or flats (negative).
+///
+/// Counts beyond seven describe theoretical keys, e.g. `KeySignature(8)` has F double sharp.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct KeySignature(pub(crate) i8);
+
+impl KeySignature {
+    pub const fn new(sharps: i8) -> Self {
+        KeySignature(sharps)
+    }
+
+    /// Number of sharps, negative for flats.
+    pub const fn sharps(&self) -> i8 {
+        self.0
+    }
+
+    /// Altered pitches in the order they are written, e.g. F♯ C♯ G♯ or B♭ E♭ A♭.
+    pub fn accidentals(&self) -> Vec<OPitch> {
+        // F sharp and B flat are the first accidentals, the others follow along the line of fifths
+        // computed in `i16`, since the last accidentals of extreme keys lie beyond `i8`
+        let (first, dir): (i16, i16) = if self.0 >= 0 { (6, 1) } else { (-2, -1) };
+        (0..self.0.unsigned_abs() as i16)
+            .map(|i| OPitch::from_co5_order(first + dir * i))
+            .collect()
+    }
+
+    /// Altered steps in the order they are written. A step appears twice if it is altered by a
+    /// double sharp or flat.
+    pub fn altered_steps(&self) -> Vec<OStep> {
+        self.accidentals().into_iter().map(|p| p.step).collect()
+    }
+
+    /// Accidental applied to `step` by the key signature.
+    pub fn acci_of(&self, step: OStep) -> Acci {
+        Acci((self.0 as i16 + 5 - step.co5_order() as i16).div_euclid(7) as i8)
+    }
+
+    /// Pitch on `step` in the key signature.
+    pub fn pitch_of(&self, step: OStep) -> OPitch {
+        OPitch::new(step, self.acci_of(step))
+    }
+
+    /// Picks the spelling of the pitch class of `tone` in the key signature.
+    ///
+    /// Diatonic pitch classes are spelled as in the key signature, the others as the nearest
+    /// chromatic alteration on the line of fifths (see [`SpellingPolicy::Key`]).
+    pub fn spell(&self, tone: i8) -> OPitch {
+        OPitch::from_tone(tone, SpellingPolicy::Key(self.0))
+    }
+
+    /// Key with the given mode using this key signature.
+    pub fn key(&self, mode: Mode) -> Key {
+        let tonic = OPitch::from_co5_order(self.0 as i16 + mode.ostep().co5_order() as i16);
+        Key { tonic, mode }
+    }
+}
+
+impl From<KeySignature> for SpellingPolicy {
+    fn from(value: KeySignature) -> Self {
+        SpellingPolicy::Key(value.0)
+    }
+}
+
+/// Key given by a tonic and a diatonic mode.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Key {
+    pub(crate) tonic: OPitch,
+    pub(crate) mode: Mode,
+}
+
+impl Key {
+    pub const fn new(tonic: OPitch, mode: Mode) -> Self {
+        Key { tonic, mode }
+    }
+
+    pub const fn major(tonic: OPitch) -> Self {
+        Self::new(tonic, Mode::MAJOR)
+    }
+
+    pub const fn minor(tonic: OPitch) -> Self {
+        Self::new(tonic, Mode::MINOR)
+    }
+
+    pub const fn tonic(&self) -> OPitch {
+        self.tonic
+    }
+
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Key signature of the key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tonic is so far out on the line of fifths that the count of sharps or flats
+    /// does not fit in an `i8`.
+    pub fn signature(&self) -> KeySignature {
+        let tonic = self.tonic.step.co5_order() as i16 + self.tonic.acci().0 as i16 * 7;
+        let sharps = tonic - self.mode.ostep().co5_order() as i16;
+        KeySignature(i8::try_from(sharps).expect("key signature out of range"))
+    }
+
+    pub fn scale(&self) -> Scale {
+        Scale::diatonic(self.tonic, self.mode)
+    }
+
+    /// Key in `mode` sharing the key signature, e.g. A minor for C major.
+    pub fn relative(&self, mode: Mode) -> Self {
+        self.signature().key(mode)
+    }
+
+    /// Key in `mode` sharing the tonic, e.g. C minor for C major.
+    pub fn parallel(&self, mode: Mode) -> Self {
+        Self::new(self.tonic, mode)
+    }
+
+    /// Picks the spelling of the pitch class of `tone` in the key.
+    pub fn spell(&self, tone: i8) -> OPitch {
+        self.signature().spell(tone)
+    }
+}
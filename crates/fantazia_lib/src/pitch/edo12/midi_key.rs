@@ -0,0 +1,61 @@
+use super::{OPitch, Pitch, SpellingPolicy};
+
+/// MIDI key number of middle C.
+pub const MIDDLE_C_KEY: u8 = 60;
+
+/// Highest valid MIDI key number.
+pub const MAX_MIDI_KEY: u8 = 127;
+
+impl Pitch {
+    /// MIDI key number of the pitch, taking `Pitch::ZERO` as middle C.
+    ///
+    /// Returns `None` if the pitch lies outside of `0..=127`.
+    pub fn midi_key(&self) -> Option<u8> {
+        self.midi_key_with_middle_c(0)
+    }
+
+    /// MIDI key number of the pitch, taking `C_{middle_c_octave}` as middle C.
+    ///
+    /// Returns `None` if the pitch lies outside of `0..=127`.
+    pub fn midi_key_with_middle_c(&self, middle_c_octave: i8) -> Option<u8> {
+        let key = MIDDLE_C_KEY as i16 + self.tone as i16 - 12 * middle_c_octave as i16;
+        u8::try_from(key).ok().filter(|&key| key <= MAX_MIDI_KEY)
+    }
+
+    /// Spells MIDI key number `key` according to `policy`, taking `Pitch::ZERO` as middle C.
+    ///
+    /// Returns `None` if `key` is greater than 127.
+    pub fn from_midi_key(key: u8, policy: SpellingPolicy) -> Option<Self> {
+        Self::from_midi_key_with_middle_c(key, policy, 0)
+    }
+
+    /// Spells MIDI key number `key` according to `policy`, taking `C_{middle_c_octave}` as
+    /// middle C.
+    ///
+    /// Returns `None` if `key` is greater than 127 or the pitch is out of range.
+    pub fn from_midi_key_with_middle_c(
+        key: u8,
+        policy: SpellingPolicy,
+        middle_c_octave: i8,
+    ) -> Option<Self> {
+        if key > MAX_MIDI_KEY {
+            return None;
+        }
+        let tone = key as i16 - MIDDLE_C_KEY as i16 + 12 * middle_c_octave as i16;
+        Some(Pitch::from_tone(i8::try_from(tone).ok()?, policy))
+    }
+}
+
+impl OPitch {
+    /// MIDI key number of the pitch in the octave starting from middle C.
+    ///
+    /// Returns `None` if the pitch lies outside of `0..=127`.
+    pub fn midi_key(&self) -> Option<u8> {
+        Pitch::from(*self).midi_key()
+    }
+
+    /// Spells the pitch class of MIDI key number `key` according to `policy`.
+    pub fn from_midi_key(key: u8, policy: SpellingPolicy) -> Self {
+        OPitch::from_tone((key % 12) as i8, policy)
+    }
+}
@@ -0,0 +1,237 @@
+//! Localized names of steps, accidentals and pitches, e.g. German `Fis` and `B`, French
+//! `fa dièse` or Japanese `嬰ヘ`.
+
+use std::fmt::{Display, Formatter};
+
+use super::{Acci, OPitch, OStep};
+use err::ParseNameError;
+
+pub mod err {
+    use thiserror::Error;
+
+    use super::NamingSystem;
+
+    #[derive(Debug, Error)]
+    #[error("Invalid {kind} name in {system:?} naming system: `{name}`.")]
+    pub struct ParseNameError {
+        pub(super) kind: &'static str,
+        pub(super) system: NamingSystem,
+        pub(super) name: String,
+    }
+}
+
+/// Convention for naming pitches.
+///
+/// Accidentals with more than three sharps or flats have no localized name and are written as
+/// in [`Acci`]'s `Display`, e.g. `C[+4]`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub enum NamingSystem {
+    /// Letters with English words, e.g. `F sharp` and `B double flat`.
+    #[default]
+    English,
+    /// Letters with `H` for B and the suffixes `-is` and `-es`, e.g. `Fis`, `Es` and `B` for B
+    /// flat.
+    German,
+    /// Lowercase letters with the suffixes `-is` and `-es`, e.g. `cis`, `es` and `bes`.
+    Dutch,
+    /// Fixed-do solfège with French words, e.g. `fa dièse` and `si bémol`.
+    French,
+    /// Fixed-do solfège with Italian words, e.g. `Fa diesis` and `Si bemolle`.
+    Italian,
+    /// Iroha names with prefixed accidentals, e.g. `嬰ヘ` and `変ロ`.
+    Japanese,
+    /// Letters with prefixed accidentals, e.g. `升F` and `降B`.
+    Chinese,
+}
+
+const ALL_STEPS: [OStep; 7] = [
+    OStep::C,
+    OStep::D,
+    OStep::E,
+    OStep::F,
+    OStep::G,
+    OStep::A,
+    OStep::B,
+];
+
+/// Largest number of sharps or flats which has a localized name.
+const MAX_NAMED_ACCI: i8 = 3;
+
+impl NamingSystem {
+    fn step_name(self, step: OStep) -> &'static str {
+        use NamingSystem::*;
+        let idx = step as usize;
+        match self {
+            English | Chinese => ["C", "D", "E", "F", "G", "A", "B"][idx],
+            German => ["C", "D", "E", "F", "G", "A", "H"][idx],
+            Dutch => ["c", "d", "e", "f", "g", "a", "b"][idx],
+            French => ["do", "ré", "mi", "fa", "sol", "la", "si"][idx],
+            Italian => ["Do", "Re", "Mi", "Fa", "Sol", "La", "Si"][idx],
+            Japanese => ["ハ", "ニ", "ホ", "ヘ", "ト", "イ", "ロ"][idx],
+        }
+    }
+
+    /// Name of a single, double or triple sharp (`n > 0`) or flat (`n < 0`), or of the natural
+    /// sign for `n == 0`.
+    fn acci_name(self, n: i8) -> String {
+        use NamingSystem::*;
+        let count = n.unsigned_abs() as usize;
+        let (sharp, flat, natural, multiples): (&str, &str, &str, [&str; 3]) = match self {
+            English => ("sharp", "flat", "natural", ["", "double ", "triple "]),
+            French => ("dièse", "bémol", "bécarre", ["", "double ", "triple "]),
+            Italian => ("diesis", "bemolle", "bequadro", ["", "doppio ", "triplo "]),
+            Japanese => ("嬰", "変", "", ["", "重", "三重"]),
+            Chinese => ("升", "降", "", ["", "重", "三重"]),
+            German | Dutch => return if n > 0 { "is" } else { "es" }.repeat(count),
+        };
+        match n {
+            0 => natural.to_string(),
+            1.. => format!("{}{sharp}", multiples[count - 1]),
+            _ => format!("{}{flat}", multiples[count - 1]),
+        }
+    }
+
+    fn pitch_name(self, pitch: OPitch) -> String {
+        use NamingSystem::*;
+        let acci = pitch.acci().0;
+        let step = self.step_name(pitch.step);
+        if acci == 0 {
+            return step.to_string();
+        }
+        if acci.abs() > MAX_NAMED_ACCI {
+            return format!("{step}{}", pitch.acci());
+        }
+        let acci_name = self.acci_name(acci);
+        match self {
+            English | French | Italian => format!("{step} {acci_name}"),
+            Japanese | Chinese => format!("{acci_name}{step}"),
+            German | Dutch => match (pitch.step, acci) {
+                (OStep::B, -1) if self == German => "B".to_string(),
+                // `Ees` and `Aes` are contracted to `Es` and `As`
+                (OStep::E | OStep::A, ..0) => format!("{step}{}", &acci_name[1..]),
+                _ => format!("{step}{acci_name}"),
+            },
+        }
+    }
+}
+
+/// Compares names ignoring case and the accents of French note names.
+fn name_eq(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| -> String {
+        s.chars()
+            .map(|ch| match ch {
+                'é' | 'è' | 'É' | 'È' => 'e',
+                ch => ch.to_ascii_lowercase(),
+            })
+            .collect()
+    };
+    normalize(a) == normalize(b)
+}
+
+/// Step name, accidental or pitch named in a given naming system, created by `display_in`.
+pub struct NamingDisplay<T> {
+    value: T,
+    system: NamingSystem,
+}
+
+impl Display for NamingDisplay<OStep> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.system.step_name(self.value))
+    }
+}
+
+impl Display for NamingDisplay<Acci> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.value.0.abs() > MAX_NAMED_ACCI {
+            write!(f, "{}", self.value)
+        } else {
+            f.write_str(&self.system.acci_name(self.value.0))
+        }
+    }
+}
+
+impl Display for NamingDisplay<OPitch> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.system.pitch_name(self.value))
+    }
+}
+
+impl OStep {
+    pub fn display_in(self, system: NamingSystem) -> NamingDisplay<Self> {
+        NamingDisplay {
+            value: self,
+            system,
+        }
+    }
+
+    pub fn parse_in(s: &str, system: NamingSystem) -> Result<Self, ParseNameError> {
+        ALL_STEPS
+            .into_iter()
+            .find(|&step| name_eq(s, system.step_name(step)))
+            .ok_or_else(|| ParseNameError {
+                kind: "step",
+                system,
+                name: s.to_string(),
+            })
+    }
+}
+
+impl Acci {
+    pub fn display_in(self, system: NamingSystem) -> NamingDisplay<Self> {
+        NamingDisplay {
+            value: self,
+            system,
+        }
+    }
+
+    /// Parses the name of an accidental. The empty string is read as a natural.
+    pub fn parse_in(s: &str, system: NamingSystem) -> Result<Self, ParseNameError> {
+        if s.is_empty() {
+            return Ok(Acci::NATURAL);
+        }
+        if s.starts_with('[') {
+            return s.parse().map_err(|_| ParseNameError {
+                kind: "accidental",
+                system,
+                name: s.to_string(),
+            });
+        }
+        (-MAX_NAMED_ACCI..=MAX_NAMED_ACCI)
+            .find(|&n| name_eq(s, &system.acci_name(n)))
+            .map(Acci)
+            .ok_or_else(|| ParseNameError {
+                kind: "accidental",
+                system,
+                name: s.to_string(),
+            })
+    }
+}
+
+impl OPitch {
+    pub fn display_in(self, system: NamingSystem) -> NamingDisplay<Self> {
+        NamingDisplay {
+            value: self,
+            system,
+        }
+    }
+
+    pub fn parse_in(s: &str, system: NamingSystem) -> Result<Self, ParseNameError> {
+        let err = || ParseNameError {
+            kind: "pitch",
+            system,
+            name: s.to_string(),
+        };
+        if let Some(idx) = s.find('[') {
+            let step = OStep::parse_in(&s[..idx], system).map_err(|_| err())?;
+            let acci: Acci = s[idx..].parse().map_err(|_| err())?;
+            return Ok(OPitch::new(step, acci));
+        }
+        ALL_STEPS
+            .into_iter()
+            .flat_map(|step| {
+                (-MAX_NAMED_ACCI..=MAX_NAMED_ACCI).map(move |n| OPitch::new(step, Acci(n)))
+            })
+            .find(|&pitch| name_eq(s, &system.pitch_name(pitch)))
+            .ok_or_else(err)
+    }
+}
@@ -0,0 +1,362 @@
+//! Alternative textual notations of pitches, selected by passing a [`PitchNotation`] to
+//! [`Pitch::display_with`] and [`Pitch::parse_with`] (or their [`OPitch`] counterparts).
+
+use std::fmt::{Display, Formatter, Write as _};
+
+use super::{Acci, OPitch, OStep, Pitch, traits::PitchNotation};
+use err::ParseNotationError;
+
+pub mod err {
+    use thiserror::Error;
+
+    use super::super::parsing::err::{ParseOPitchError, ParsePitchError};
+
+    #[derive(Debug, Error)]
+    pub enum ParseNotationError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error("Invalid step name: `{0}`.")]
+        InvalidOStep(char),
+        #[error("Invalid accidental: `{0}`.")]
+        InvalidAcci(String),
+        #[error("Invalid octave: `{0}`.")]
+        InvalidOctave(String),
+        #[error(transparent)]
+        InvalidOPitch(#[from] ParseOPitchError),
+        #[error(transparent)]
+        InvalidPitch(#[from] ParsePitchError),
+    }
+}
+
+/// Octave number of middle C in scientific pitch notation.
+const MIDDLE_C_OCTAVE: i8 = 4;
+
+/// The notation of `Display` and `FromStr`, e.g. `F+_0` for F sharp 4 and `E-` for E flat.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Fantazia;
+
+/// Scientific pitch notation with ASCII accidentals, e.g. `F#4`, `Eb3` and `Bbb-1`.
+///
+/// `x` is accepted as a double sharp when parsing.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Scientific;
+
+/// Scientific pitch notation with Unicode accidentals, e.g. `F♯4`, `E♭3` and `C𝄪5`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Unicode;
+
+/// Helmholtz pitch notation, e.g. `c'` for middle C, `f#''` for F sharp 5 and `Bb,` for B flat 1.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Helmholtz;
+
+/// LilyPond absolute pitch notation with Dutch note names, e.g. `fis''` for F sharp 5 and `es`
+/// for E flat 3.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct LilyPond;
+
+fn split_step(s: &str) -> Result<(OStep, bool, &str), ParseNotationError> {
+    let mut chars = s.chars();
+    let ch = chars.next().ok_or(ParseNotationError::EmptyInput)?;
+    let step = match ch.to_ascii_uppercase() {
+        'C' => OStep::C,
+        'D' => OStep::D,
+        'E' => OStep::E,
+        'F' => OStep::F,
+        'G' => OStep::G,
+        'A' => OStep::A,
+        'B' => OStep::B,
+        _ => return Err(ParseNotationError::InvalidOStep(ch)),
+    };
+    Ok((step, ch.is_ascii_uppercase(), chars.as_str()))
+}
+
+fn fmt_step(step: OStep, lowercase: bool, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let name = step.to_string();
+    f.write_str(&if lowercase {
+        name.to_ascii_lowercase()
+    } else {
+        name
+    })
+}
+
+fn fmt_ascii_acci(acci: Acci, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let sym = if acci.0 > 0 { '#' } else { 'b' };
+    (0..acci.0.unsigned_abs()).try_for_each(|_| f.write_char(sym))
+}
+
+fn parse_ascii_acci(s: &str) -> Result<Acci, ParseNotationError> {
+    s.chars().try_fold(Acci::NATURAL, |acci, ch| match ch {
+        '#' => Ok(Acci(acci.0 + 1)),
+        'x' => Ok(Acci(acci.0 + 2)),
+        'b' => Ok(Acci(acci.0 - 1)),
+        _ => Err(ParseNotationError::InvalidAcci(s.to_string())),
+    })
+}
+
+/// Splits the scientific octave number from the end of `s`.
+fn split_octave_number(s: &str) -> Result<(&str, i8), ParseNotationError> {
+    let idx = s
+        .find(|ch: char| ch.is_ascii_digit() || ch == '-')
+        .ok_or_else(|| ParseNotationError::InvalidOctave(String::new()))?;
+    let octave = s[idx..]
+        .parse::<i8>()
+        .map_err(|_| ParseNotationError::InvalidOctave(s[idx..].to_string()))?;
+    Ok((&s[..idx], octave - MIDDLE_C_OCTAVE))
+}
+
+/// Splits trailing octave marks from `s`, returning the number of apostrophes minus the number of
+/// commas.
+fn split_octave_marks(s: &str) -> Result<(&str, i8), ParseNotationError> {
+    let body = s.trim_end_matches(['\'', ',']);
+    let marks = &s[body.len()..];
+    let count = marks.len() as i8;
+    match marks.chars().next() {
+        None => Ok((body, 0)),
+        Some(ch) if marks.chars().any(|other| other != ch) => {
+            Err(ParseNotationError::InvalidOctave(marks.to_string()))
+        }
+        Some('\'') => Ok((body, count)),
+        Some(_) => Ok((body, -count)),
+    }
+}
+
+fn fmt_octave_marks(n: i8, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let mark = if n > 0 { '\'' } else { ',' };
+    (0..n.unsigned_abs()).try_for_each(|_| f.write_char(mark))
+}
+
+impl PitchNotation for Fantazia {
+    fn fmt_opitch(&self, opitch: OPitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{opitch}")
+    }
+
+    fn fmt_pitch(&self, pitch: Pitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{pitch}")
+    }
+
+    fn parse_opitch(&self, s: &str) -> Result<OPitch, ParseNotationError> {
+        Ok(s.parse()?)
+    }
+
+    fn parse_pitch(&self, s: &str) -> Result<Pitch, ParseNotationError> {
+        Ok(s.parse()?)
+    }
+}
+
+impl PitchNotation for Scientific {
+    fn fmt_opitch(&self, opitch: OPitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_step(opitch.step, false, f)?;
+        fmt_ascii_acci(opitch.acci(), f)
+    }
+
+    fn fmt_pitch(&self, pitch: Pitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (opitch, octave) = pitch.opitch_and_octave();
+        self.fmt_opitch(opitch, f)?;
+        write!(f, "{}", octave + MIDDLE_C_OCTAVE)
+    }
+
+    fn parse_opitch(&self, s: &str) -> Result<OPitch, ParseNotationError> {
+        let (step, _, acci) = split_step(s)?;
+        Ok(OPitch::new(step, parse_ascii_acci(acci)?))
+    }
+
+    fn parse_pitch(&self, s: &str) -> Result<Pitch, ParseNotationError> {
+        let (opitch, octave) = split_octave_number(s)?;
+        Ok(Pitch::from_opitch_and_octave(
+            self.parse_opitch(opitch)?,
+            octave,
+        ))
+    }
+}
+
+impl PitchNotation for Unicode {
+    fn fmt_opitch(&self, opitch: OPitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_step(opitch.step, false, f)?;
+        let n = opitch.acci().0;
+        let (single, double) = if n > 0 {
+            ('♯', '𝄪')
+        } else {
+            ('♭', '𝄫')
+        };
+        let n = n.unsigned_abs();
+        if n % 2 == 1 {
+            f.write_char(single)?;
+        }
+        (0..n / 2).try_for_each(|_| f.write_char(double))
+    }
+
+    fn fmt_pitch(&self, pitch: Pitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (opitch, octave) = pitch.opitch_and_octave();
+        self.fmt_opitch(opitch, f)?;
+        write!(f, "{}", octave + MIDDLE_C_OCTAVE)
+    }
+
+    fn parse_opitch(&self, s: &str) -> Result<OPitch, ParseNotationError> {
+        let (step, _, acci) = split_step(s)?;
+        let acci = if acci == "♮" {
+            Acci::NATURAL
+        } else {
+            acci.chars()
+                .try_fold(Acci::NATURAL, |acci_sum, ch| match ch {
+                    '♯' => Ok(Acci(acci_sum.0 + 1)),
+                    '𝄪' => Ok(Acci(acci_sum.0 + 2)),
+                    '♭' => Ok(Acci(acci_sum.0 - 1)),
+                    '𝄫' => Ok(Acci(acci_sum.0 - 2)),
+                    _ => Err(ParseNotationError::InvalidAcci(acci.to_string())),
+                })?
+        };
+        Ok(OPitch::new(step, acci))
+    }
+
+    fn parse_pitch(&self, s: &str) -> Result<Pitch, ParseNotationError> {
+        let (opitch, octave) = split_octave_number(s)?;
+        Ok(Pitch::from_opitch_and_octave(
+            self.parse_opitch(opitch)?,
+            octave,
+        ))
+    }
+}
+
+impl PitchNotation for Helmholtz {
+    /// Writes the step in lower case, as in the octave starting from C3.
+    fn fmt_opitch(&self, opitch: OPitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_step(opitch.step, true, f)?;
+        fmt_ascii_acci(opitch.acci(), f)
+    }
+
+    fn fmt_pitch(&self, pitch: Pitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (opitch, octave) = pitch.opitch_and_octave();
+        // the small octave (`c` to `b`) lies just below middle C
+        let marks = octave + 1;
+        fmt_step(opitch.step, marks >= 0, f)?;
+        fmt_ascii_acci(opitch.acci(), f)?;
+        fmt_octave_marks(if marks >= 0 { marks } else { marks + 1 }, f)
+    }
+
+    fn parse_opitch(&self, s: &str) -> Result<OPitch, ParseNotationError> {
+        let (step, _, acci) = split_step(s)?;
+        Ok(OPitch::new(step, parse_ascii_acci(acci)?))
+    }
+
+    fn parse_pitch(&self, s: &str) -> Result<Pitch, ParseNotationError> {
+        let (body, marks) = split_octave_marks(s)?;
+        let (step, uppercase, acci) = split_step(body)?;
+        let octave = match (uppercase, marks) {
+            (false, 0..) => marks - 1,
+            (true, ..=0) => marks - 2,
+            _ => {
+                return Err(ParseNotationError::InvalidOctave(
+                    s[body.len()..].to_string(),
+                ));
+            }
+        };
+        let opitch = OPitch::new(step, parse_ascii_acci(acci)?);
+        Ok(Pitch::from_opitch_and_octave(opitch, octave))
+    }
+}
+
+impl PitchNotation for LilyPond {
+    fn fmt_opitch(&self, opitch: OPitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_step(opitch.step, true, f)?;
+        let n = opitch.acci().0;
+        if n > 0 {
+            (0..n).try_for_each(|_| f.write_str("is"))
+        } else if n < 0 {
+            // `ees` and `aes` are contracted to `es` and `as`
+            let mut n = n.unsigned_abs();
+            if matches!(opitch.step, OStep::E | OStep::A) {
+                f.write_char('s')?;
+                n -= 1;
+            }
+            (0..n).try_for_each(|_| f.write_str("es"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn fmt_pitch(&self, pitch: Pitch, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (opitch, octave) = pitch.opitch_and_octave();
+        self.fmt_opitch(opitch, f)?;
+        fmt_octave_marks(octave + 1, f)
+    }
+
+    fn parse_opitch(&self, s: &str) -> Result<OPitch, ParseNotationError> {
+        let (step, uppercase, acci_src) = split_step(s)?;
+        if uppercase {
+            return Err(ParseNotationError::InvalidOStep(s.chars().next().unwrap()));
+        }
+        let mut rest = acci_src;
+        let mut acci = 0;
+        if matches!(step, OStep::E | OStep::A)
+            && let Some(stripped) = rest.strip_prefix('s')
+        {
+            acci -= 1;
+            rest = stripped;
+        }
+        while !rest.is_empty() {
+            if let Some(stripped) = rest.strip_prefix("is") {
+                acci += 1;
+                rest = stripped;
+            } else if let Some(stripped) = rest.strip_prefix("es") {
+                acci -= 1;
+                rest = stripped;
+            } else {
+                return Err(ParseNotationError::InvalidAcci(acci_src.to_string()));
+            }
+        }
+        Ok(OPitch::new(step, Acci(acci)))
+    }
+
+    fn parse_pitch(&self, s: &str) -> Result<Pitch, ParseNotationError> {
+        let (body, marks) = split_octave_marks(s)?;
+        Ok(Pitch::from_opitch_and_octave(
+            self.parse_opitch(body)?,
+            marks - 1,
+        ))
+    }
+}
+
+/// Pitch written in a given notation, created by [`Pitch::display_with`] or
+/// [`OPitch::display_with`].
+pub struct NotationDisplay<'a, T> {
+    value: T,
+    notation: &'a dyn PitchNotation,
+}
+
+impl Display for NotationDisplay<'_, OPitch> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.notation.fmt_opitch(self.value, f)
+    }
+}
+
+impl Display for NotationDisplay<'_, Pitch> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.notation.fmt_pitch(self.value, f)
+    }
+}
+
+impl OPitch {
+    pub fn display_with(self, notation: &dyn PitchNotation) -> NotationDisplay<'_, Self> {
+        NotationDisplay {
+            value: self,
+            notation,
+        }
+    }
+
+    pub fn parse_with(s: &str, notation: &dyn PitchNotation) -> Result<Self, ParseNotationError> {
+        notation.parse_opitch(s)
+    }
+}
+
+impl Pitch {
+    pub fn display_with(self, notation: &dyn PitchNotation) -> NotationDisplay<'_, Self> {
+        NotationDisplay {
+            value: self,
+            notation,
+        }
+    }
+
+    pub fn parse_with(s: &str, notation: &dyn PitchNotation) -> Result<Self, ParseNotationError> {
+        notation.parse_pitch(s)
+    }
+}
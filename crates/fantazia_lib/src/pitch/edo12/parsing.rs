@@ -111,7 +111,7 @@ impl FromStr for OStep {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let key = s.as_uncased();
         STEP_NAMES
-            .get(&key)
+            .get(key)
             .cloned()
             .ok_or_else(|| err::ParseOStepError(s.to_string()))
     }
@@ -121,10 +121,10 @@ impl FromStr for Step {
     type Err = err::ParseStepError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some(idx) = s.find('_') {
-            let ostep = (&s[..idx]).parse::<OStep>()?;
+            let ostep = s[..idx].parse::<OStep>()?;
             let octave_src = &s[idx + 1..];
             let octave: i8 = octave_src.parse()?;
-            Ok(Step(ostep as i8 + octave * 12))
+            Ok(Step::from_ostep_and_octave(ostep, octave))
         } else {
             Ok(s.parse::<OStep>()?.into())
         }
@@ -163,7 +163,7 @@ impl FromStr for OPitch {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.find(|ch: char| !ch.is_ascii_alphabetic()) {
-            Some(idx) => Ok(OPitch::new((&s[..idx]).parse()?, (&s[idx..]).parse()?)),
+            Some(idx) => Ok(OPitch::new(s[..idx].parse()?, s[idx..].parse()?)),
             None => Ok(s.parse::<OStep>()?.into()),
         }
     }
@@ -177,7 +177,7 @@ impl FromStr for OIntervalDeg {
         let deg = OIntervalDeg::try_from(
             deg_plus_1
                 .checked_sub(1)
-                .ok_or_else(|| err::ParseOIntervalDegError::ZeroDegree)?,
+                .ok_or(err::ParseOIntervalDegError::ZeroDegree)?,
         )?;
         Ok(deg)
     }
@@ -190,7 +190,7 @@ impl FromStr for IntervalDeg {
             let odeg_src = &s[..idx];
             let octave_src = &s[idx + 1..];
             let odeg: OIntervalDeg = odeg_src.parse()?;
-            let octave: i8 = i8::from_str_radix(octave_src, 10)?;
+            let octave: i8 = octave_src.parse::<i8>()?;
             Ok(IntervalDeg::from_odeg_and_octave(odeg, octave))
         } else {
             Ok(OIntervalDeg::from_str(s)?.into())
@@ -210,7 +210,7 @@ impl FromStr for IntervalQual {
             "m" => return Ok(Minor),
             s => {
                 let mut chars = s.chars();
-                match chars.next().ok_or_else(|| EmptyInput)? {
+                match chars.next().ok_or(EmptyInput)? {
                     '[' => {
                         if !s.ends_with("]") {
                             return Err(UnclosedBracket);
@@ -219,14 +219,14 @@ impl FromStr for IntervalQual {
                             'A' => {
                                 if let Some('*') = chars.next() {
                                     let n: u8 =
-                                        u8::from_str_radix(chars.dropping_back(1).as_str(), 10)?;
+                                        chars.dropping_back(1).as_str().parse()?;
                                     return Ok(Augmented(n));
                                 }
                             }
                             'd' => {
                                 if let Some('*') = chars.next() {
                                     let n: u8 =
-                                        u8::from_str_radix(chars.dropping_back(1).as_str(), 10)?;
+                                        chars.dropping_back(1).as_str().parse()?;
                                     return Ok(Diminished(n));
                                 }
                             }
@@ -238,9 +238,8 @@ impl FromStr for IntervalQual {
                         while let Some('A') = chars.next() {
                             n += 1;
                         }
-                        match chars.next() {
-                            None => return Ok(Augmented(n)),
-                            _ => (),
+                        if chars.next().is_none() {
+                            return Ok(Augmented(n));
                         }
                     }
                     'd' => {
@@ -248,9 +247,8 @@ impl FromStr for IntervalQual {
                         while let Some('d') = chars.next() {
                             n += 1;
                         }
-                        match chars.next() {
-                            None => return Ok(Diminished(n)),
-                            _ => (),
+                        if chars.next().is_none() {
+                            return Ok(Diminished(n));
                         }
                     }
                     _ => (),
@@ -289,7 +287,7 @@ impl FromStr for Pitch {
             let opitch_src = &s[..idx];
             let octave_src = &s[idx + 1..];
             let opitch: OPitch = opitch_src.parse()?;
-            let octave = i8::from_str_radix(octave_src, 10)?;
+            let octave = octave_src.parse::<i8>()?;
             Ok(Pitch::from_opitch_and_octave(opitch, octave))
         } else {
             Ok(OPitch::from_str(s).map(Pitch::from)?)
@@ -310,7 +308,7 @@ impl Interval {
             true
         };
         let qual: IntervalQual = qual.parse()?;
-        let deg: IntervalDeg = (i8::from_str_radix(deg, 10)? - 1).into();
+        let deg: IntervalDeg = (deg.parse::<i8>()? - 1).into();
         use IntervalQual::*;
         use OIntervalDeg::*;
         let odeg = OIntervalDeg::from(deg);
@@ -328,17 +326,17 @@ impl FromStr for Interval {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.starts_with('+') {
-            let (result, sign) = Interval::from_str_positive(&s[1..])?;
+        if let Some(s) = s.strip_prefix('+') {
+            let (result, sign) = Interval::from_str_positive(s)?;
             if sign { Ok(result) } else { Ok(-result) }
-        } else if s.starts_with('-') {
-            let (result, sign) = Interval::from_str_positive(&s[1..])?;
+        } else if let Some(s) = s.strip_prefix('-') {
+            let (result, sign) = Interval::from_str_positive(s)?;
             if sign { Ok(-result) } else { Ok(result) }
         } else if let Some(idx) = s.find('_') {
             let ointerval_src = &s[..idx];
             let octave_src = &s[idx + 1..];
             let ointerval: OInterval = ointerval_src.parse()?;
-            let octave: i8 = i8::from_str_radix(octave_src, 10)?;
+            let octave: i8 = octave_src.parse::<i8>()?;
             Ok(Interval::from_ointerval_and_octave(ointerval, octave))
         } else {
             let (result, sign) = Interval::from_str_positive(s)?;
@@ -0,0 +1,7 @@
+mod base;
+mod forte;
+pub(crate) mod parsing;
+mod repr;
+
+pub use base::*;
+pub use forte::*;
@@ -0,0 +1,216 @@
+use super::super::{Chord, OPitch, Pitch, Scale};
+
+/// Set of the 12 pitch classes, with C as `0`, stored as a bitset.
+///
+/// Spelling is discarded, so C♯ and D♭ both become `1`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct PcSet(pub(crate) u16);
+
+const MASK: u16 = 0xfff;
+
+impl PcSet {
+    pub const EMPTY: Self = PcSet(0);
+    pub const CHROMATIC: Self = PcSet(MASK);
+
+    /// Creates a set from pitch classes, which are reduced modulo 12.
+    pub fn new(pcs: impl IntoIterator<Item = i8>) -> Self {
+        pcs.into_iter().collect()
+    }
+
+    /// Creates a set from a bitset in which bit `n` stands for pitch class `n`. Bits above the
+    /// 12th are ignored.
+    pub const fn from_bits(bits: u16) -> Self {
+        PcSet(bits & MASK)
+    }
+
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(self, pc: i8) -> bool {
+        self.0 & 1 << pc.rem_euclid(12) != 0
+    }
+
+    pub fn insert(&mut self, pc: i8) {
+        self.0 |= 1 << pc.rem_euclid(12);
+    }
+
+    pub fn remove(&mut self, pc: i8) {
+        self.0 &= !(1 << pc.rem_euclid(12));
+    }
+
+    /// Pitch classes in ascending order.
+    pub fn iter(self) -> impl Iterator<Item = i8> {
+        (0..12).filter(move |&pc| self.contains(pc))
+    }
+
+    /// `Tn`, i.e. every pitch class moved up by `n` semitones.
+    pub const fn transpose(self, n: i8) -> Self {
+        let n = n.rem_euclid(12) as u32;
+        PcSet((self.0 << n | self.0 >> (12 - n)) & MASK)
+    }
+
+    /// `TnI`, i.e. every pitch class `pc` replaced by `n - pc`.
+    pub fn invert(self, n: i8) -> Self {
+        self.iter().map(|pc| n - pc).collect()
+    }
+
+    pub const fn complement(self) -> Self {
+        PcSet(!self.0 & MASK)
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        PcSet(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: Self) -> Self {
+        PcSet(self.0 & other.0)
+    }
+
+    pub const fn is_subset_of(self, other: Self) -> bool {
+        self.0 & other.0 == self.0
+    }
+
+    pub const fn is_superset_of(self, other: Self) -> bool {
+        other.is_subset_of(self)
+    }
+
+    /// Whether some transposition or inversion of `self` is a subset of `other`.
+    pub fn is_abstract_subset_of(self, other: Self) -> bool {
+        (0..12).any(|n| self.transpose(n).is_subset_of(other) || self.invert(n).is_subset_of(other))
+    }
+
+    /// Numbers of unordered pairs of pitch classes at each interval class from 1 to 6.
+    pub fn interval_vector(self) -> [u8; 6] {
+        let mut icv = [0; 6];
+        for a in self.iter() {
+            for b in self.iter().filter(|&b| b > a) {
+                let ic = (b - a).min(12 - b + a);
+                icv[ic as usize - 1] += 1;
+            }
+        }
+        icv
+    }
+
+    /// Normal form with Forte's tie-breaking: the rotation with the smallest span, and then the
+    /// one packed most tightly towards its first pitch class.
+    pub fn normal_form(self) -> Vec<i8> {
+        self.normal_form_by(Packing::Forte)
+    }
+
+    /// Normal form with Rahn's tie-breaking: the rotation with the smallest span, and then the
+    /// one whose intervals from the first pitch class are smallest counting from the end.
+    pub fn normal_form_rahn(self) -> Vec<i8> {
+        self.normal_form_by(Packing::Rahn)
+    }
+
+    /// Prime form as used by Forte, i.e. the more compact of the normal forms of the set and its
+    /// inversion, transposed to start on `0`.
+    pub fn prime_form(self) -> Self {
+        self.prime_form_by(Packing::Forte)
+    }
+
+    /// Prime form as used by Rahn and most software. It differs from Forte's for six set
+    /// classes, e.g. 5-20 is `{0,1,5,6,8}` rather than `{0,1,3,7,8}`.
+    pub fn prime_form_rahn(self) -> Self {
+        self.prime_form_by(Packing::Rahn)
+    }
+
+    /// Whether both sets belong to the same set class, i.e. are related by `Tn` or `TnI`.
+    pub fn is_equivalent_to(self, other: Self) -> bool {
+        self.prime_form() == other.prime_form()
+    }
+
+    /// Whether the sets have the same interval vector without belonging to the same set class.
+    pub fn is_z_related_to(self, other: Self) -> bool {
+        self.interval_vector() == other.interval_vector() && !self.is_equivalent_to(other)
+    }
+
+    fn normal_form_by(self, packing: Packing) -> Vec<i8> {
+        let pcs: Vec<_> = self.iter().collect();
+        (0..pcs.len())
+            .map(|i| {
+                let mut rotation = pcs.clone();
+                rotation.rotate_left(i);
+                rotation
+            })
+            .min_by_key(|rotation| packing.key(rotation))
+            .unwrap_or_default()
+    }
+
+    fn prime_form_by(self, packing: Packing) -> Self {
+        [self, self.invert(0)]
+            .into_iter()
+            .map(|set| {
+                let normal = set.normal_form_by(packing);
+                let first = normal.first().copied().unwrap_or_default();
+                set.transpose(-first)
+            })
+            .min_by_key(|set| packing.key(&set.iter().collect::<Vec<_>>()))
+            .unwrap()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Packing {
+    Forte,
+    Rahn,
+}
+
+impl Packing {
+    /// Sort key of an ordering of pitch classes: the span, followed by the intervals from the
+    /// first pitch class to the others in the order in which they are compared.
+    fn key(self, pcs: &[i8]) -> Vec<i8> {
+        let Some((&first, rest)) = pcs.split_first() else {
+            return Vec::new();
+        };
+        let above = |pc: &i8| (pc - first).rem_euclid(12);
+        let mut key: Vec<_> = rest.iter().map(above).collect();
+        match self {
+            Packing::Forte if !key.is_empty() => key.rotate_right(1),
+            Packing::Forte => {}
+            Packing::Rahn => key.reverse(),
+        }
+        key
+    }
+}
+
+impl FromIterator<i8> for PcSet {
+    fn from_iter<T: IntoIterator<Item = i8>>(iter: T) -> Self {
+        let mut set = PcSet::EMPTY;
+        iter.into_iter().for_each(|pc| set.insert(pc));
+        set
+    }
+}
+
+impl FromIterator<OPitch> for PcSet {
+    fn from_iter<T: IntoIterator<Item = OPitch>>(iter: T) -> Self {
+        iter.into_iter().map(|pitch| pitch.tone).collect()
+    }
+}
+
+impl FromIterator<Pitch> for PcSet {
+    fn from_iter<T: IntoIterator<Item = Pitch>>(iter: T) -> Self {
+        iter.into_iter().map(|pitch| pitch.tone).collect()
+    }
+}
+
+impl From<&Chord> for PcSet {
+    fn from(value: &Chord) -> Self {
+        value.pitches().into_iter().collect()
+    }
+}
+
+impl From<&Scale> for PcSet {
+    fn from(value: &Scale) -> Self {
+        value.pitches().into_iter().collect()
+    }
+}
@@ -0,0 +1,125 @@
+use super::PcSet;
+
+/// Name of a set class in Forte's catalogue, e.g. `4-Z15`: the cardinality, the ordinal number
+/// and whether the class is Z-related to another one.
+///
+/// Forte's list runs from trichords to nonachords. Names of the remaining cardinalities follow
+/// the common extension of the list: `0-1`, `1-1`, `2-1` to `2-6` by interval class, and their
+/// complements `10-1` to `10-6`, `11-1` and `12-1`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct ForteName {
+    pub(crate) cardinality: u8,
+    pub(crate) ordinal: u8,
+    pub(crate) z: bool,
+}
+
+/// Forte prime forms of the set classes with at most six pitch classes, in catalogue order.
+/// Larger set classes share the ordinal numbers of their complements.
+const PRIME_FORMS: [&[&str]; 7] = [
+    &[""],
+    &["0"],
+    &["01", "02", "03", "04", "05", "06"],
+    &[
+        "012", "013", "014", "015", "016", "024", "025", "026", "027", "036", "037", "048",
+    ],
+    &[
+        "0123", "0124", "0134", "0125", "0126", "0127", "0145", "0156", "0167", "0235", "0135",
+        "0236", "0136", "0237", "0146", "0157", "0347", "0147", "0148", "0158", "0246", "0247",
+        "0257", "0248", "0268", "0358", "0258", "0369", "0137",
+    ],
+    &[
+        "01234", "01235", "01245", "01236", "01237", "01256", "01267", "02346", "01246", "01346",
+        "02347", "01356", "01248", "01257", "01268", "01347", "01348", "01457", "01367", "01378",
+        "01458", "01478", "02357", "01357", "02358", "02458", "01358", "02368", "01368", "01468",
+        "01369", "01469", "02468", "02469", "02479", "01247", "03458", "01258",
+    ],
+    &[
+        "012345", "012346", "012356", "012456", "012367", "012567", "012678", "023457", "012357",
+        "013457", "012457", "012467", "013467", "013458", "012458", "014568", "012478", "012578",
+        "013478", "014589", "023468", "012468", "023568", "013468", "013568", "013578", "013469",
+        "013569", "013689", "013679", "013589", "024579", "023579", "013579", "02468T", "012347",
+        "012348", "012378", "023458", "012358", "012368", "012369", "012568", "012569", "023469",
+        "012469", "012479", "012579", "013479", "014679",
+    ],
+];
+
+fn parse_prime_form(s: &str) -> PcSet {
+    s.chars()
+        .map(|ch| match ch {
+            'T' => 10,
+            'E' => 11,
+            _ => ch.to_digit(10).unwrap() as i8,
+        })
+        .collect()
+}
+
+impl ForteName {
+    pub const fn cardinality(&self) -> u8 {
+        self.cardinality
+    }
+
+    pub const fn ordinal(&self) -> u8 {
+        self.ordinal
+    }
+
+    pub const fn is_z(&self) -> bool {
+        self.z
+    }
+
+    /// Forte prime form of the set class. Returns `None` if the name is not in the catalogue.
+    /// The Z flag is not checked.
+    pub fn prime_form(&self) -> Option<PcSet> {
+        let card = self.cardinality as usize;
+        if card > 12 {
+            return None;
+        }
+        let listed = card.min(12 - card);
+        let s = PRIME_FORMS
+            .get(listed)?
+            .get((self.ordinal as usize).checked_sub(1)?)?;
+        let set = parse_prime_form(s);
+        if listed == card {
+            Some(set)
+        } else {
+            Some(set.complement().prime_form())
+        }
+    }
+}
+
+impl PcSet {
+    /// Name of the set class in Forte's catalogue.
+    pub fn forte_name(self) -> Option<ForteName> {
+        let card = self.len();
+        let (listed, prime) = if card <= 6 {
+            (card, self.prime_form())
+        } else {
+            (12 - card, self.complement().prime_form())
+        };
+        let ordinal = PRIME_FORMS[listed]
+            .iter()
+            .position(|&s| parse_prime_form(s) == prime)?;
+        Some(ForteName {
+            cardinality: card as u8,
+            ordinal: ordinal as u8 + 1,
+            z: prime.z_partner().is_some(),
+        })
+    }
+
+    /// Prime form of the set class which has the same interval vector as this set but is not
+    /// related to it by `Tn` or `TnI`, e.g. 4-Z29 for 4-Z15.
+    pub fn z_partner(self) -> Option<PcSet> {
+        let card = self.len();
+        let icv = self.interval_vector();
+        PRIME_FORMS[card.min(12 - card)]
+            .iter()
+            .map(|s| parse_prime_form(s))
+            .map(|prime| {
+                if card <= 6 {
+                    prime
+                } else {
+                    prime.complement().prime_form()
+                }
+            })
+            .find(|&prime| prime.interval_vector() == icv && !prime.is_equivalent_to(self))
+    }
+}
@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use super::{ForteName, PcSet};
+
+pub mod err {
+    use thiserror::Error;
+
+    #[derive(Debug, Error)]
+    pub enum ParsePcSetError {
+        #[error("Invalid pitch class: `{0}`.")]
+        InvalidPc(String),
+        #[error("Unbalanced brackets in pitch-class set: `{0}`.")]
+        UnbalancedBrackets(String),
+    }
+
+    #[derive(Debug, Error)]
+    #[error("Invalid Forte name: `{0}`.")]
+    pub struct ParseForteNameError(pub(super) String);
+}
+
+fn parse_pc(s: &str) -> Result<i8, err::ParsePcSetError> {
+    match s {
+        "T" | "t" | "A" | "a" => Ok(10),
+        "E" | "e" | "B" | "b" => Ok(11),
+        _ => s
+            .parse()
+            .ok()
+            .filter(|pc| (0..12).contains(pc))
+            .ok_or_else(|| err::ParsePcSetError::InvalidPc(s.to_string())),
+    }
+}
+
+/// Parses pitch classes in the order they are written. See [`PcSet::from_str`] for the syntax.
+pub(crate) fn parse_pcs(s: &str) -> Result<Vec<i8>, err::ParsePcSetError> {
+    let trimmed = s.trim();
+    let inner = [('[', ']'), ('{', '}'), ('(', ')')]
+        .into_iter()
+        .find_map(|(open, close)| trimmed.strip_prefix(open)?.strip_suffix(close))
+        .unwrap_or(trimmed);
+    if inner.contains(['[', ']', '{', '}', '(', ')']) {
+        return Err(err::ParsePcSetError::UnbalancedBrackets(s.to_string()));
+    }
+    if inner.contains([',', ' ']) {
+        inner
+            .split([',', ' '])
+            .filter(|pc| !pc.is_empty())
+            .map(parse_pc)
+            .collect()
+    } else {
+        inner
+            .char_indices()
+            .map(|(idx, ch)| parse_pc(&inner[idx..idx + ch.len_utf8()]))
+            .collect()
+    }
+}
+
+impl FromStr for PcSet {
+    type Err = err::ParsePcSetError;
+
+    /// Parses pitch classes separated by commas or spaces, such as `{0, 4, 7}`, or written
+    /// without separators, such as `[014T]`. `T`/`A` stand for 10 and `E`/`B` for 11, and the
+    /// set may be enclosed in brackets, braces or parentheses.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(parse_pcs(s)?.into_iter().collect())
+    }
+}
+
+impl FromStr for ForteName {
+    type Err = err::ParseForteNameError;
+
+    /// Parses names such as `3-11` or `4-Z15`. The name must be in the catalogue, and the `Z`
+    /// must be present exactly for Z-related set classes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || err::ParseForteNameError(s.to_string());
+        let (cardinality, ordinal) = s.split_once('-').ok_or_else(invalid)?;
+        let (z, ordinal) = match ordinal.strip_prefix(['Z', 'z']) {
+            Some(ordinal) => (true, ordinal),
+            None => (false, ordinal),
+        };
+        let name = ForteName {
+            cardinality: cardinality.parse().map_err(|_| invalid())?,
+            ordinal: ordinal.parse().map_err(|_| invalid())?,
+            z,
+        };
+        name.prime_form()
+            .and_then(PcSet::forte_name)
+            .filter(|&found| found == name)
+            .ok_or_else(invalid)
+    }
+}
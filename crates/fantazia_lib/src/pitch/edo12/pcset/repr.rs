@@ -0,0 +1,24 @@
+use std::fmt::{Display, Formatter};
+
+use super::{ForteName, PcSet};
+
+impl Display for PcSet {
+    /// Writes the pitch classes in ascending order, e.g. `[0,1,4,10]`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, pc) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{pc}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl Display for ForteName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let z = if self.z { "Z" } else { "" };
+        write!(f, "{}-{z}{}", self.cardinality, self.ordinal)
+    }
+}
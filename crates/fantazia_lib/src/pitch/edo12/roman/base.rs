@@ -0,0 +1,281 @@
+use super::super::{
+    Acci, Chord, IntervalQual, Key, Mode, OInterval, OIntervalDeg, OPitch, ScaleDegree,
+    traits::{Co5Order as _, FromCo5Order as _, Qual as _},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum TriadQuality {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+}
+
+impl TriadQuality {
+    fn third_and_fifth(self) -> [OInterval; 2] {
+        use TriadQuality::*;
+        match self {
+            Major => [OInterval::MAJOR_THIRD, OInterval::PERFECT_FIFTH],
+            Minor => [OInterval::MINOR_THIRD, OInterval::PERFECT_FIFTH],
+            Diminished => [OInterval::MINOR_THIRD, OInterval::DIMINISHED_FIFTH],
+            Augmented => [OInterval::MAJOR_THIRD, OInterval::AUGMENTED_FIFTH],
+        }
+    }
+
+    fn from_third_and_fifth(third: OInterval, fifth: OInterval) -> Option<Self> {
+        use TriadQuality::*;
+        [Major, Minor, Diminished, Augmented]
+            .into_iter()
+            .find(|q| q.third_and_fifth() == [third, fifth])
+    }
+
+    /// Whether the numeral is written in lower case.
+    pub fn is_lowercase(self) -> bool {
+        matches!(self, TriadQuality::Minor | TriadQuality::Diminished)
+    }
+}
+
+/// Augmented sixth chords, built on the lowered sixth degree of a key.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum AugmentedSixth {
+    Italian,
+    French,
+    German,
+}
+
+impl AugmentedSixth {
+    /// Intervals above the lowered sixth degree, which is the bass.
+    fn intervals(self) -> Vec<OInterval> {
+        use AugmentedSixth::*;
+        let extra = match self {
+            Italian => None,
+            French => Some(OInterval::AUGMENTED_FOURTH),
+            German => Some(OInterval::PERFECT_FIFTH),
+        };
+        [OInterval::MAJOR_THIRD, OInterval::AUGMENTED_SIXTH]
+            .into_iter()
+            .chain(extra)
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Numeral {
+    /// Chord on a scale degree, e.g. `V7`, `bII6` or `viiø7`.
+    ///
+    /// The accidental of `degree` is written relative to the key, except that in minor keys the
+    /// roots of lower-case chords on the seventh degree are raised implicitly, so `vii°` in
+    /// C minor is built on B.
+    Degree {
+        degree: ScaleDegree,
+        quality: TriadQuality,
+        seventh: Option<OInterval>,
+        inversion: u8,
+    },
+    AugmentedSixth(AugmentedSixth),
+    /// Cadential six-four chord, i.e. the tonic triad in second inversion.
+    Cadential64,
+}
+
+/// Key tonicized by an applied chord, given by the scale degree of its tonic and its mode.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Tonicization {
+    pub degree: ScaleDegree,
+    pub minor: bool,
+}
+
+impl Tonicization {
+    fn key_in(&self, key: &Key) -> Key {
+        let mode = if self.minor { Mode::MINOR } else { Mode::MAJOR };
+        Key::new(self.degree.pitch(key), mode)
+    }
+}
+
+/// Roman numeral analysis of a chord in a key, e.g. `V7/V`, `bII6`, `viiø7`, `It+6` or `Cad64`.
+///
+/// A seventh written as `7` is a minor seventh, except in `°7` where it is diminished. Major
+/// sevenths are written as `M7`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RomanNumeral {
+    pub(crate) numeral: Numeral,
+    /// Tonicized keys from the innermost to the outermost, e.g. `[V, V]` for `V7/V/V`.
+    pub(crate) targets: Vec<Tonicization>,
+}
+
+/// Whether a lower-case chord on the seventh degree of `key` has its root raised implicitly.
+fn raises_leading_tone(key: &Key, deg: OIntervalDeg, quality: TriadQuality) -> bool {
+    key.mode == Mode::MINOR && deg == OIntervalDeg::Seventh && quality.is_lowercase()
+}
+
+impl RomanNumeral {
+    pub fn new(numeral: Numeral) -> Self {
+        RomanNumeral {
+            numeral,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Applies the chord to a key, e.g. turns `V7` into `V7/V`. Can be called repeatedly to
+    /// tonicize further, from the innermost key outwards.
+    pub fn applied_to(mut self, target: Tonicization) -> Self {
+        self.targets.push(target);
+        self
+    }
+
+    pub fn numeral(&self) -> Numeral {
+        self.numeral
+    }
+
+    pub fn targets(&self) -> &[Tonicization] {
+        &self.targets
+    }
+
+    /// Key in which the numeral itself is read, following tonicizations from `key`.
+    pub fn local_key(&self, key: &Key) -> Key {
+        self.targets
+            .iter()
+            .rev()
+            .fold(*key, |key, target| target.key_in(&key))
+    }
+
+    /// Spelled chord of the numeral in `key`.
+    pub fn realize(&self, key: &Key) -> Chord {
+        let key = self.local_key(key);
+        match self.numeral {
+            Numeral::Degree {
+                degree,
+                quality,
+                seventh,
+                inversion,
+            } => {
+                let mut root = degree.pitch(&key);
+                if raises_leading_tone(&key, degree.deg, quality) {
+                    root = root + Acci::SHARP;
+                }
+                let chord = Chord::new(root, quality.third_and_fifth().into_iter().chain(seventh));
+                let n = inversion as usize;
+                chord.clone().inverted(n).unwrap_or(chord)
+            }
+            Numeral::AugmentedSixth(kind) => {
+                Chord::new(key.tonic + OInterval::MINOR_SIXTH, kind.intervals())
+            }
+            Numeral::Cadential64 => {
+                let quality = if key.mode == Mode::MINOR {
+                    TriadQuality::Minor
+                } else {
+                    TriadQuality::Major
+                };
+                Chord::new(key.tonic, quality.third_and_fifth())
+                    .inverted(2)
+                    .unwrap()
+            }
+        }
+    }
+
+    /// Analyzes `chord` in `key`.
+    ///
+    /// Chromatic major triads, dominant sevenths and diminished chords are read as applied chords
+    /// when they are the dominant or leading-tone chord of a major or minor triad of the key,
+    /// e.g. D major in C major is `V/V`. Returns `None` for chords which are neither tertian
+    /// triads or seventh chords nor augmented sixth chords.
+    pub fn analyze(chord: &Chord, key: &Key) -> Option<Self> {
+        if let Some(kind) = [
+            AugmentedSixth::Italian,
+            AugmentedSixth::French,
+            AugmentedSixth::German,
+        ]
+        .into_iter()
+        .find(|&kind| Chord::new(key.tonic + OInterval::MINOR_SIXTH, kind.intervals()) == *chord)
+        {
+            return Some(Self::new(Numeral::AugmentedSixth(kind)));
+        }
+
+        let intervals: Vec<_> = chord.intervals.iter().copied().collect();
+        let (quality, seventh) = match intervals[..] {
+            [_, third, fifth] => (TriadQuality::from_third_and_fifth(third, fifth)?, None),
+            [_, third, fifth, seventh] => {
+                let quality = TriadQuality::from_third_and_fifth(third, fifth)?;
+                let valid = match seventh {
+                    OInterval::MINOR_SEVENTH | OInterval::MAJOR_SEVENTH => true,
+                    OInterval::DIMINISHED_SEVENTH => quality == TriadQuality::Diminished,
+                    _ => false,
+                };
+                if !valid {
+                    return None;
+                }
+                (quality, Some(seventh))
+            }
+            _ => return None,
+        };
+        let inversion = chord.inversion()? as u8;
+        let root = chord.root;
+
+        let scale = key.scale();
+        let is_diatonic = |pitch: &OPitch| {
+            scale.contains(pitch)
+                || key.mode == Mode::MINOR && *pitch == key.tonic + OInterval::MAJOR_SEVENTH
+        };
+        if !chord.chord_tones().iter().all(is_diatonic) {
+            let is_dominant = quality == TriadQuality::Major
+                && seventh.is_none_or(|s| s == OInterval::MINOR_SEVENTH);
+            let is_leading_tone = quality == TriadQuality::Diminished;
+            let target = if is_dominant {
+                // a fifth below the root
+                Some(OPitch::from_co5_order(root.co5_order() - 1))
+            } else if is_leading_tone {
+                Some(root + OInterval::MINOR_SECOND)
+            } else {
+                None
+            };
+            if let Some(target) = target
+                && target != key.tonic
+                && scale.contains(&target)
+                && let Some(minor) = diatonic_triad_is_minor(key, target)
+            {
+                let target = Tonicization {
+                    degree: ScaleDegree::of(target, key),
+                    minor,
+                };
+                let local_key = target.key_in(key);
+                return Some(
+                    Self::degree_in(&local_key, root, quality, seventh, inversion)
+                        .applied_to(target),
+                );
+            }
+        }
+        Some(Self::degree_in(key, root, quality, seventh, inversion))
+    }
+
+    fn degree_in(
+        key: &Key,
+        root: OPitch,
+        quality: TriadQuality,
+        seventh: Option<OInterval>,
+        inversion: u8,
+    ) -> Self {
+        let mut degree = ScaleDegree::of(root, key);
+        if raises_leading_tone(key, degree.deg, quality) {
+            degree.acci = Acci(degree.acci.0 - 1);
+        }
+        Self::new(Numeral::Degree {
+            degree,
+            quality,
+            seventh,
+            inversion,
+        })
+    }
+}
+
+/// Whether the diatonic triad on `root` in `key` is minor (`Some(true)`) or major
+/// (`Some(false)`). Returns `None` for diminished and augmented triads.
+fn diatonic_triad_is_minor(key: &Key, root: OPitch) -> Option<bool> {
+    let pitches = key.scale().pitches();
+    let idx = pitches.iter().position(|p| *p == root)?;
+    let third = OInterval::from(pitches[(idx + 2) % 7] - root);
+    let fifth = OInterval::from(pitches[(idx + 4) % 7] - root);
+    match (third.qual(), fifth.qual()) {
+        (IntervalQual::Minor, IntervalQual::Perfect) => Some(true),
+        (IntervalQual::Major, IntervalQual::Perfect) => Some(false),
+        _ => None,
+    }
+}
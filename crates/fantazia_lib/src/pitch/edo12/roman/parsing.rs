@@ -0,0 +1,135 @@
+use std::str::FromStr;
+
+use super::super::{Acci, OInterval, OIntervalDeg, ScaleDegree};
+use super::{AugmentedSixth, Numeral, RomanNumeral, Tonicization, TriadQuality};
+
+pub mod err {
+    use thiserror::Error;
+
+    #[derive(Debug, Error)]
+    pub enum ParseRomanNumeralError {
+        #[error("Invalid roman numeral: `{0}`.")]
+        InvalidNumeral(String),
+        #[error("Invalid figured bass in roman numeral: `{0}`.")]
+        InvalidFigure(String),
+    }
+}
+
+use err::ParseRomanNumeralError::{self, *};
+
+/// Numerals ordered so that no numeral is tried after one of its prefixes.
+const NUMERALS: [(&str, OIntervalDeg); 7] = [
+    ("VII", OIntervalDeg::Seventh),
+    ("III", OIntervalDeg::Third),
+    ("VI", OIntervalDeg::Sixth),
+    ("IV", OIntervalDeg::Fourth),
+    ("II", OIntervalDeg::Second),
+    ("V", OIntervalDeg::Fifth),
+    ("I", OIntervalDeg::Unison),
+];
+
+/// Parses an optionally altered numeral such as `bVI` or `#iv`, returning the scale degree,
+/// whether the numeral is in lower case, and the rest of the string.
+fn parse_degree(s: &str) -> Result<(ScaleDegree, bool, &str), ParseRomanNumeralError> {
+    let numeral_start = s
+        .find(|ch| !matches!(ch, 'b' | '♭' | '#' | '♯'))
+        .ok_or_else(|| InvalidNumeral(s.to_string()))?;
+    let (prefix, rest) = s.split_at(numeral_start);
+    let acci = prefix
+        .chars()
+        .map(|ch| if matches!(ch, '#' | '♯') { 1 } else { -1 })
+        .sum();
+    for (numeral, deg) in NUMERALS {
+        let len = numeral.len();
+        let Some(head) = rest.get(..len) else {
+            continue;
+        };
+        let lowercase = head == numeral.to_ascii_lowercase();
+        if head == numeral || lowercase {
+            return Ok((ScaleDegree::new(deg, Acci(acci)), lowercase, &rest[len..]));
+        }
+    }
+    Err(InvalidNumeral(s.to_string()))
+}
+
+fn parse_special(s: &str) -> Option<Numeral> {
+    use AugmentedSixth::*;
+    Some(match s {
+        "It+6" | "It6" => Numeral::AugmentedSixth(Italian),
+        "Fr+6" | "Fr6" | "Fr43" => Numeral::AugmentedSixth(French),
+        "Ger+6" | "Ger6" | "Ger65" => Numeral::AugmentedSixth(German),
+        "Cad64" => Numeral::Cadential64,
+        _ => return None,
+    })
+}
+
+fn parse_numeral(s: &str) -> Result<Numeral, ParseRomanNumeralError> {
+    if let Some(numeral) = parse_special(s) {
+        return Ok(numeral);
+    }
+    let (degree, lowercase, rest) = parse_degree(s)?;
+    let (mark, rest) = match rest.chars().next() {
+        Some(ch @ ('°' | 'o' | 'ø' | '+')) => (Some(ch), &rest[ch.len_utf8()..]),
+        _ => (None, rest),
+    };
+    let quality = match (mark, lowercase) {
+        (None, false) => TriadQuality::Major,
+        (None, true) => TriadQuality::Minor,
+        (Some('°' | 'o' | 'ø'), true) => TriadQuality::Diminished,
+        (Some('+'), false) => TriadQuality::Augmented,
+        _ => return Err(InvalidNumeral(s.to_string())),
+    };
+    let (major_seventh, figure) = match rest.strip_prefix('M') {
+        Some(figure) => (true, figure),
+        None => (false, rest),
+    };
+    let half_diminished = mark == Some('ø');
+    let (seventh, inversion) = match figure {
+        "" if half_diminished => (true, 0),
+        "" => (false, 0),
+        "6" => (false, 1),
+        "64" => (false, 2),
+        "7" => (true, 0),
+        "65" => (true, 1),
+        "43" => (true, 2),
+        "42" | "2" => (true, 3),
+        _ => return Err(InvalidFigure(figure.to_string())),
+    };
+    if major_seventh && (!seventh || half_diminished) {
+        return Err(InvalidFigure(rest.to_string()));
+    }
+    let seventh = seventh.then_some(if major_seventh {
+        OInterval::MAJOR_SEVENTH
+    } else if quality == TriadQuality::Diminished && !half_diminished {
+        OInterval::DIMINISHED_SEVENTH
+    } else {
+        OInterval::MINOR_SEVENTH
+    });
+    Ok(Numeral::Degree {
+        degree,
+        quality,
+        seventh,
+        inversion,
+    })
+}
+
+fn parse_target(s: &str) -> Result<Tonicization, ParseRomanNumeralError> {
+    match parse_degree(s)? {
+        (degree, minor, "") => Ok(Tonicization { degree, minor }),
+        _ => Err(InvalidNumeral(s.to_string())),
+    }
+}
+
+impl FromStr for RomanNumeral {
+    type Err = ParseRomanNumeralError;
+
+    /// Parses roman numerals such as `V7/V`, `bII6`, `viiø7`, `vii°42`, `IM7`, `Ger+6` or
+    /// `Cad64`. `o` is accepted for `°`, and `It6`, `Fr43` and `Ger65` for the augmented sixths.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('/');
+        let numeral = parse_numeral(parts.next().unwrap_or_default())?;
+        // written from the innermost key outwards, which is the order kept in `targets`
+        let targets = parts.map(parse_target).collect::<Result<_, _>>()?;
+        Ok(RomanNumeral { numeral, targets })
+    }
+}
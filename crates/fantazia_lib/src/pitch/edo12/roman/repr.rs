@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter, Write as _};
+
+use super::super::{OInterval, ScaleDegree};
+use super::{AugmentedSixth, Numeral, RomanNumeral, Tonicization, TriadQuality};
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+fn fmt_degree(f: &mut Formatter<'_>, degree: ScaleDegree, lowercase: bool) -> std::fmt::Result {
+    let sym = if degree.acci.0 > 0 { '#' } else { 'b' };
+    for _ in 0..degree.acci.0.unsigned_abs() {
+        f.write_char(sym)?;
+    }
+    let numeral = NUMERALS[degree.deg as usize];
+    if lowercase {
+        f.write_str(&numeral.to_ascii_lowercase())
+    } else {
+        f.write_str(numeral)
+    }
+}
+
+impl Display for Tonicization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_degree(f, self.degree, self.minor)
+    }
+}
+
+impl Display for Numeral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use AugmentedSixth::*;
+        let (degree, quality, seventh, inversion) = match *self {
+            Numeral::Degree {
+                degree,
+                quality,
+                seventh,
+                inversion,
+            } => (degree, quality, seventh, inversion),
+            Numeral::AugmentedSixth(Italian) => return f.write_str("It+6"),
+            Numeral::AugmentedSixth(French) => return f.write_str("Fr+6"),
+            Numeral::AugmentedSixth(German) => return f.write_str("Ger+6"),
+            Numeral::Cadential64 => return f.write_str("Cad64"),
+        };
+        fmt_degree(f, degree, quality.is_lowercase())?;
+        match quality {
+            TriadQuality::Diminished if seventh == Some(OInterval::MINOR_SEVENTH) => {
+                f.write_char('ø')?
+            }
+            TriadQuality::Diminished => f.write_char('°')?,
+            TriadQuality::Augmented => f.write_char('+')?,
+            _ => {}
+        }
+        if seventh == Some(OInterval::MAJOR_SEVENTH) {
+            f.write_char('M')?;
+        }
+        let figures: &[&str] = match seventh {
+            Some(_) => &["7", "65", "43", "42"],
+            None => &["", "6", "64"],
+        };
+        f.write_str(figures.get(inversion as usize).unwrap_or(&""))
+    }
+}
+
+impl Display for RomanNumeral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.numeral)?;
+        for target in &self.targets {
+            write!(f, "/{target}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+mod base;
+mod repr;
+
+pub use base::*;
@@ -0,0 +1,227 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use super::super::{OInterval, OPitch, OStep};
+
+/// The seven rotations of the diatonic scale.
+#[repr(u8)]
+#[derive(
+    IntoPrimitive,
+    TryFromPrimitive,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Debug,
+    Hash,
+    Default,
+)]
+pub enum Mode {
+    #[default]
+    Ionian = 0,
+    Dorian = 1,
+    Phrygian = 2,
+    Lydian = 3,
+    Mixolydian = 4,
+    Aeolian = 5,
+    Locrian = 6,
+}
+
+impl Mode {
+    pub const MAJOR: Self = Mode::Ionian;
+    pub const MINOR: Self = Mode::Aeolian;
+
+    /// Step of the C major scale on which the mode starts, e.g. D for dorian.
+    pub fn ostep(self) -> OStep {
+        OStep::try_from(u8::from(self)).unwrap()
+    }
+
+    /// Intervals of the mode above its tonic, in ascending order.
+    pub fn intervals(self) -> [OInterval; 7] {
+        let start = OPitch::from(self.ostep());
+        std::array::from_fn(|i| {
+            let step = OStep::try_from(i as u8 + self.ostep()).unwrap();
+            OInterval::from(OPitch::from(step) - start)
+        })
+    }
+}
+
+/// Scale given by a tonic and the ascending intervals of its degrees above the tonic.
+///
+/// The first degree is always the tonic itself. Degrees may share a step, as in the whole-half
+/// octatonic scale C D E♭ F G♭ A♭ A B, so members are spelled exactly as given by the intervals.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Scale {
+    pub(crate) tonic: OPitch,
+    pub(crate) intervals: Vec<OInterval>,
+}
+
+macro_rules! scale_constructors {
+    ($($(#[$meta:meta])* $name:ident => [$($interval:ident),*]);*$(;)?) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(tonic: OPitch) -> Self {
+                Self::new(tonic, [$(OInterval::$interval),*])
+            }
+        )*
+    };
+}
+
+impl Scale {
+    /// Creates a scale from intervals in ascending order. The unison is prepended if missing.
+    pub fn new(tonic: OPitch, intervals: impl IntoIterator<Item = OInterval>) -> Self {
+        let mut intervals: Vec<_> = intervals.into_iter().collect();
+        if intervals.first() != Some(&OInterval::UNISON) {
+            intervals.insert(0, OInterval::UNISON);
+        }
+        Scale { tonic, intervals }
+    }
+
+    pub fn diatonic(tonic: OPitch, mode: Mode) -> Self {
+        Self::new(tonic, mode.intervals())
+    }
+
+    pub fn major(tonic: OPitch) -> Self {
+        Self::diatonic(tonic, Mode::MAJOR)
+    }
+
+    /// Natural minor scale.
+    pub fn minor(tonic: OPitch) -> Self {
+        Self::diatonic(tonic, Mode::MINOR)
+    }
+
+    scale_constructors! {
+        harmonic_minor => [
+            MAJOR_SECOND, MINOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SIXTH, MAJOR_SEVENTH
+        ];
+        /// Second mode of harmonic minor.
+        locrian_natural6 => [
+            MINOR_SECOND, MINOR_THIRD, PERFECT_FOURTH, DIMINISHED_FIFTH, MAJOR_SIXTH, MINOR_SEVENTH
+        ];
+        /// Third mode of harmonic minor.
+        ionian_augmented => [
+            MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, AUGMENTED_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH
+        ];
+        /// Fourth mode of harmonic minor.
+        dorian_sharp4 => [
+            MAJOR_SECOND, MINOR_THIRD, AUGMENTED_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MINOR_SEVENTH
+        ];
+        /// Fifth mode of harmonic minor.
+        phrygian_dominant => [
+            MINOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SIXTH, MINOR_SEVENTH
+        ];
+        /// Sixth mode of harmonic minor.
+        lydian_sharp2 => [
+            AUGMENTED_SECOND, MAJOR_THIRD, AUGMENTED_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH,
+            MAJOR_SEVENTH
+        ];
+        /// Seventh mode of harmonic minor.
+        super_locrian_double_flat7 => [
+            MINOR_SECOND, MINOR_THIRD, DIMINISHED_FOURTH, DIMINISHED_FIFTH, MINOR_SIXTH,
+            DIMINISHED_SEVENTH
+        ];
+        /// Ascending melodic minor scale.
+        melodic_minor => [
+            MAJOR_SECOND, MINOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH
+        ];
+        /// Second mode of melodic minor.
+        dorian_flat2 => [
+            MINOR_SECOND, MINOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MINOR_SEVENTH
+        ];
+        /// Third mode of melodic minor.
+        lydian_augmented => [
+            MAJOR_SECOND, MAJOR_THIRD, AUGMENTED_FOURTH, AUGMENTED_FIFTH, MAJOR_SIXTH, MAJOR_SEVENTH
+        ];
+        /// Fourth mode of melodic minor.
+        lydian_dominant => [
+            MAJOR_SECOND, MAJOR_THIRD, AUGMENTED_FOURTH, PERFECT_FIFTH, MAJOR_SIXTH, MINOR_SEVENTH
+        ];
+        /// Fifth mode of melodic minor.
+        mixolydian_flat6 => [
+            MAJOR_SECOND, MAJOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SIXTH, MINOR_SEVENTH
+        ];
+        /// Sixth mode of melodic minor.
+        locrian_natural2 => [
+            MAJOR_SECOND, MINOR_THIRD, PERFECT_FOURTH, DIMINISHED_FIFTH, MINOR_SIXTH, MINOR_SEVENTH
+        ];
+        /// Seventh mode of melodic minor, also known as the super locrian scale.
+        altered => [
+            MINOR_SECOND, MINOR_THIRD, DIMINISHED_FOURTH, DIMINISHED_FIFTH, MINOR_SIXTH,
+            MINOR_SEVENTH
+        ];
+        major_pentatonic => [MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+        minor_pentatonic => [MINOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SEVENTH];
+        whole_tone => [
+            MAJOR_SECOND, MAJOR_THIRD, AUGMENTED_FOURTH, AUGMENTED_FIFTH, AUGMENTED_SIXTH
+        ];
+        /// Octatonic scale starting with a half step, e.g. C D♭ D♯ E F♯ G A B♭.
+        octatonic_half_whole => [
+            MINOR_SECOND, AUGMENTED_SECOND, MAJOR_THIRD, AUGMENTED_FOURTH, PERFECT_FIFTH,
+            MAJOR_SIXTH, MINOR_SEVENTH
+        ];
+        /// Octatonic scale starting with a whole step, e.g. C D E♭ F G♭ A♭ A B.
+        octatonic_whole_half => [
+            MAJOR_SECOND, MINOR_THIRD, PERFECT_FOURTH, DIMINISHED_FIFTH, MINOR_SIXTH, MAJOR_SIXTH,
+            MAJOR_SEVENTH
+        ];
+        /// Minor pentatonic scale with an added diminished fifth.
+        blues => [MINOR_THIRD, PERFECT_FOURTH, DIMINISHED_FIFTH, PERFECT_FIFTH, MINOR_SEVENTH];
+    }
+
+    pub fn tonic(&self) -> OPitch {
+        self.tonic
+    }
+
+    /// Intervals of the degrees above the tonic, starting with the unison.
+    pub fn intervals(&self) -> &[OInterval] {
+        &self.intervals
+    }
+
+    /// Spelled scale members in ascending order, starting from the tonic.
+    pub fn pitches(&self) -> Vec<OPitch> {
+        self.intervals.iter().map(|&i| self.tonic + i).collect()
+    }
+
+    /// The `n`th degree of the scale, counting the tonic as `1`. Returns `None` if `n` is `0` or
+    /// exceeds the number of degrees.
+    pub fn degree(&self, n: usize) -> Option<OPitch> {
+        let interval = self.intervals.get(n.checked_sub(1)?)?;
+        Some(self.tonic + *interval)
+    }
+
+    /// Degree of `pitch` in the scale, counting the tonic as `1`. The spelling must match.
+    pub fn degree_of(&self, pitch: &OPitch) -> Option<usize> {
+        self.intervals
+            .iter()
+            .position(|&i| self.tonic + i == *pitch)
+            .map(|i| i + 1)
+    }
+
+    /// Whether `pitch` is a member of the scale with the same spelling.
+    pub fn contains(&self, pitch: &OPitch) -> bool {
+        self.degree_of(pitch).is_some()
+    }
+
+    /// Whether the scale has a member enharmonically equivalent to `pitch`.
+    pub fn contains_enharmonic(&self, pitch: &OPitch) -> bool {
+        self.intervals
+            .iter()
+            .any(|&i| (self.tonic + i).is_enharmonic(pitch))
+    }
+
+    /// The scale starting from its `n`th degree, e.g. the dorian mode of a major scale for
+    /// `n = 2`. Returns `None` under the same conditions as [`Scale::degree`].
+    pub fn mode(&self, n: usize) -> Option<Self> {
+        let tonic = self.degree(n)?;
+        let mut pitches = self.pitches();
+        pitches.rotate_left(n - 1);
+        Some(Scale {
+            tonic,
+            intervals: pitches
+                .into_iter()
+                .map(|p| OInterval::from(p - tonic))
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use std::fmt::{Display, Formatter};
+
+use super::Scale;
+
+impl Display for Scale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, pitch) in self.pitches().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{pitch}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+mod base;
+mod parsing;
+mod repr;
+
+pub use base::*;
@@ -0,0 +1,82 @@
+use super::super::{Acci, Key, OInterval, OIntervalDeg, OPitch, Pitch};
+
+/// Chromatic movable-do syllables and the intervals above do they stand for.
+pub(crate) const SYLLABLES: [(&str, OInterval); 17] = [
+    ("do", OInterval::UNISON),
+    ("di", OInterval::AUGMENTED_UNISON),
+    ("ra", OInterval::MINOR_SECOND),
+    ("re", OInterval::MAJOR_SECOND),
+    ("ri", OInterval::AUGMENTED_SECOND),
+    ("me", OInterval::MINOR_THIRD),
+    ("mi", OInterval::MAJOR_THIRD),
+    ("fa", OInterval::PERFECT_FOURTH),
+    ("fi", OInterval::AUGMENTED_FOURTH),
+    ("se", OInterval::DIMINISHED_FIFTH),
+    ("sol", OInterval::PERFECT_FIFTH),
+    ("si", OInterval::AUGMENTED_FIFTH),
+    ("le", OInterval::MINOR_SIXTH),
+    ("la", OInterval::MAJOR_SIXTH),
+    ("li", OInterval::AUGMENTED_SIXTH),
+    ("te", OInterval::MINOR_SEVENTH),
+    ("ti", OInterval::MAJOR_SEVENTH),
+];
+
+impl OPitch {
+    /// Chromatic movable-do syllable of the pitch with `tonic` as do, e.g. `fi` for F sharp
+    /// above C. Returns `None` for intervals without a syllable, such as a diminished fourth.
+    pub fn solfege(&self, tonic: OPitch) -> Option<&'static str> {
+        let interval = OInterval::from(*self - tonic);
+        SYLLABLES
+            .iter()
+            .find(|(_, i)| *i == interval)
+            .map(|&(syllable, _)| syllable)
+    }
+}
+
+impl Pitch {
+    /// See [`OPitch::solfege`].
+    pub fn solfege(&self, tonic: OPitch) -> Option<&'static str> {
+        OPitch::from(*self).solfege(tonic)
+    }
+}
+
+/// Scale degree of a pitch within a key, written with a caret such as `^3` or `♭^7`.
+///
+/// The accidental is relative to the key, so the leading tone of C minor is `♯^7` although it is
+/// spelled B natural.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScaleDegree {
+    pub(crate) deg: OIntervalDeg,
+    pub(crate) acci: Acci,
+}
+
+impl ScaleDegree {
+    pub const fn new(deg: OIntervalDeg, acci: Acci) -> Self {
+        ScaleDegree { deg, acci }
+    }
+
+    /// Zero-based degree, i.e. `Unison` for `^1`.
+    pub const fn deg(&self) -> OIntervalDeg {
+        self.deg
+    }
+
+    /// Alteration relative to the key.
+    pub const fn acci(&self) -> Acci {
+        self.acci
+    }
+
+    /// Scale degree of `pitch` in `key`.
+    pub fn of(pitch: OPitch, key: &Key) -> Self {
+        let deg = OInterval::from(pitch - key.tonic).deg;
+        let diatonic = key.tonic + key.mode.intervals()[deg as usize];
+        ScaleDegree {
+            deg,
+            acci: Acci(pitch.acci().0 - diatonic.acci().0),
+        }
+    }
+
+    /// Spelled pitch of the scale degree in `key`.
+    pub fn pitch(&self, key: &Key) -> OPitch {
+        key.tonic + key.mode.intervals()[self.deg as usize] + self.acci
+    }
+}
@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use super::super::{Acci, OIntervalDeg, OPitch};
+use super::{SYLLABLES, ScaleDegree};
+
+pub mod err {
+    use thiserror::Error;
+
+    #[derive(Debug, Error)]
+    #[error("Invalid solfège syllable: `{0}`.")]
+    pub struct ParseSolfegeError(pub(super) String);
+
+    #[derive(Debug, Error)]
+    pub enum ParseScaleDegreeError {
+        #[error("Missing caret in scale degree: `{0}`.")]
+        MissingCaret(String),
+        #[error("Invalid accidental in scale degree: `{0}`.")]
+        InvalidAcci(char),
+        #[error("Invalid scale degree: `{0}`.")]
+        InvalidDeg(String),
+    }
+}
+
+impl OPitch {
+    /// Spells the movable-do syllable `s` with `tonic` as do. `so` is accepted for `sol`.
+    pub fn from_solfege(s: &str, tonic: OPitch) -> Result<Self, err::ParseSolfegeError> {
+        let syllable = if s.eq_ignore_ascii_case("so") {
+            "sol"
+        } else {
+            s
+        };
+        SYLLABLES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(syllable))
+            .map(|&(_, interval)| tonic + interval)
+            .ok_or_else(|| err::ParseSolfegeError(s.to_string()))
+    }
+}
+
+impl FromStr for ScaleDegree {
+    type Err = err::ParseScaleDegreeError;
+
+    /// Parses scale degrees such as `^3`, `♭^7` or `#^4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use err::ParseScaleDegreeError::*;
+        let (prefix, deg) = s
+            .split_once('^')
+            .ok_or_else(|| MissingCaret(s.to_string()))?;
+        let acci = prefix.chars().try_fold(0, |acci, ch| match ch {
+            '#' | '♯' => Ok(acci + 1),
+            'b' | '♭' => Ok(acci - 1),
+            _ => Err(InvalidAcci(ch)),
+        })?;
+        let deg: OIntervalDeg = deg.parse().map_err(|_| InvalidDeg(deg.to_string()))?;
+        Ok(ScaleDegree::new(deg, Acci(acci)))
+    }
+}
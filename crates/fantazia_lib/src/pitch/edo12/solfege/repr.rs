@@ -0,0 +1,13 @@
+use std::fmt::{Display, Formatter, Write as _};
+
+use super::ScaleDegree;
+
+impl Display for ScaleDegree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sym = if self.acci.0 > 0 { '♯' } else { '♭' };
+        for _ in 0..self.acci.0.unsigned_abs() {
+            f.write_char(sym)?;
+        }
+        write!(f, "^{}", self.deg)
+    }
+}
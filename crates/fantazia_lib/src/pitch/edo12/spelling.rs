@@ -0,0 +1,73 @@
+use super::{
+    Acci, OPitch, OStep, Pitch, Step,
+    traits::FromCo5Order as _,
+    utils::{KEY_IDX_OF_COLOR, NEIGHBORING_WHITE_KEYS, is_black_key_otone},
+};
+
+/// Decides how a pitch class given only by its tone is spelled.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum SpellingPolicy {
+    /// Spells black keys as the white key below raised by a sharp.
+    Sharps,
+    /// Spells black keys as the white key above lowered by a flat.
+    Flats,
+    /// Spells every pitch class within a window of the line of fifths around a key signature,
+    /// given as a signed count of sharps (positive) or flats (negative).
+    ///
+    /// The window spans from three fifths below to eight fifths above the tonic of the major
+    /// key, e.g. E flat to G sharp for C major, so that the leading tone of the relative minor
+    /// is spelled as a raised step. Counts too extreme to spell every pitch class are clamped.
+    Key(i8),
+}
+
+impl SpellingPolicy {
+    /// Lowest circle-of-fifths order used by the policy. The spelling of any pitch class lies in
+    /// `start..start + 12`.
+    pub fn co5_start(&self) -> i8 {
+        use SpellingPolicy::*;
+        match self {
+            Sharps => -1,
+            Flats => -6,
+            &Key(n) => (n as i16 - 3).clamp(i8::MIN as i16, i8::MAX as i16 - 12) as i8,
+        }
+    }
+}
+
+impl OPitch {
+    /// Spells the pitch class of `tone` according to `policy`.
+    pub fn from_tone(tone: i8, policy: SpellingPolicy) -> Self {
+        use SpellingPolicy::*;
+        let otone = tone.rem_euclid(12) as u8;
+        let white_key = |idx: u8| OStep::try_from(idx).unwrap();
+        match policy {
+            Sharps | Flats if !is_black_key_otone(otone) => {
+                white_key(KEY_IDX_OF_COLOR[otone as usize]).into()
+            }
+            Sharps => {
+                let below = NEIGHBORING_WHITE_KEYS[KEY_IDX_OF_COLOR[otone as usize] as usize];
+                OPitch::new(white_key(below), Acci::SHARP)
+            }
+            Flats => {
+                let below = NEIGHBORING_WHITE_KEYS[KEY_IDX_OF_COLOR[otone as usize] as usize];
+                OPitch::new(white_key(below + 1), Acci::FLAT)
+            }
+            Key(_) => {
+                let start = policy.co5_start() as i16;
+                let co5_order = start + (otone as i16 * 7 - start).rem_euclid(12);
+                OPitch::from_co5_order(co5_order as i8)
+            }
+        }
+    }
+}
+
+impl Pitch {
+    /// Spells the pitch `tone` semitones above `Pitch::ZERO` according to `policy`.
+    pub fn from_tone(tone: i8, policy: SpellingPolicy) -> Self {
+        let opitch = OPitch::from_tone(tone, policy);
+        let octave = ((tone as i16 - opitch.tone as i16) / 12) as i8;
+        Pitch {
+            step: Step::from_ostep_and_octave(opitch.step, octave),
+            tone,
+        }
+    }
+}
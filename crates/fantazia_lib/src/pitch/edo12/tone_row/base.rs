@@ -0,0 +1,182 @@
+use super::super::{OPitch, PcSet, SpellingPolicy};
+use super::parsing::err;
+
+/// Operation deriving a row form from the prime form.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum RowForm {
+    Prime,
+    Inversion,
+    Retrograde,
+    RetrogradeInversion,
+}
+
+/// Label of a row form such as `P0` or `RI5`.
+///
+/// The index is the first pitch class of `P` and `I` forms, where C is `0`. `R` and `RI` forms
+/// are the retrogrades of `P` and `I` forms with the same index, so they end on it.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct RowLabel {
+    pub form: RowForm,
+    pub index: u8,
+}
+
+impl RowLabel {
+    pub fn new(form: RowForm, index: i8) -> Self {
+        RowLabel {
+            form,
+            index: index.rem_euclid(12) as u8,
+        }
+    }
+
+    /// All 48 labels, ordered by form and then by index.
+    pub fn all() -> impl Iterator<Item = Self> {
+        use RowForm::*;
+        [Prime, Inversion, Retrograde, RetrogradeInversion]
+            .into_iter()
+            .flat_map(|form| (0..12).map(move |index| RowLabel::new(form, index)))
+    }
+}
+
+/// Ordering of the 12 pitch classes, where C is `0`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct ToneRow(pub(crate) [i8; 12]);
+
+impl ToneRow {
+    /// Creates a row from 12 pitch classes, which are reduced modulo 12 and must be distinct.
+    pub fn new(pcs: impl IntoIterator<Item = i8>) -> Result<Self, err::InvalidToneRow> {
+        let pcs: Vec<_> = pcs.into_iter().map(|pc| pc.rem_euclid(12)).collect();
+        let pcs: [i8; 12] = pcs
+            .try_into()
+            .map_err(|pcs: Vec<_>| err::InvalidToneRow::WrongLength(pcs.len()))?;
+        let mut seen = PcSet::EMPTY;
+        for pc in pcs {
+            if seen.contains(pc) {
+                return Err(err::InvalidToneRow::Duplicate(pc));
+            }
+            seen.insert(pc);
+        }
+        Ok(ToneRow(pcs))
+    }
+
+    /// Creates a row from the pitch classes of spelled pitches.
+    pub fn from_pitches(
+        pitches: impl IntoIterator<Item = OPitch>,
+    ) -> Result<Self, err::InvalidToneRow> {
+        Self::new(pitches.into_iter().map(|pitch| pitch.tone))
+    }
+
+    pub fn pcs(&self) -> [i8; 12] {
+        self.0
+    }
+
+    fn map(&self, f: impl Fn(i8) -> i8) -> Self {
+        ToneRow(self.0.map(|pc| f(pc).rem_euclid(12)))
+    }
+
+    fn reversed(&self) -> Self {
+        let mut pcs = self.0;
+        pcs.reverse();
+        ToneRow(pcs)
+    }
+
+    /// `Pn`, the row transposed to start on `n`.
+    pub fn prime(&self, n: i8) -> Self {
+        let by = n - self.0[0];
+        self.map(|pc| pc + by)
+    }
+
+    /// `In`, the inversion of the row starting on `n`.
+    pub fn inversion(&self, n: i8) -> Self {
+        let first = self.0[0];
+        self.map(|pc| n + first - pc)
+    }
+
+    /// `Rn`, the retrograde of `Pn`.
+    pub fn retrograde(&self, n: i8) -> Self {
+        self.prime(n).reversed()
+    }
+
+    /// `RIn`, the retrograde of `In`.
+    pub fn retrograde_inversion(&self, n: i8) -> Self {
+        self.inversion(n).reversed()
+    }
+
+    /// Row form named by `label`, taking this row as a prime form.
+    pub fn form(&self, label: RowLabel) -> Self {
+        use RowForm::*;
+        let n = label.index as i8;
+        match label.form {
+            Prime => self.prime(n),
+            Inversion => self.inversion(n),
+            Retrograde => self.retrograde(n),
+            RetrogradeInversion => self.retrograde_inversion(n),
+        }
+    }
+
+    /// Label of `other` as a form of this row, if it is one. Symmetrical rows have several
+    /// labels for some forms, of which the first in the order of [`RowLabel::all`] is returned.
+    pub fn label_of(&self, other: &ToneRow) -> Option<RowLabel> {
+        RowLabel::all().find(|&label| self.form(label) == *other)
+    }
+
+    /// 12×12 matrix whose rows are the `P` forms, read left to right (`R` forms right to left),
+    /// and whose columns are the `I` forms, read top to bottom (`RI` forms bottom to top). The
+    /// first row is this row and the first column its inversion starting on the same pitch class.
+    pub fn matrix(&self) -> [[i8; 12]; 12] {
+        self.inversion(self.0[0]).0.map(|n| self.prime(n).0)
+    }
+
+    /// The row started from its `n`th member, with the members before it moved to the end.
+    pub fn rotated(&self, n: usize) -> Self {
+        let mut pcs = self.0;
+        pcs.rotate_left(n % 12);
+        ToneRow(pcs)
+    }
+
+    /// Pitch classes of the first six members.
+    pub fn first_hexachord(&self) -> PcSet {
+        PcSet::new(self.0[..6].iter().copied())
+    }
+
+    /// Whether the first hexachord of the form named by `label` is the complement of this row's
+    /// first hexachord, so that the two forms together make up an aggregate in each half.
+    pub fn is_combinatorial_with(&self, label: RowLabel) -> bool {
+        self.form(label).first_hexachord() == self.first_hexachord().complement()
+    }
+
+    /// Labels of all forms hexachordally combinatorial with this row. These always include the
+    /// retrograde of the row itself.
+    pub fn combinatorial_forms(&self) -> Vec<RowLabel> {
+        RowLabel::all()
+            .filter(|&label| self.is_combinatorial_with(label))
+            .collect()
+    }
+
+    /// Whether some form of the kind `form` is hexachordally combinatorial with this row, e.g.
+    /// `Inversion` for I-combinatorial rows.
+    pub fn is_combinatorial(&self, form: RowForm) -> bool {
+        RowLabel::all().any(|label| label.form == form && self.is_combinatorial_with(label))
+    }
+
+    /// Whether the row is P-, I- and RI-combinatorial, and thus combinatorial under every
+    /// operation.
+    pub fn is_all_combinatorial(&self) -> bool {
+        use RowForm::*;
+        [Prime, Inversion, RetrogradeInversion]
+            .into_iter()
+            .all(|form| self.is_combinatorial(form))
+    }
+
+    /// Spells every member of the row according to `policy`.
+    pub fn spell(&self, policy: SpellingPolicy) -> [OPitch; 12] {
+        self.0.map(|pc| OPitch::from_tone(pc, policy))
+    }
+}
+
+impl TryFrom<[i8; 12]> for ToneRow {
+    type Error = err::InvalidToneRow;
+
+    fn try_from(value: [i8; 12]) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
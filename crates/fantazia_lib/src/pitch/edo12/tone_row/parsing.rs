@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use super::super::pcset::parsing::parse_pcs;
+use super::{RowForm, RowLabel, ToneRow};
+
+pub mod err {
+    use thiserror::Error;
+
+    use super::super::super::pcset::parsing::err::ParsePcSetError;
+
+    #[derive(Debug, Error)]
+    pub enum InvalidToneRow {
+        #[error("A tone row has 12 pitch classes, found {0}.")]
+        WrongLength(usize),
+        #[error("Pitch class {0} occurs more than once in the tone row.")]
+        Duplicate(i8),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ParseToneRowError {
+        #[error(transparent)]
+        InvalidPc(#[from] ParsePcSetError),
+        #[error(transparent)]
+        InvalidRow(#[from] InvalidToneRow),
+    }
+
+    #[derive(Debug, Error)]
+    #[error("Invalid row label: `{0}`.")]
+    pub struct ParseRowLabelError(pub(super) String);
+}
+
+impl FromStr for ToneRow {
+    type Err = err::ParseToneRowError;
+
+    /// Parses pitch classes in the syntax of [`PcSet`](super::super::PcSet)'s `FromStr`, e.g.
+    /// `0 11 7 8 3 1 2 10 6 5 4 9` or `0E783126T549`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ToneRow::new(parse_pcs(s)?)?)
+    }
+}
+
+impl FromStr for RowLabel {
+    type Err = err::ParseRowLabelError;
+
+    /// Parses labels such as `P0`, `I7`, `R11` or `RI5`. `T` and `E` are accepted for 10 and 11.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use RowForm::*;
+        let invalid = || err::ParseRowLabelError(s.to_string());
+        let (form, index) = [
+            ("RI", RetrogradeInversion),
+            ("P", Prime),
+            ("I", Inversion),
+            ("R", Retrograde),
+        ]
+        .into_iter()
+        .find_map(|(prefix, form)| Some((form, s.strip_prefix(prefix)?)))
+        .ok_or_else(invalid)?;
+        let index = match index {
+            "T" => 10,
+            "E" => 11,
+            _ => index.parse().map_err(|_| invalid())?,
+        };
+        if !(0..12).contains(&index) {
+            return Err(invalid());
+        }
+        Ok(RowLabel::new(form, index))
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt::{Display, Formatter};
+
+use super::{RowForm, RowLabel, ToneRow};
+
+impl Display for RowForm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use RowForm::*;
+        f.write_str(match self {
+            Prime => "P",
+            Inversion => "I",
+            Retrograde => "R",
+            RetrogradeInversion => "RI",
+        })
+    }
+}
+
+impl Display for RowLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.form, self.index)
+    }
+}
+
+impl Display for ToneRow {
+    /// Writes the pitch classes separated by spaces, e.g. `0 11 7 8 3 1 2 10 6 5 4 9`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, pc) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{pc}")?;
+        }
+        Ok(())
+    }
+}
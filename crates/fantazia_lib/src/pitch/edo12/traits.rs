@@ -1,6 +1,9 @@
+use std::fmt::Formatter;
+
 use super::Acci;
 
 use super::IntervalQual;
+use super::{OPitch, Pitch, notation::err::ParseNotationError};
 
 pub trait Co5Order {
     type Output;
@@ -22,3 +25,14 @@ pub trait AbsQual {
 pub trait AcciByQual {
     fn acci_by_qual(&self, qual: IntervalQual) -> Option<Acci>;
 }
+
+/// Textual notation of pitches, e.g. scientific (`F#4`) or Helmholtz (`f#'`).
+///
+/// Implementations are found in [`notation`](super::notation). Parsing the output of a formatter
+/// gives back the same pitch.
+pub trait PitchNotation {
+    fn fmt_opitch(&self, opitch: OPitch, f: &mut Formatter<'_>) -> std::fmt::Result;
+    fn fmt_pitch(&self, pitch: Pitch, f: &mut Formatter<'_>) -> std::fmt::Result;
+    fn parse_opitch(&self, s: &str) -> Result<OPitch, ParseNotationError>;
+    fn parse_pitch(&self, s: &str) -> Result<Pitch, ParseNotationError>;
+}
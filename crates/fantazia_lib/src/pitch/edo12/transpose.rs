@@ -0,0 +1,145 @@
+use super::{
+    Acci, Chord, Interval, Key, OInterval, OPitch, OStep, Pitch, Scale, SpellingPolicy, Step,
+};
+
+/// How pitches are moved by [`Transpose::transpose`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Transposition {
+    /// Adds an interval, preserving the spelled intervals between pitches.
+    ByInterval(Interval),
+    /// Moves by a number of semitones and spells the result according to `policy`.
+    Chromatic {
+        semitones: i8,
+        policy: SpellingPolicy,
+    },
+    /// Moves by a number of steps within `key`. Pitches foreign to the key keep their
+    /// alteration relative to the key signature, e.g. F♯ moved up a step in C major becomes G♯.
+    Diatonic { steps: i8, key: Key },
+}
+
+impl Transposition {
+    pub fn chromatic(semitones: i8, policy: SpellingPolicy) -> Self {
+        Transposition::Chromatic { semitones, policy }
+    }
+
+    pub fn diatonic(steps: i8, key: Key) -> Self {
+        Transposition::Diatonic { steps, key }
+    }
+}
+
+impl From<Interval> for Transposition {
+    fn from(value: Interval) -> Self {
+        Transposition::ByInterval(value)
+    }
+}
+
+impl From<OInterval> for Transposition {
+    /// Transposition upwards by `value`.
+    fn from(value: OInterval) -> Self {
+        Transposition::ByInterval(value.into())
+    }
+}
+
+pub trait Transpose {
+    type Output;
+    fn transpose(&self, by: Transposition) -> Self::Output;
+}
+
+impl Transpose for Pitch {
+    type Output = Self;
+
+    fn transpose(&self, by: Transposition) -> Self::Output {
+        use Transposition::*;
+        match by {
+            ByInterval(interval) => *self + interval,
+            Chromatic { semitones, policy } => Pitch::from_tone(self.tone + semitones, policy),
+            Diatonic { steps, key } => {
+                let sig = key.signature();
+                let chroma = self.acci().0 - sig.acci_of(self.step.into()).0;
+                let step = Step(self.step.0 + steps);
+                let acci = sig.acci_of(OStep::from(step)).0 + chroma;
+                Pitch::from_step_and_acci(step, Acci(acci))
+            }
+        }
+    }
+}
+
+impl Transpose for OPitch {
+    type Output = Self;
+
+    fn transpose(&self, by: Transposition) -> Self::Output {
+        use Transposition::*;
+        match by {
+            ByInterval(interval) => *self + OPitch::from(Pitch::from(interval)),
+            Chromatic { semitones, policy } => OPitch::from_tone(self.tone + semitones, policy),
+            Diatonic { .. } => Pitch::from(*self).transpose(by).into(),
+        }
+    }
+}
+
+impl Transpose for Chord {
+    type Output = Self;
+
+    /// Transposing by an interval or chromatically moves the root and bass while keeping the
+    /// chord quality. Transposing diatonically moves every member within the key, so that e.g.
+    /// a C major triad moved up a step in C major becomes a D minor triad.
+    fn transpose(&self, by: Transposition) -> Self::Output {
+        let root = self.root.transpose(by);
+        let intervals = match by {
+            Transposition::Diatonic { .. } => self
+                .intervals
+                .iter()
+                .map(|&i| OInterval::from((self.root + i).transpose(by) - root))
+                .collect(),
+            _ => self.intervals.clone(),
+        };
+        Chord {
+            root,
+            intervals,
+            bass: self.bass.map(|bass| bass.transpose(by)),
+        }
+    }
+}
+
+impl Transpose for Scale {
+    type Output = Self;
+
+    /// Transposing by an interval or chromatically moves the tonic while keeping the intervals.
+    /// Transposing diatonically moves every member within the key, so that e.g. C major moved up
+    /// a step in C major becomes D dorian.
+    fn transpose(&self, by: Transposition) -> Self::Output {
+        let tonic = self.tonic.transpose(by);
+        let intervals = match by {
+            Transposition::Diatonic { .. } => self
+                .pitches()
+                .into_iter()
+                .map(|p| OInterval::from(p.transpose(by) - tonic))
+                .collect(),
+            _ => self.intervals.clone(),
+        };
+        Scale { tonic, intervals }
+    }
+}
+
+impl<T: Transpose> Transpose for [T] {
+    type Output = Vec<T::Output>;
+
+    fn transpose(&self, by: Transposition) -> Self::Output {
+        self.iter().map(|item| item.transpose(by)).collect()
+    }
+}
+
+/// Transposition of every item of an iterator.
+pub trait TransposeIter: Iterator + Sized
+where
+    Self::Item: Transpose,
+{
+    fn transposed(
+        self,
+        by: Transposition,
+    ) -> impl Iterator<Item = <Self::Item as Transpose>::Output> {
+        self.map(move |item| item.transpose(by))
+    }
+}
+
+impl<I: Iterator> TransposeIter for I where I::Item: Transpose {}
@@ -0,0 +1,101 @@
+use super::{Interval, OInterval, OPitch, Pitch, SpellingPolicy};
+
+/// Reference pitch and frequency of a 12-EDO tuning.
+///
+/// Defaults to A4 = 440 Hz. Middle C is taken to be `Pitch::ZERO`, so A4 is `A_0`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ConcertPitch {
+    pub(crate) pitch: Pitch,
+    pub(crate) freq: f64,
+}
+
+impl ConcertPitch {
+    pub const A440: Self = ConcertPitch {
+        pitch: Pitch::from_opitch_and_octave(OPitch::A, 0),
+        freq: 440.0,
+    };
+
+    pub const fn new(pitch: Pitch, freq: f64) -> Self {
+        ConcertPitch { pitch, freq }
+    }
+
+    /// Concert pitch with the default reference pitch A4 tuned to `freq`.
+    pub const fn with_a4(freq: f64) -> Self {
+        ConcertPitch {
+            pitch: Self::A440.pitch,
+            freq,
+        }
+    }
+
+    pub const fn pitch(&self) -> Pitch {
+        self.pitch
+    }
+
+    pub const fn freq(&self) -> f64 {
+        self.freq
+    }
+}
+
+impl Default for ConcertPitch {
+    fn default() -> Self {
+        Self::A440
+    }
+}
+
+#[inline]
+fn cents_to_ratio(cents: f64) -> f64 {
+    (cents / 1200.0).exp2()
+}
+
+impl Pitch {
+    /// Frequency of the pitch in Hz under the given concert pitch.
+    pub fn freq(&self, concert: &ConcertPitch) -> f64 {
+        let semitones = self.tone as i16 - concert.pitch.tone as i16;
+        concert.freq * cents_to_ratio(semitones as f64 * 100.0)
+    }
+
+    /// Finds the pitch nearest to `freq` under the given concert pitch, together with the
+    /// deviation of `freq` from that pitch in cents (in `-50.0..=50.0`).
+    ///
+    /// Black keys are spelled with sharps. Returns `None` if `freq` is not a positive finite
+    /// number or if the nearest pitch is out of range.
+    pub fn from_freq(freq: f64, concert: &ConcertPitch) -> Option<(Self, f64)> {
+        if !(freq.is_finite() && freq > 0.0) {
+            return None;
+        }
+        let semitones = 12.0 * (freq / concert.freq).log2();
+        let nearest = semitones.round();
+        let tone = nearest + concert.pitch.tone as f64;
+        if !(i8::MIN as f64..=i8::MAX as f64).contains(&tone) {
+            return None;
+        }
+        let pitch = Pitch::from_tone(tone as i8, SpellingPolicy::Sharps);
+        Some((pitch, (semitones - nearest) * 100.0))
+    }
+}
+
+impl OPitch {
+    /// Frequency of the pitch in Hz in the central octave under the given concert pitch.
+    pub fn freq(&self, concert: &ConcertPitch) -> f64 {
+        Pitch::from(*self).freq(concert)
+    }
+}
+
+impl OInterval {
+    /// Size of the interval in 12-EDO cents.
+    pub fn cents(&self) -> f64 {
+        OPitch::from(*self).tone as f64 * 100.0
+    }
+}
+
+impl Interval {
+    /// Size of the interval in 12-EDO cents.
+    pub fn cents(&self) -> f64 {
+        Pitch::from(*self).tone as f64 * 100.0
+    }
+
+    /// Frequency ratio of the interval in 12-EDO.
+    pub fn freq_ratio(&self) -> f64 {
+        cents_to_ratio(self.cents())
+    }
+}
@@ -1,6 +1,6 @@
 use num_integer::Integer as _;
 
-const BLACK_KEY_MASK: u16 = 0b0101010_01010;
+const BLACK_KEY_MASK: u16 = 0b0101_0100_1010;
 pub const KEY_IDX_OF_COLOR: [u8; 12] = [0, 0, 1, 1, 2, 3, 2, 4, 3, 5, 4, 6];
 pub const WHITE_TONES: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
 pub const BLACK_TONES: [u8; 5] = [1, 3, 6, 8, 10];
@@ -0,0 +1,10 @@
+mod approx;
+mod arith;
+mod base;
+mod factor;
+mod monzo;
+pub(crate) mod parsing;
+mod repr;
+
+pub use base::*;
+pub use monzo::*;
@@ -0,0 +1,56 @@
+use std::f64::consts::LN_2;
+
+use malachite_base::num::arithmetic::traits::Mod as _;
+use malachite_nz::natural::Natural;
+
+use crate::pitch::{
+    edo12::{Interval, Pitch, Step},
+    xen::edo::EdoInterval,
+};
+
+use super::Ratio;
+
+/// Spelling of a prime as fifths and octaves: the 12-EDO interval nearest to it, among the
+/// intervals from `m2` to `A4` on the circle of fifths (`d5` is spelled as `A4`).
+fn prime_spelling(p: &Natural) -> (i64, i64) {
+    let semitones = (12.0 * p.approx_log() / LN_2).round() as i64;
+    // 7 is its own inverse modulo 12
+    let fifths = (7 * semitones + 5).mod_op(12) - 5;
+    (fifths, (semitones - 7 * fifths) / 12)
+}
+
+impl Ratio {
+    /// Nearest 12-EDO interval, or `None` if it is out of range of [`Interval`].
+    ///
+    /// Every prime factor is spelled as the 12-EDO interval nearest to it, so that 3-limit
+    /// ratios get Pythagorean spellings, `5/4` is a major third, `7/4` a minor seventh and
+    /// `11/8` an augmented fourth. Should the spelling of the whole ratio be a semitone or more
+    /// away from its size, it is corrected with augmented unisons.
+    pub fn nearest_interval(&self) -> Option<Interval> {
+        let (mut fifths, mut octaves) = (0, 0);
+        for (p, exp) in self.monzo().primes() {
+            let (p_fifths, p_octaves) = prime_spelling(p);
+            fifths += i64::from(exp) * p_fifths;
+            octaves += i64::from(exp) * p_octaves;
+        }
+        let semitones = (self.cents() / 100.0).round() as i64;
+        let error = semitones - (7 * fifths + 12 * octaves);
+        // an augmented unison is 7 fifths minus 4 octaves
+        fifths += 7 * error;
+        octaves -= 4 * error;
+        let pitch = Pitch {
+            step: Step((4 * fifths + 7 * octaves).try_into().ok()?),
+            tone: (7 * fifths + 12 * octaves).try_into().ok()?,
+        };
+        Some(pitch.into())
+    }
+
+    /// Nearest interval in `N`-EDO, spelled as [`Ratio::nearest_interval`] with ups and downs
+    /// making up the difference. Returns `None` if it is out of range of [`EdoInterval`].
+    pub fn nearest_edo_interval<const N: u16>(&self) -> Option<EdoInterval<N>> {
+        let interval = EdoInterval::<N>::from(self.nearest_interval()?);
+        let edosteps = (self.cents() * N as f64 / 1200.0).round() as i32;
+        let ups = (edosteps - interval.edosteps()).try_into().ok()?;
+        Some(EdoInterval::new(interval.interval(), ups))
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    iter::Product,
+    ops::{Div, DivAssign, Mul, MulAssign},
+};
+
+use malachite_base::num::{arithmetic::traits::Pow as _, basic::traits::One as _};
+
+use super::Ratio;
+
+impl Mul for Ratio {
+    type Output = Self;
+
+    /// Stacks two intervals.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Ratio(self.0 * rhs.0)
+    }
+}
+
+impl Mul for &Ratio {
+    type Output = Ratio;
+
+    /// Stacks two intervals.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Ratio(&self.0 * &rhs.0)
+    }
+}
+
+impl Div for Ratio {
+    type Output = Self;
+
+    /// Interval from `rhs` up to `self`.
+    fn div(self, rhs: Self) -> Self::Output {
+        Ratio(self.0 / rhs.0)
+    }
+}
+
+impl Div for &Ratio {
+    type Output = Ratio;
+
+    /// Interval from `rhs` up to `self`.
+    fn div(self, rhs: Self) -> Self::Output {
+        Ratio(&self.0 / &rhs.0)
+    }
+}
+
+impl MulAssign for Ratio {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl DivAssign for Ratio {
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 /= rhs.0;
+    }
+}
+
+impl Product for Ratio {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Ratio> for Ratio {
+    fn product<I: Iterator<Item = &'a Ratio>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, ratio| Ratio(acc.0 * &ratio.0))
+    }
+}
+
+impl Ratio {
+    /// The interval stacked `exp` times, descending for negative `exp`.
+    pub fn pow(&self, exp: i64) -> Self {
+        Ratio((&self.0).pow(exp))
+    }
+}
@@ -0,0 +1,93 @@
+use std::f64::consts::LN_2;
+
+use malachite_base::num::{
+    arithmetic::traits::{FloorLogBase2 as _, Reciprocal as _},
+    basic::traits::One,
+};
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+use super::{factor::prime_factors, parsing::err::InvalidRatio};
+
+/// Just intonation interval, i.e. a positive frequency ratio kept in lowest terms.
+///
+/// Ratios below `1/1` are descending intervals. Stacking intervals multiplies their ratios.
+/// Numerators and denominators are arbitrary-precision, so stacking never overflows.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Ratio(pub(crate) Rational);
+
+impl Ratio {
+    pub const OCTAVE: Self = Ratio(Rational::const_from_unsigned(2));
+
+    /// Creates the ratio `numer/denom`, reduced to lowest terms.
+    pub fn new(numer: u64, denom: u64) -> Result<Self, InvalidRatio> {
+        Self::from_naturals(numer.into(), denom.into())
+    }
+
+    /// Creates the ratio `numer/denom` of arbitrary-precision integers, reduced to lowest terms.
+    pub fn from_naturals(numer: Natural, denom: Natural) -> Result<Self, InvalidRatio> {
+        if numer == 0u32 || denom == 0u32 {
+            return Err(InvalidRatio(numer, denom));
+        }
+        Ok(Ratio(Rational::from_naturals(numer, denom)))
+    }
+
+    pub const fn numer(&self) -> &Natural {
+        self.0.numerator_ref()
+    }
+
+    pub const fn denom(&self) -> &Natural {
+        self.0.denominator_ref()
+    }
+
+    /// The inverted interval `denom/numer`.
+    pub fn recip(&self) -> Self {
+        Ratio((&self.0).reciprocal())
+    }
+
+    /// Size of the interval in cents, negative for descending intervals.
+    pub fn cents(&self) -> f64 {
+        1200.0 * self.0.approx_log() / LN_2
+    }
+
+    /// Number of whole octaves in the interval, rounded down.
+    pub fn octaves(&self) -> i64 {
+        (&self.0).floor_log_base_2()
+    }
+
+    /// The interval moved by whole octaves into `1/1..2/1`.
+    pub fn octave_reduced(&self) -> Self {
+        Ratio(&self.0 >> self.octaves())
+    }
+
+    /// Largest prime factor of the numerator and the denominator, or `1` for the unison.
+    ///
+    /// This factors the numerator and the denominator, see [`Monzo`](super::Monzo) for the cost.
+    pub fn prime_limit(&self) -> Natural {
+        prime_factors(self.numer())
+            .chain(prime_factors(self.denom()))
+            .map(|(p, _)| p)
+            .max()
+            .unwrap_or(Natural::from(1u32))
+    }
+
+    /// Tenney height `log2(numer * denom)`, a measure of the complexity of the interval.
+    pub fn tenney_height(&self) -> f64 {
+        (self.numer().approx_log() + self.denom().approx_log()) / LN_2
+    }
+}
+
+impl One for Ratio {
+    const ONE: Self = Ratio(Rational::const_from_unsigned(1));
+}
+
+impl From<u64> for Ratio {
+    /// The harmonic `value/1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is zero.
+    fn from(value: u64) -> Self {
+        Ratio::new(value, 1).expect("a harmonic should be positive")
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+
+use malachite_base::num::{
+    arithmetic::traits::{
+        CheckedRoot as _, DivisibleBy as _, Gcd as _, ModPow as _, ModSquare as _,
+    },
+    basic::traits::One as _,
+    factorization::traits::{Factor as _, Primes as _},
+    logic::traits::SignificantBits as _,
+};
+use malachite_nz::natural::Natural;
+
+/// Witnesses of the Miller-Rabin test, which make it exact below `3.3 * 10^24`.
+const WITNESSES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Prime factors of `n` in ascending order, with their exponents.
+///
+/// Numbers that fit in a `u64` are factored exactly. Larger numbers are split with Pollard's rho
+/// algorithm and their factors are tested with Miller-Rabin, so large primes are recognized
+/// quickly, but a product of two primes of more than about 20 digits each takes very long.
+pub(crate) fn prime_factors(n: &Natural) -> impl Iterator<Item = (Natural, u32)> {
+    let mut factors = BTreeMap::new();
+    factor_into(n.clone(), 1, &mut factors);
+    factors.into_iter()
+}
+
+/// Adds the prime factors of `n` to `factors`, with their exponents multiplied by `mult`.
+fn factor_into(mut n: Natural, mult: u32, factors: &mut BTreeMap<Natural, u32>) {
+    if let Ok(n) = u64::try_from(&n) {
+        if n > 1 {
+            for (p, exp) in n.factor() {
+                *factors.entry(Natural::from(p)).or_default() += u32::from(exp) * mult;
+            }
+        }
+        return;
+    }
+    // small primes first, so that `n` is odd and Pollard's rho only looks for large factors
+    for p in u64::primes().take_while(|&p| p < 1000) {
+        let p = Natural::from(p);
+        while (&n).divisible_by(&p) {
+            n /= &p;
+            *factors.entry(p.clone()).or_default() += mult;
+        }
+    }
+    if n <= u64::MAX {
+        return factor_into(n, mult, factors);
+    }
+    if is_probable_prime(&n) {
+        *factors.entry(n).or_default() += mult;
+        return;
+    }
+    // Pollard's rho is slow for powers of a large prime, so roots are taken first. The factors
+    // left are above 1000 > 2^9, which bounds the exponent.
+    for k in u64::primes().take_while(|&k| 9 * k < n.significant_bits()) {
+        if let Some(root) = (&n).checked_root(k) {
+            return factor_into(root, mult * k as u32, factors);
+        }
+    }
+    let d = find_factor(&n);
+    factor_into(&n / &d, mult, factors);
+    factor_into(d, mult, factors);
+}
+
+/// Miller-Rabin test of an odd `n` greater than all [`WITNESSES`].
+fn is_probable_prime(n: &Natural) -> bool {
+    let n_minus_1 = n - Natural::ONE;
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let d = &n_minus_1 >> s;
+    WITNESSES.into_iter().all(|a| {
+        let mut x = Natural::from(a).mod_pow(&d, n);
+        if x == 1u32 || x == n_minus_1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = x.mod_square(n);
+            if x == n_minus_1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Nontrivial factor of an odd composite `n` by Pollard's rho algorithm.
+fn find_factor(n: &Natural) -> Natural {
+    for c in 1u32.. {
+        let step = |x: &Natural| (x.mod_square(n) + Natural::from(c)) % n;
+        let (mut x, mut y) = (Natural::from(2u32), Natural::from(2u32));
+        loop {
+            x = step(&x);
+            y = step(&step(&y));
+            let diff = if x > y { &x - &y } else { &y - &x };
+            let d = diff.gcd(n);
+            if d == *n {
+                // the cycle closed without splitting `n`, so try another polynomial
+                break;
+            }
+            if d != 1u32 {
+                return d;
+            }
+        }
+    }
+    unreachable!("Pollard's rho should split a composite number")
+}
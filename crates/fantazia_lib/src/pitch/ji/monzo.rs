@@ -0,0 +1,79 @@
+use malachite_base::num::{arithmetic::traits::Pow as _, factorization::traits::Primes as _};
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+use super::{Ratio, factor::prime_factors};
+
+/// Prime exponent vector of a [`Ratio`]: the `i`-th entry is the exponent of the `i`-th prime,
+/// so `[-2 0 1>` is `2^-2 * 5^1 = 5/4`.
+///
+/// Only the primes with nonzero exponents are stored, so a ratio with a large prime factor such
+/// as `100000007/100000000` has a short monzo. Finding the primes takes a factorization of the
+/// numerator and the denominator, which is slow for products of two very large primes.
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Monzo(pub(crate) Vec<(Natural, i32)>);
+
+impl Monzo {
+    /// Creates a monzo from exponents of `2`, `3`, `5`, `7`, ...
+    pub fn new(exponents: Vec<i32>) -> Self {
+        Monzo(
+            u64::primes()
+                .zip(exponents)
+                .filter(|&(_, exp)| exp != 0)
+                .map(|(p, exp)| (Natural::from(p), exp))
+                .collect(),
+        )
+    }
+
+    /// Exponent of the prime `p`, which is zero if `p` is not a prime factor.
+    pub fn exponent(&self, p: u64) -> i32 {
+        self.0
+            .iter()
+            .find(|(prime, _)| *prime == p)
+            .map_or(0, |&(_, exp)| exp)
+    }
+
+    /// Primes with nonzero exponents in ascending order, with their exponents.
+    pub fn primes(&self) -> impl Iterator<Item = (&Natural, i32)> + '_ {
+        self.0.iter().map(|(p, exp)| (p, *exp))
+    }
+
+    /// Largest prime with a nonzero exponent, or `None` for the unison.
+    pub fn max_prime(&self) -> Option<&Natural> {
+        self.0.last().map(|(p, _)| p)
+    }
+}
+
+impl Ratio {
+    pub fn monzo(&self) -> Monzo {
+        Monzo::from(self)
+    }
+
+    /// Ratio of a monzo.
+    pub fn from_monzo(monzo: &Monzo) -> Self {
+        Ratio(
+            monzo
+                .primes()
+                .map(|(p, exp)| Rational::from(p).pow(i64::from(exp)))
+                .product(),
+        )
+    }
+}
+
+impl From<&Ratio> for Monzo {
+    fn from(value: &Ratio) -> Self {
+        let mut factors: Vec<_> = prime_factors(value.numer())
+            .map(|(p, exp)| (p, exp as i32))
+            .chain(prime_factors(value.denom()).map(|(p, exp)| (p, -(exp as i32))))
+            .collect();
+        // numerator and denominator are coprime, so every prime appears once
+        factors.sort_unstable();
+        Monzo(factors)
+    }
+}
+
+impl From<&Monzo> for Ratio {
+    fn from(value: &Monzo) -> Self {
+        Ratio::from_monzo(value)
+    }
+}
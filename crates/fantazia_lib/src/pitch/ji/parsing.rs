@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use malachite_nz::natural::Natural;
+
+use super::{Monzo, Ratio};
+
+pub mod err {
+    use malachite_nz::natural::Natural;
+    use thiserror::Error;
+
+    #[derive(Debug, Error)]
+    #[error("A ratio needs a positive numerator and denominator, found {0}/{1}.")]
+    pub struct InvalidRatio(pub(crate) Natural, pub(crate) Natural);
+
+    #[derive(Debug, Error)]
+    pub enum ParseRatioError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error("Invalid number: `{0}`.")]
+        InvalidNumber(String),
+        #[error(transparent)]
+        InvalidRatio(#[from] InvalidRatio),
+    }
+
+    #[derive(Debug, Error)]
+    #[error("Invalid monzo: `{0}`.")]
+    pub struct ParseMonzoError(pub(super) String);
+}
+
+use err::{ParseMonzoError, ParseRatioError};
+
+impl FromStr for Ratio {
+    type Err = ParseRatioError;
+
+    /// Parses `numer/denom`, e.g. `3/2` or `81/80`, or a harmonic such as `7`. The ratio needs
+    /// not be in lowest terms.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseRatioError::EmptyInput);
+        }
+        let parse = |s: &str| {
+            s.trim()
+                .parse::<Natural>()
+                .map_err(|_| ParseRatioError::InvalidNumber(s.to_string()))
+        };
+        let (numer, denom) = match s.split_once('/') {
+            Some((numer, denom)) => (parse(numer)?, parse(denom)?),
+            None => (parse(s)?, Natural::from(1u32)),
+        };
+        Ok(Ratio::from_naturals(numer, denom)?)
+    }
+}
+
+impl FromStr for Monzo {
+    type Err = ParseMonzoError;
+
+    /// Parses exponents separated by spaces or commas between `[` and `>` (or `⟩`), e.g.
+    /// `[-4 4 -1>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseMonzoError(s.to_string());
+        let inner = s
+            .trim()
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix('>').or_else(|| s.strip_suffix('⟩')))
+            .ok_or_else(invalid)?;
+        let exponents = inner
+            .split(|ch: char| ch.is_whitespace() || ch == ',')
+            .filter(|exp| !exp.is_empty())
+            .map(|exp| exp.parse().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+        Ok(Monzo::new(exponents))
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt::{Display, Formatter};
+
+use malachite_base::num::factorization::traits::Primes as _;
+
+use super::{Monzo, Ratio};
+
+impl Display for Ratio {
+    /// Writes `numer/denom`, e.g. `5/4`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numer(), self.denom())
+    }
+}
+
+impl Display for Monzo {
+    /// Writes the exponents in ket notation, e.g. `[-2 0 1>`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        if let Some(max) = self.max_prime() {
+            let mut factors = self.primes().peekable();
+            for (i, p) in u64::primes().take_while(|&p| *max >= p).enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                let exp = factors
+                    .next_if(|&(prime, _)| *prime == p)
+                    .map_or(0, |(_, exp)| exp);
+                write!(f, "{exp}")?;
+            }
+        }
+        write!(f, ">")
+    }
+}
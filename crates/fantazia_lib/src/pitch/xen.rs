@@ -1 +1,3 @@
 pub mod edo;
+pub mod scala;
+pub mod temperament;
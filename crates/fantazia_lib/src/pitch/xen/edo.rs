@@ -1,3 +1,8 @@
+mod arith;
 mod base;
+mod parsing;
+mod pitch;
+mod repr;
 
 pub use base::*;
+pub use pitch::*;
@@ -0,0 +1,118 @@
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use malachite_base::num::basic::traits::Zero as _;
+
+use crate::pitch::edo12::{Interval, Pitch};
+
+use super::{EdoInterval, EdoPitch};
+
+impl<const N: u16> Add<EdoInterval<N>> for EdoPitch<N> {
+    type Output = Self;
+
+    fn add(self, rhs: EdoInterval<N>) -> Self::Output {
+        EdoPitch {
+            pitch: self.pitch + rhs.interval,
+            ups: self.ups + rhs.ups,
+        }
+    }
+}
+
+impl<const N: u16> Sub<EdoInterval<N>> for EdoPitch<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: EdoInterval<N>) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const N: u16> Sub for EdoPitch<N> {
+    type Output = EdoInterval<N>;
+
+    /// Interval from `rhs` up to `self`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        EdoInterval {
+            interval: (self.pitch - rhs.pitch).into(),
+            ups: self.ups - rhs.ups,
+        }
+    }
+}
+
+impl<const N: u16> Add for EdoInterval<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        EdoInterval {
+            interval: (Pitch::from(self.interval) + Pitch::from(rhs.interval)).into(),
+            ups: self.ups + rhs.ups,
+        }
+    }
+}
+
+impl<const N: u16> Sub for EdoInterval<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const N: u16> Neg for EdoInterval<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        EdoInterval {
+            interval: -self.interval,
+            ups: -self.ups,
+        }
+    }
+}
+
+impl<const N: u16> Mul<i8> for EdoInterval<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: i8) -> Self::Output {
+        EdoInterval {
+            interval: Interval::from(Pitch::from(self.interval) * rhs),
+            ups: self.ups * rhs,
+        }
+    }
+}
+
+impl<const N: u16> AddAssign<EdoInterval<N>> for EdoPitch<N> {
+    fn add_assign(&mut self, rhs: EdoInterval<N>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: u16> SubAssign<EdoInterval<N>> for EdoPitch<N> {
+    fn sub_assign(&mut self, rhs: EdoInterval<N>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: u16> AddAssign for EdoInterval<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: u16> SubAssign for EdoInterval<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: u16> MulAssign<i8> for EdoInterval<N> {
+    fn mul_assign(&mut self, rhs: i8) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: u16> Sum for EdoInterval<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
@@ -1,10 +1,16 @@
 use std::cell::OnceCell;
 
-use malachite_base::num::arithmetic::traits::Gcd;
+const REORDER_ARG: [usize; 7] = [3, 0, 4, 1, 5, 2, 6];
 
-// use super::super::super::edo12::traits::PitchNotation;
+/// Size in edosteps of the fifth of `n`-EDO, the closest to a just `3/2`.
+pub(crate) fn fifth_size_of(n: u64) -> u64 {
+    (n as f64 * 1.5f64.log2()).round() as u64
+}
 
-const REORDER_ARG: [usize; 7] = [3, 0, 4, 1, 5, 2, 6];
+/// Sharpness of `n`-EDO, as in [`EDO::sharpness`].
+pub(crate) fn sharpness_of(n: u64) -> i64 {
+    fifth_size_of(n) as i64 * 7 - n as i64 * 4
+}
 
 pub struct EDO {
     edo: u64,
@@ -13,14 +19,10 @@ pub struct EDO {
 }
 
 impl EDO {
-    pub fn new(mut n: u64) -> Self {
-        let mut fifth_size = (n as f64 * 1.5f64.log2()).round() as u64;
-        let gcd = n.gcd(fifth_size);
-        n /= gcd;
-        fifth_size /= gcd;
+    pub fn new(n: u64) -> Self {
         EDO {
             edo: n,
-            fifth_size: OnceCell::from(fifth_size),
+            fifth_size: OnceCell::new(),
             diatonic: OnceCell::new(),
         }
     }
@@ -30,13 +32,13 @@ impl EDO {
     }
 
     pub fn fifth_size(&self) -> u64 {
-        *self
-            .fifth_size
-            .get_or_init(|| (self.edo as f64 * 1.5f64.log2()).round() as u64)
+        *self.fifth_size.get_or_init(|| fifth_size_of(self.edo))
     }
 
-    pub fn sharpness(&self) -> u64 {
-        self.fifth_size() * 7 - self.edo
+    /// Size of the chromatic semitone, i.e. seven fifths minus four octaves. It is negative for
+    /// EDOs whose fifth is flatter than 4/7 of an octave.
+    pub fn sharpness(&self) -> i64 {
+        sharpness_of(self.edo)
     }
 
     pub fn diatonic(&self) -> &[u64; 7] {
@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use crate::pitch::edo12::{Interval, Pitch, notation::Scientific, traits::PitchNotation as _};
+
+use super::{EdoInterval, EdoPitch, sharpness_of};
+
+pub mod err {
+    use thiserror::Error;
+
+    use crate::pitch::edo12::notation::err::ParseNotationError;
+
+    #[derive(Debug, Error)]
+    pub enum ParseEdoPitchError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error(transparent)]
+        InvalidPitch(#[from] ParseNotationError),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ParseEdoIntervalError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error("Invalid interval: `{0}`.")]
+        InvalidInterval(String),
+        #[error("Invalid interval name: `{0}`.")]
+        InvalidName(String),
+        #[error("Only seconds, thirds, sixths and sevenths can be mid: `{0}`.")]
+        PerfectMid(String),
+        #[error("{edo}-EDO has no mid intervals, since a sharp is {sharpness} edosteps.")]
+        NoMid { edo: u16, sharpness: i64 },
+    }
+}
+
+use err::{ParseEdoIntervalError, ParseEdoPitchError};
+
+/// Splits leading `^` and `v` from `s`, returning the number of ups.
+fn split_ups(s: &str) -> (i8, &str) {
+    let body = s.trim_start_matches(['^', 'v']);
+    let ups = s[..s.len() - body.len()]
+        .chars()
+        .map(|ch| if ch == '^' { 1 } else { -1 })
+        .sum();
+    (ups, body)
+}
+
+/// Words for ups and downs in interval names, in the order in which they are matched.
+pub(super) const UPS_WORDS: [(&str, i8); 6] = [
+    ("trup", 3),
+    ("trud", -3),
+    ("down", -1),
+    ("dup", 2),
+    ("dud", -2),
+    ("up", 1),
+];
+
+impl<const N: u16> FromStr for EdoPitch<N> {
+    type Err = ParseEdoPitchError;
+
+    /// Parses ups and downs followed by a pitch in [`Scientific`] notation, e.g. `^Eb4` or
+    /// `vvF#5`. A pitch without octave number is in the octave of middle C.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ups, s) = split_ups(s);
+        if s.is_empty() {
+            return Err(ParseEdoPitchError::EmptyInput);
+        }
+        let pitch = if s.contains(|ch: char| ch.is_ascii_digit()) {
+            Scientific.parse_pitch(s)?
+        } else {
+            Pitch::from(Scientific.parse_opitch(s)?)
+        };
+        Ok(EdoPitch::new(pitch, ups))
+    }
+}
+
+/// Splits `4-up major` (given as `4` and `up major`) into the ups and the rest.
+fn counted_ups<'a>(count: &str, rest: &'a str) -> Option<(i8, &'a str)> {
+    let count: i16 = count.parse().ok()?;
+    let (ups, rest) = match rest.strip_prefix("up") {
+        Some(rest) => (count, rest),
+        None => (-count, rest.strip_prefix("down")?),
+    };
+    Some((i8::try_from(ups).ok()?, rest.trim_start()))
+}
+
+impl<const N: u16> EdoInterval<N> {
+    /// Mid interval of an imperfect degree `deg`, halfway between its minor and major forms.
+    fn mid(deg: &str, src: &str) -> Result<Self, ParseEdoIntervalError> {
+        let minor = match format!("m{deg}").parse::<Interval>() {
+            Ok(minor) => minor,
+            Err(_) if format!("P{deg}").parse::<Interval>().is_ok() => {
+                return Err(ParseEdoIntervalError::PerfectMid(src.to_string()));
+            }
+            Err(_) => return Err(ParseEdoIntervalError::InvalidInterval(src.to_string())),
+        };
+        let sharpness = sharpness_of(N as u64);
+        if sharpness <= 0 || sharpness % 2 != 0 {
+            return Err(ParseEdoIntervalError::NoMid { edo: N, sharpness });
+        }
+        Ok(EdoInterval::new(minor, (sharpness / 2) as i8))
+    }
+
+    /// Parses names such as `upmajor 3rd`, `mid 6th`, `down 5th`, `perfect octave` or
+    /// `dudminor 7th`, with more ups and downs counted as in `4-up major 3rd` or `5-down 5th`.
+    pub fn from_name(s: &str) -> Result<Self, ParseEdoIntervalError> {
+        let invalid = || ParseEdoIntervalError::InvalidName(s.to_string());
+        let (qual, ordinal) = s.trim().rsplit_once(' ').ok_or_else(invalid)?;
+        let deg: i8 = match ordinal {
+            "unison" => 1,
+            "octave" => 8,
+            _ => ["st", "nd", "rd", "th"]
+                .into_iter()
+                .find_map(|suffix| ordinal.strip_suffix(suffix)?.parse().ok())
+                .ok_or_else(invalid)?,
+        };
+        let (ups, qual) = qual
+            .split_once('-')
+            .and_then(|(count, rest)| counted_ups(count, rest))
+            .or_else(|| {
+                UPS_WORDS
+                    .into_iter()
+                    .find_map(|(word, ups)| Some((ups, qual.strip_prefix(word)?)))
+            })
+            .unwrap_or((0, qual));
+        let qual = match qual {
+            "mid" if ups == 0 => return Self::mid(&deg.to_string(), s),
+            "" if ups != 0 => "P",
+            "perfect" => "P",
+            "major" => "M",
+            "minor" => "m",
+            "augmented" => "A",
+            "diminished" => "d",
+            "doubly augmented" => "AA",
+            "doubly diminished" => "dd",
+            // further alterations are named by their symbols, e.g. `AAA 4th`
+            _ if !qual.is_empty()
+                && (qual.bytes().all(|b| b == b'A') || qual.bytes().all(|b| b == b'd')) =>
+            {
+                qual
+            }
+            _ => return Err(invalid()),
+        };
+        let interval = format!("{qual}{deg}").parse().map_err(|_| invalid())?;
+        Ok(EdoInterval::new(interval, ups))
+    }
+}
+
+impl<const N: u16> FromStr for EdoInterval<N> {
+    type Err = ParseEdoIntervalError;
+
+    /// Parses ups and downs followed by an interval, e.g. `^m7`, `vM3` or `vvP5` (also `vv5`), or
+    /// a mid interval such as `~3` (`~M3` and `~m3` are accepted as well). A leading `-` makes
+    /// the interval descending. Names as in [`EdoInterval::from_name`] are accepted too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(' ') {
+            return Self::from_name(s);
+        }
+        if let Some(s) = s.strip_prefix('-') {
+            return s.parse().map(|interval: Self| -interval);
+        }
+        if s.is_empty() {
+            return Err(ParseEdoIntervalError::EmptyInput);
+        }
+        let invalid = || ParseEdoIntervalError::InvalidInterval(s.to_string());
+        if let Some(deg) = s.strip_prefix('~') {
+            return Self::mid(deg.trim_start_matches(['M', 'm']), s);
+        }
+        let (ups, interval) = split_ups(s);
+        let interval = match interval.parse() {
+            Ok(interval) => interval,
+            // perfect intervals with ups and downs may omit the quality, e.g. `v5`
+            Err(_) if ups != 0 => format!("P{interval}").parse().map_err(|_| invalid())?,
+            Err(_) => return Err(invalid()),
+        };
+        Ok(EdoInterval::new(interval, ups))
+    }
+}
@@ -0,0 +1,149 @@
+use malachite_base::num::basic::traits::Zero;
+
+use crate::pitch::edo12::{Acci, Interval, OPitch, Pitch, Step};
+
+use super::super::temperament::fifths_and_octaves;
+use super::{EDO, fifth_size_of};
+
+/// Pitch in `N`-tone equal temperament, spelled in ups-and-downs notation: a letter with
+/// sharps or flats as in 12-EDO, raised or lowered by a number of edosteps (ups and downs).
+///
+/// `C_0` is middle C, as in [`Pitch`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct EdoPitch<const N: u16> {
+    pub(crate) pitch: Pitch,
+    pub(crate) ups: i8,
+}
+
+/// Interval in `N`-tone equal temperament, i.e. a 12-EDO interval raised or lowered by a number
+/// of edosteps.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EdoInterval<const N: u16> {
+    pub(crate) interval: Interval,
+    pub(crate) ups: i8,
+}
+
+/// Edosteps above middle C of a pitch spelled in 12-EDO, mapping every fifth to `fifth` edosteps
+/// and every octave to `n` edosteps.
+fn edosteps_of(pitch: Pitch, n: i32, fifth: i32) -> i32 {
+    let (fifths, octaves) = fifths_and_octaves(pitch);
+    fifths * fifth + octaves * n
+}
+
+impl<const N: u16> EdoPitch<N> {
+    pub const fn new(pitch: Pitch, ups: i8) -> Self {
+        EdoPitch { pitch, ups }
+    }
+
+    pub fn edo() -> EDO {
+        EDO::new(N as u64)
+    }
+
+    /// The pitch without ups and downs.
+    pub const fn pitch(&self) -> Pitch {
+        self.pitch
+    }
+
+    pub const fn step(&self) -> Step {
+        self.pitch.step
+    }
+
+    pub fn acci(&self) -> Acci {
+        self.pitch.acci()
+    }
+
+    /// Number of ups, negative for downs.
+    pub const fn ups(&self) -> i8 {
+        self.ups
+    }
+
+    /// Edosteps above middle C.
+    pub fn edosteps(&self) -> i32 {
+        let fifth = fifth_size_of(N as u64) as i32;
+        edosteps_of(self.pitch, N as i32, fifth) + self.ups as i32
+    }
+
+    /// Cents above middle C.
+    pub fn cents(&self) -> f64 {
+        self.edosteps() as f64 * 1200.0 / N as f64
+    }
+
+    pub fn is_enharmonic(&self, other: &Self) -> bool {
+        self.edosteps() == other.edosteps()
+    }
+}
+
+impl<const N: u16> EdoInterval<N> {
+    pub const fn new(interval: Interval, ups: i8) -> Self {
+        EdoInterval { interval, ups }
+    }
+
+    /// The interval without ups and downs.
+    pub const fn interval(&self) -> Interval {
+        self.interval
+    }
+
+    /// Number of ups, negative for downs.
+    pub const fn ups(&self) -> i8 {
+        self.ups
+    }
+
+    /// Size in edosteps.
+    pub fn edosteps(&self) -> i32 {
+        let fifth = fifth_size_of(N as u64) as i32;
+        edosteps_of(self.interval.into(), N as i32, fifth) + self.ups as i32
+    }
+
+    pub fn cents(&self) -> f64 {
+        self.edosteps() as f64 * 1200.0 / N as f64
+    }
+}
+
+impl<const N: u16> Zero for EdoPitch<N> {
+    /// Middle C.
+    const ZERO: Self = EdoPitch {
+        pitch: Pitch::ZERO,
+        ups: 0,
+    };
+}
+
+impl<const N: u16> Zero for EdoInterval<N> {
+    const ZERO: Self = EdoInterval {
+        interval: Interval::ZERO,
+        ups: 0,
+    };
+}
+
+impl<const N: u16> From<Pitch> for EdoPitch<N> {
+    /// Maps the pitch through the fifth of the EDO, keeping its spelling.
+    fn from(value: Pitch) -> Self {
+        EdoPitch::new(value, 0)
+    }
+}
+
+impl<const N: u16> From<OPitch> for EdoPitch<N> {
+    /// Creates a pitch in central octave.
+    fn from(value: OPitch) -> Self {
+        Pitch::from(value).into()
+    }
+}
+
+impl<const N: u16> From<Interval> for EdoInterval<N> {
+    fn from(value: Interval) -> Self {
+        EdoInterval::new(value, 0)
+    }
+}
+
+impl<const N: u16> From<EdoPitch<N>> for EdoInterval<N> {
+    /// Interval above middle C.
+    fn from(value: EdoPitch<N>) -> Self {
+        EdoInterval::new(value.pitch.into(), value.ups)
+    }
+}
+
+impl<const N: u16> From<EdoInterval<N>> for EdoPitch<N> {
+    /// Pitch the interval above middle C.
+    fn from(value: EdoInterval<N>) -> Self {
+        EdoPitch::new(value.interval.into(), value.ups)
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::{Display, Formatter, Write as _};
+
+use malachite_base::num::{arithmetic::traits::Abs as _, basic::traits::Zero as _};
+
+use crate::pitch::edo12::{
+    Interval, IntervalQual, notation::Scientific, traits::PitchNotation as _, traits::Qual as _,
+};
+
+use super::parsing::UPS_WORDS;
+use super::{EdoInterval, EdoPitch, sharpness_of};
+
+fn fmt_ups(ups: i8, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let sym = if ups > 0 { '^' } else { 'v' };
+    (0..ups.unsigned_abs()).try_for_each(|_| f.write_char(sym))
+}
+
+impl<const N: u16> Display for EdoPitch<N> {
+    /// Writes ups and downs followed by the pitch in [`Scientific`] notation, e.g. `^Eb4`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_ups(self.ups, f)?;
+        Scientific.fmt_pitch(self.pitch, f)
+    }
+}
+
+impl<const N: u16> EdoInterval<N> {
+    /// Whether the interval lies halfway between the minor and major forms of its degree.
+    ///
+    /// Only the minor form with ups such as `^m3` counts, which is how mid intervals are parsed.
+    /// The major form with downs of the same size such as `vM3` keeps its spelling.
+    pub fn is_mid(&self) -> bool {
+        let sharpness = sharpness_of(N as u64);
+        sharpness > 0
+            && self.interval.qual() == IntervalQual::Minor
+            && self.ups as i64 * 2 == sharpness
+    }
+
+    /// Name of the interval such as `upmajor 3rd`, `mid 6th`, `down 5th`, `perfect octave` or
+    /// `4-up major 3rd`.
+    /// Descending intervals are named as their ascending counterparts.
+    pub fn name(&self) -> String {
+        let interval = if self.interval < Interval::ZERO {
+            -*self
+        } else {
+            *self
+        };
+        let deg = interval.interval.deg().0 + 1;
+        let ordinal = match deg {
+            1 => "unison".to_string(),
+            8 => "octave".to_string(),
+            _ => {
+                let suffix = match (deg % 10, deg % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                format!("{deg}{suffix}")
+            }
+        };
+        if interval.is_mid() {
+            return format!("mid {ordinal}");
+        }
+        // ups beyond the words are counted, e.g. `4-up major 3rd`
+        let (ups, sep) = match UPS_WORDS.iter().find(|(_, ups)| *ups == interval.ups) {
+            Some((word, _)) => (word.to_string(), ""),
+            None if interval.ups == 0 => (String::new(), ""),
+            None if interval.ups > 0 => (format!("{}-up", interval.ups), " "),
+            None => (format!("{}-down", interval.ups.unsigned_abs()), " "),
+        };
+        let qual = match interval.interval.qual() {
+            IntervalQual::Perfect if !ups.is_empty() => String::new(),
+            IntervalQual::Perfect => "perfect".to_string(),
+            IntervalQual::Major => "major".to_string(),
+            IntervalQual::Minor => "minor".to_string(),
+            IntervalQual::Augmented(1) => "augmented".to_string(),
+            IntervalQual::Diminished(1) => "diminished".to_string(),
+            IntervalQual::Augmented(2) => "doubly augmented".to_string(),
+            IntervalQual::Diminished(2) => "doubly diminished".to_string(),
+            // further alterations keep their symbols, e.g. `AAA 4th`
+            qual => qual.to_string(),
+        };
+        if qual.is_empty() {
+            format!("{ups} {ordinal}")
+        } else {
+            format!("{ups}{sep}{qual} {ordinal}")
+        }
+    }
+}
+
+impl<const N: u16> Display for EdoInterval<N> {
+    /// Writes ups and downs followed by the interval, e.g. `^m7` or `vM3`, and mid intervals as
+    /// `~3`. Descending intervals start with `-`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.interval < Interval::ZERO {
+            f.write_char('-')?;
+            return (-*self).fmt(f);
+        }
+        if self.is_mid() {
+            return write!(f, "~{}", self.interval.abs().deg().0 + 1);
+        }
+        fmt_ups(self.ups, f)?;
+        write!(f, "{}", self.interval)
+    }
+}
@@ -0,0 +1,7 @@
+mod base;
+mod parsing;
+mod repr;
+mod tuning;
+
+pub use base::*;
+pub use tuning::*;
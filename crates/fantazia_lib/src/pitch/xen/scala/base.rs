@@ -0,0 +1,96 @@
+use malachite_base::num::arithmetic::traits::DivMod as _;
+
+use crate::pitch::{ji::Ratio, xen::edo::EDO};
+
+/// Pitch of a Scala scale, relative to its first degree.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ScalaPitch {
+    Cents(f64),
+    Ratio(Ratio),
+}
+
+impl ScalaPitch {
+    pub fn cents(&self) -> f64 {
+        match self {
+            ScalaPitch::Cents(cents) => *cents,
+            ScalaPitch::Ratio(ratio) => ratio.cents(),
+        }
+    }
+}
+
+impl From<Ratio> for ScalaPitch {
+    fn from(value: Ratio) -> Self {
+        ScalaPitch::Ratio(value)
+    }
+}
+
+/// Scale in the format of Scala `.scl` files: a description and the pitches of the degrees
+/// above the first one, the last of which is the period (usually the octave) of the scale.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScalaScale {
+    pub(crate) description: String,
+    pub(crate) pitches: Vec<ScalaPitch>,
+}
+
+impl ScalaScale {
+    pub fn new(description: impl Into<String>, pitches: Vec<ScalaPitch>) -> Self {
+        ScalaScale {
+            description: description.into(),
+            pitches,
+        }
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Pitches of the degrees `1..=len`, excluding the implicit `1/1` of degree `0`.
+    pub fn pitches(&self) -> &[ScalaPitch] {
+        &self.pitches
+    }
+
+    /// Number of degrees in a period.
+    pub fn len(&self) -> usize {
+        self.pitches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pitches.is_empty()
+    }
+
+    /// Size of the period in cents, `0.0` for an empty scale.
+    pub fn period_cents(&self) -> f64 {
+        self.pitches.last().map_or(0.0, ScalaPitch::cents)
+    }
+
+    /// Cents of scale degree `degree` above degree `0`, repeating the scale every period.
+    pub fn degree_cents(&self, degree: i32) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let (periods, degree) = degree.div_mod(self.len() as i32);
+        let cents = match degree {
+            0 => 0.0,
+            _ => self.pitches[degree as usize - 1].cents(),
+        };
+        periods as f64 * self.period_cents() + cents
+    }
+}
+
+impl From<&EDO> for ScalaScale {
+    /// Equal division of the octave in cents, with the octave written as `2/1`.
+    fn from(value: &EDO) -> Self {
+        let n = value.edo();
+        let pitches = (1..n)
+            .map(|i| ScalaPitch::Cents(1200.0 * i as f64 / n as f64))
+            .chain([ScalaPitch::Ratio(Ratio::OCTAVE)])
+            .collect();
+        ScalaScale::new(format!("{n} equal divisions of the octave"), pitches)
+    }
+}
+
+impl From<EDO> for ScalaScale {
+    fn from(value: EDO) -> Self {
+        (&value).into()
+    }
+}
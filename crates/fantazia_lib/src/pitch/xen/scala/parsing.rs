@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use crate::pitch::{edo12::MAX_MIDI_KEY, ji::Ratio};
+
+use super::{KeyboardMapping, ScalaPitch, ScalaScale};
+
+pub mod err {
+    use thiserror::Error;
+
+    use crate::pitch::ji::parsing::err::ParseRatioError;
+
+    #[derive(Debug, Error)]
+    pub enum ParseScalaPitchError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error("Invalid cents: `{0}`.")]
+        InvalidCents(String),
+        #[error(transparent)]
+        InvalidRatio(#[from] ParseRatioError),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ParseScalaError {
+        #[error("Missing description line.")]
+        MissingDescription,
+        #[error("Missing number of notes.")]
+        MissingCount,
+        #[error("Invalid number of notes: `{0}`.")]
+        InvalidCount(String),
+        #[error("Expected {expected} notes, found {found}.")]
+        MissingPitches { expected: usize, found: usize },
+        #[error("Invalid pitch on line {line}: {source}")]
+        InvalidPitch {
+            line: usize,
+            source: ParseScalaPitchError,
+        },
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ParseKbmError {
+        #[error("Missing {0}.")]
+        MissingField(&'static str),
+        #[error("Invalid {field}: `{value}`.")]
+        InvalidField { field: &'static str, value: String },
+        #[error("Invalid mapping entry: `{0}`.")]
+        InvalidMapping(String),
+        #[error("Mapping has {found} entries, more than its size {size}.")]
+        TooManyEntries { size: usize, found: usize },
+    }
+}
+
+use err::{ParseKbmError, ParseScalaError, ParseScalaPitchError};
+
+impl FromStr for ScalaPitch {
+    type Err = ParseScalaPitchError;
+
+    /// Parses a pitch line of a `.scl` file: cents if it contains a `.` (e.g. `701.955` or
+    /// `100.`), a ratio otherwise (e.g. `3/2` or `2`). Text after the first whitespace is
+    /// ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .split_whitespace()
+            .next()
+            .ok_or(ParseScalaPitchError::EmptyInput)?;
+        if s.contains('.') {
+            s.parse()
+                .map(ScalaPitch::Cents)
+                .map_err(|_| ParseScalaPitchError::InvalidCents(s.to_string()))
+        } else {
+            Ok(ScalaPitch::Ratio(s.parse::<Ratio>()?))
+        }
+    }
+}
+
+/// Lines of a Scala file that are not comments, with their line numbers starting at `1`.
+fn lines(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end_matches('\r')))
+        .filter(|(_, line)| !line.starts_with('!'))
+}
+
+impl FromStr for ScalaScale {
+    type Err = ParseScalaError;
+
+    /// Parses the contents of a `.scl` file. Lines starting with `!` are comments.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = lines(s);
+        let (_, description) = lines.next().ok_or(ParseScalaError::MissingDescription)?;
+        let (_, count) = lines.next().ok_or(ParseScalaError::MissingCount)?;
+        let count = count
+            .split_whitespace()
+            .next()
+            .ok_or(ParseScalaError::MissingCount)?;
+        let count: usize = count
+            .parse()
+            .map_err(|_| ParseScalaError::InvalidCount(count.to_string()))?;
+        let pitches = lines
+            .filter(|(_, line)| !line.trim().is_empty())
+            .take(count)
+            .map(|(line, s)| {
+                s.parse()
+                    .map_err(|source| ParseScalaError::InvalidPitch { line, source })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if pitches.len() < count {
+            return Err(ParseScalaError::MissingPitches {
+                expected: count,
+                found: pitches.len(),
+            });
+        }
+        Ok(ScalaScale::new(description.trim(), pitches))
+    }
+}
+
+impl FromStr for KeyboardMapping {
+    type Err = ParseKbmError;
+
+    /// Parses the contents of a `.kbm` file. Lines starting with `!` are comments. Mappings
+    /// with fewer entries than their size leave the remaining keys unmapped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = lines(s)
+            .map(|(_, line)| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split_whitespace().next().unwrap_or(line));
+        let mut field = |field: &'static str| {
+            lines
+                .next()
+                .ok_or(ParseKbmError::MissingField(field))
+                .map(|value| (field, value))
+        };
+        fn parse<T: FromStr>((field, value): (&'static str, &str)) -> Result<T, ParseKbmError> {
+            value.parse().map_err(|_| ParseKbmError::InvalidField {
+                field,
+                value: value.to_string(),
+            })
+        }
+        let key = |field| {
+            parse::<u8>(field).and_then(|key| match key {
+                0..=MAX_MIDI_KEY => Ok(key),
+                _ => Err(ParseKbmError::InvalidField {
+                    field: field.0,
+                    value: key.to_string(),
+                }),
+            })
+        };
+        let size: usize = parse(field("map size")?)?;
+        let first_key = key(field("first MIDI key")?)?;
+        let last_key = key(field("last MIDI key")?)?;
+        let middle_key = key(field("middle key")?)?;
+        let reference_key = key(field("reference key")?)?;
+        let reference_freq: f64 = parse(field("reference frequency")?)?;
+        let octave_degree: usize = parse(field("octave degree")?)?;
+        let keys = lines
+            .map(|entry| match entry {
+                "x" | "X" => Ok(None),
+                _ => entry
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| ParseKbmError::InvalidMapping(entry.to_string())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.len() > size {
+            return Err(ParseKbmError::TooManyEntries {
+                size,
+                found: keys.len(),
+            });
+        }
+        Ok(KeyboardMapping {
+            first_key,
+            last_key,
+            middle_key,
+            reference_key,
+            reference_freq,
+            octave_degree,
+            keys,
+            size,
+        })
+    }
+}
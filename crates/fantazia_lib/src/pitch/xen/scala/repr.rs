@@ -0,0 +1,62 @@
+use std::fmt::{Display, Formatter};
+
+use super::{KeyboardMapping, ScalaPitch, ScalaScale};
+
+impl Display for ScalaPitch {
+    /// Writes the pitch as a line of a `.scl` file, e.g. `701.955` or `3/2`. Cents always
+    /// contain a `.` so that they are not read back as ratios.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScalaPitch::Cents(cents) if cents.fract() == 0.0 => write!(f, "{cents:.1}"),
+            ScalaPitch::Cents(cents) => write!(f, "{cents}"),
+            ScalaPitch::Ratio(ratio) => write!(f, "{ratio}"),
+        }
+    }
+}
+
+impl Display for ScalaScale {
+    /// Writes the contents of a `.scl` file.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "! Generated by fantazia")?;
+        writeln!(f, "!")?;
+        writeln!(f, "{}", self.description)?;
+        writeln!(f, " {}", self.pitches.len())?;
+        writeln!(f, "!")?;
+        for pitch in &self.pitches {
+            writeln!(f, " {pitch}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for KeyboardMapping {
+    /// Writes the contents of a `.kbm` file.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "! Generated by fantazia")?;
+        writeln!(f, "! Size of map:")?;
+        writeln!(f, "{}", self.size)?;
+        writeln!(f, "! First MIDI note number to retune:")?;
+        writeln!(f, "{}", self.first_key)?;
+        writeln!(f, "! Last MIDI note number to retune:")?;
+        writeln!(f, "{}", self.last_key)?;
+        writeln!(
+            f,
+            "! Middle note where the first entry of the mapping is mapped to:"
+        )?;
+        writeln!(f, "{}", self.middle_key)?;
+        writeln!(f, "! Reference note for which frequency is given:")?;
+        writeln!(f, "{}", self.reference_key)?;
+        writeln!(f, "! Frequency to tune the above note to:")?;
+        writeln!(f, "{:?}", self.reference_freq)?;
+        writeln!(f, "! Scale degree to consider as formal octave:")?;
+        writeln!(f, "{}", self.octave_degree)?;
+        writeln!(f, "! Mapping:")?;
+        for key in &self.keys {
+            match key {
+                Some(degree) => writeln!(f, "{degree}")?,
+                None => writeln!(f, "x")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use malachite_base::num::arithmetic::traits::DivMod as _;
+
+use crate::pitch::edo12::{MAX_MIDI_KEY, MIDDLE_C_KEY, Pitch};
+
+use super::ScalaScale;
+
+/// Keyboard mapping in the format of Scala `.kbm` files, assigning scale degrees to MIDI keys.
+///
+/// The mapping repeats every `size` keys, moving up by the scale degree `octave_degree` each
+/// time. A mapping of size `0` is linear: every key is one scale degree above the previous.
+#[derive(Clone, PartialEq, Debug)]
+pub struct KeyboardMapping {
+    pub(crate) first_key: u8,
+    pub(crate) last_key: u8,
+    pub(crate) middle_key: u8,
+    pub(crate) reference_key: u8,
+    pub(crate) reference_freq: f64,
+    pub(crate) octave_degree: usize,
+    pub(crate) keys: Vec<Option<usize>>,
+    pub(crate) size: usize,
+}
+
+impl KeyboardMapping {
+    /// Linear mapping of all MIDI keys with scale degree `0` on `middle_key`, and with
+    /// `reference_key` tuned to `reference_freq` Hz.
+    pub fn linear(middle_key: u8, reference_key: u8, reference_freq: f64) -> Self {
+        KeyboardMapping {
+            first_key: 0,
+            last_key: MAX_MIDI_KEY,
+            middle_key,
+            reference_key,
+            reference_freq,
+            octave_degree: 0,
+            keys: Vec::new(),
+            size: 0,
+        }
+    }
+
+    /// Mapping repeating every `keys.len()` MIDI keys, where `None` leaves a key unmapped.
+    pub fn new(
+        middle_key: u8,
+        reference_key: u8,
+        reference_freq: f64,
+        octave_degree: usize,
+        keys: Vec<Option<usize>>,
+    ) -> Self {
+        KeyboardMapping {
+            size: keys.len(),
+            octave_degree,
+            keys,
+            ..Self::linear(middle_key, reference_key, reference_freq)
+        }
+    }
+
+    /// Restricts the mapping to the MIDI keys `first_key..=last_key`.
+    pub fn with_range(self, first_key: u8, last_key: u8) -> Self {
+        KeyboardMapping {
+            first_key,
+            last_key,
+            ..self
+        }
+    }
+
+    pub fn first_key(&self) -> u8 {
+        self.first_key
+    }
+
+    pub fn last_key(&self) -> u8 {
+        self.last_key
+    }
+
+    /// MIDI key of scale degree `0`.
+    pub fn middle_key(&self) -> u8 {
+        self.middle_key
+    }
+
+    pub fn reference_key(&self) -> u8 {
+        self.reference_key
+    }
+
+    pub fn reference_freq(&self) -> f64 {
+        self.reference_freq
+    }
+
+    /// Scale degree of the formal octave, by which the mapping moves up every repetition.
+    pub fn octave_degree(&self) -> usize {
+        self.octave_degree
+    }
+
+    /// Scale degrees of the keys in one repetition of the mapping, empty for linear mappings.
+    pub fn keys(&self) -> &[Option<usize>] {
+        &self.keys
+    }
+
+    /// Number of keys in one repetition of the mapping, `0` for linear mappings. It can exceed
+    /// the length of [`keys`](Self::keys), leaving the remaining keys unmapped.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Number of repetitions of the mapping above the middle key and the scale degree within
+    /// the repetition that `key` is mapped to, regardless of the range of the mapping.
+    fn degree_of(&self, key: u8) -> Option<(i32, i32)> {
+        let offset = key as i32 - self.middle_key as i32;
+        if self.size == 0 {
+            return Some((0, offset));
+        }
+        let (repetitions, i) = offset.div_mod(self.size as i32);
+        let degree = (*self.keys.get(i as usize)?)?;
+        Some((repetitions, degree as i32))
+    }
+}
+
+impl Default for KeyboardMapping {
+    /// Linear mapping with scale degree `0` on middle C and A4 tuned to 440 Hz.
+    fn default() -> Self {
+        Self::linear(MIDDLE_C_KEY, 69, 440.0)
+    }
+}
+
+/// Tuning of the MIDI keys by a Scala scale and keyboard mapping.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Tuning {
+    pub(crate) scale: ScalaScale,
+    pub(crate) mapping: KeyboardMapping,
+}
+
+impl Tuning {
+    pub fn new(scale: ScalaScale, mapping: KeyboardMapping) -> Self {
+        Tuning { scale, mapping }
+    }
+
+    pub fn scale(&self) -> &ScalaScale {
+        &self.scale
+    }
+
+    pub fn mapping(&self) -> &KeyboardMapping {
+        &self.mapping
+    }
+
+    /// Cents of the pitch that `key` is mapped to above scale degree `0`.
+    fn cents_of(&self, key: u8) -> Option<f64> {
+        let (repetitions, degree) = self.mapping.degree_of(key)?;
+        let octave = match self.mapping.size {
+            0 => 0.0,
+            _ => self.scale.degree_cents(self.mapping.octave_degree as i32),
+        };
+        Some(repetitions as f64 * octave + self.scale.degree_cents(degree))
+    }
+
+    /// Frequency of MIDI key `key` in Hz.
+    ///
+    /// Returns `None` if the key is out of the range of the mapping or unmapped, or if the
+    /// reference key is unmapped.
+    pub fn key_freq(&self, key: u8) -> Option<f64> {
+        if !(self.mapping.first_key..=self.mapping.last_key).contains(&key) {
+            return None;
+        }
+        let cents = self.cents_of(key)? - self.cents_of(self.mapping.reference_key)?;
+        Some(self.mapping.reference_freq * (cents / 1200.0).exp2())
+    }
+
+    /// Frequency of the MIDI key of `pitch` in Hz, taking `Pitch::ZERO` as middle C.
+    ///
+    /// Returns `None` if the key is out of range or not tuned, as in [`Tuning::key_freq`].
+    pub fn freq(&self, pitch: Pitch) -> Option<f64> {
+        self.key_freq(pitch.midi_key()?)
+    }
+
+    /// Frequencies of all MIDI keys, `None` for keys that are not tuned.
+    pub fn table(&self) -> [Option<f64>; MAX_MIDI_KEY as usize + 1] {
+        std::array::from_fn(|key| self.key_freq(key as u8))
+    }
+}
+
+impl From<ScalaScale> for Tuning {
+    /// Tuning with the [default](KeyboardMapping::default) keyboard mapping.
+    fn from(value: ScalaScale) -> Self {
+        Tuning::new(value, KeyboardMapping::default())
+    }
+}
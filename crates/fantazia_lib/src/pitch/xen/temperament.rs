@@ -0,0 +1,128 @@
+use crate::pitch::{
+    edo12::{ConcertPitch, Interval, OPitch, Pitch, traits::Co5Order as _},
+    ji::Ratio,
+};
+
+use super::edo::EDO;
+
+/// Number of fifths and octaves that add up to `pitch` from middle C, i.e. its co5 order and
+/// the octaves left after stacking that many fifths.
+pub(crate) fn fifths_and_octaves(pitch: Pitch) -> (i32, i32) {
+    let (opitch, octave) = pitch.opitch_and_octave();
+    let fifths = opitch.co5_order() as i32;
+    let octaves = (opitch.tone as i32 - 7 * fifths) / 12 + octave as i32;
+    (fifths, octaves)
+}
+
+/// Rank-2 temperament of the fifth-based pitch system, generated by a period and a generator
+/// in cents that take the places of the octave and the fifth.
+///
+/// A spelled pitch is realized by stacking as many generators as its co5 order and filling up
+/// with periods, so that for example `E_0` is four generators minus two periods above middle C.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Temperament {
+    pub(crate) period: f64,
+    pub(crate) generator: f64,
+}
+
+impl Temperament {
+    /// Pure octaves and fifths.
+    pub const PYTHAGOREAN: Self = Temperament::new(1200.0, 701.955_000_865_387_4);
+
+    /// Meantone with pure major thirds, tempering the fifth by a quarter of the syntonic comma.
+    pub const QUARTER_COMMA_MEANTONE: Self = Temperament::new(1200.0, 696.578_428_466_208_6);
+
+    /// Size of the syntonic comma `81/80` in cents.
+    const SYNTONIC_COMMA: f64 = 21.506_289_596_714_78;
+
+    /// Size of the schisma `32805/32768` in cents.
+    const SCHISMA: f64 = 1.953_720_787_934_16;
+
+    pub const fn new(period: f64, generator: f64) -> Self {
+        Temperament { period, generator }
+    }
+
+    /// Temperament with pure octaves and the fifth `generator`.
+    pub fn from_generator_ratio(generator: Ratio) -> Self {
+        Temperament::new(1200.0, generator.cents())
+    }
+
+    /// Meantone with pure octaves whose fifth is flattened by `fraction` of the syntonic comma,
+    /// e.g. `1/4` for quarter-comma and `1/6` for sixth-comma meantone.
+    pub fn meantone(fraction: f64) -> Self {
+        Temperament::new(
+            1200.0,
+            Self::PYTHAGOREAN.generator - fraction * Self::SYNTONIC_COMMA,
+        )
+    }
+
+    /// Schismatic temperament with pure octaves whose fifth is flattened by `fraction` of the
+    /// schisma, e.g. `1/8` for Helmholtz's temperament with a pure `5/4` as `d4`.
+    pub fn schismatic(fraction: f64) -> Self {
+        Temperament::new(
+            1200.0,
+            Self::PYTHAGOREAN.generator - fraction * Self::SCHISMA,
+        )
+    }
+
+    pub const fn period(&self) -> f64 {
+        self.period
+    }
+
+    pub const fn generator(&self) -> f64 {
+        self.generator
+    }
+
+    /// Size of the augmented unison in cents, i.e. seven generators minus four periods. It is
+    /// negative if sharps are lower than their enharmonic flats a step above.
+    pub fn sharpness(&self) -> f64 {
+        7.0 * self.generator - 4.0 * self.period
+    }
+
+    /// Cents of `pitch` above middle C.
+    pub fn cents(&self, pitch: Pitch) -> f64 {
+        let (fifths, octaves) = fifths_and_octaves(pitch);
+        fifths as f64 * self.generator + octaves as f64 * self.period
+    }
+
+    /// Cents of `opitch` above middle C, in the central octave.
+    pub fn ocents(&self, opitch: OPitch) -> f64 {
+        self.cents(opitch.into())
+    }
+
+    /// Size of `interval` in cents.
+    pub fn interval_cents(&self, interval: Interval) -> f64 {
+        self.cents(interval.into())
+    }
+
+    /// How much larger `interval` is in this temperament than in `other`, in cents.
+    pub fn compare(&self, other: &Self, interval: Interval) -> f64 {
+        self.interval_cents(interval) - other.interval_cents(interval)
+    }
+
+    /// How much larger `interval` is in this temperament than the just `ratio`, in cents.
+    pub fn deviation(&self, interval: Interval, ratio: Ratio) -> f64 {
+        self.interval_cents(interval) - ratio.cents()
+    }
+
+    /// Frequency of `pitch` in Hz, with the reference pitch of `concert` realized in this
+    /// temperament as well.
+    pub fn freq(&self, pitch: Pitch, concert: &ConcertPitch) -> f64 {
+        let cents = self.cents(pitch) - self.cents(concert.pitch());
+        concert.freq() * (cents / 1200.0).exp2()
+    }
+}
+
+impl From<&EDO> for Temperament {
+    /// Temperament generated by the fifth of the EDO.
+    fn from(value: &EDO) -> Self {
+        let step = 1200.0 / value.edo() as f64;
+        Temperament::new(1200.0, value.fifth_size() as f64 * step)
+    }
+}
+
+impl From<EDO> for Temperament {
+    fn from(value: EDO) -> Self {
+        (&value).into()
+    }
+}
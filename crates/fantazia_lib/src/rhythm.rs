@@ -3,4 +3,8 @@ mod base;
 mod cmp;
 mod parsing;
 mod repr;
+mod seq;
+mod tuplet;
 pub use base::*;
+pub use seq::*;
+pub use tuplet::*;
@@ -1 +1,4 @@
+mod add;
 mod bit_ops;
+mod scalar_mul;
+mod sub;
@@ -0,0 +1,57 @@
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
+
+use malachite_base::num::{arithmetic::traits::CheckedLcm as _, basic::traits::Zero as _};
+
+use crate::rhythm::Duration;
+
+impl Duration {
+    /// Sum of two durations, or `None` if it does not fit into a `u64` fraction.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let denom = self.denom.checked_lcm(rhs.denom)?;
+        let numer = self
+            .numer
+            .checked_mul(denom / self.denom)?
+            .checked_add(rhs.numer.checked_mul(denom / rhs.denom)?)?;
+        Duration::new(numer, denom).ok()
+    }
+
+    /// Total length of `durations`, or `None` if it does not fit into a `u64` fraction.
+    pub fn checked_sum(durations: impl IntoIterator<Item = Duration>) -> Option<Self> {
+        durations
+            .into_iter()
+            .try_fold(Self::ZERO, Duration::checked_add)
+    }
+}
+
+impl Add for Duration {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the sum does not fit into a `u64` fraction, which [`Duration::checked_add`]
+    /// reports as `None` instead.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("duration overflow")
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sum for Duration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Duration> for Duration {
+    fn sum<I: Iterator<Item = &'a Duration>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
@@ -4,12 +4,14 @@ use malachite_base::num::arithmetic::traits::{ArithmeticCheckedShl, ArithmeticCh
 
 use super::super::BinaryDuration;
 
+#[allow(clippy::suspicious_op_assign_impl)]
 impl ShlAssign<i8> for BinaryDuration {
     fn shl_assign(&mut self, rhs: i8) {
         self.kind += rhs;
     }
 }
 
+#[allow(clippy::suspicious_op_assign_impl)]
 impl ShrAssign<i8> for BinaryDuration {
     fn shr_assign(&mut self, rhs: i8) {
         self.kind -= rhs;
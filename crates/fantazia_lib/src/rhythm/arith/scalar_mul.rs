@@ -0,0 +1,69 @@
+use std::ops::{Div, DivAssign, Mul, MulAssign};
+
+use malachite_base::num::arithmetic::traits::Gcd as _;
+
+use crate::rhythm::Duration;
+
+impl Duration {
+    /// The duration repeated `rhs` times, or `None` if it does not fit into a `u64` fraction.
+    pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+        let gcd = self.denom.gcd(rhs);
+        let numer = self.numer.checked_mul(rhs / gcd)?;
+        Duration::new(numer, self.denom / gcd).ok()
+    }
+
+    /// Divides the duration into `rhs` equal parts, or returns `None` if `rhs` is zero or the
+    /// quotient does not fit into a `u64` fraction.
+    pub fn checked_div(self, rhs: u64) -> Option<Self> {
+        if rhs == 0 {
+            return None;
+        }
+        let gcd = self.numer.gcd(rhs);
+        let denom = self.denom.checked_mul(rhs / gcd)?;
+        Duration::new(self.numer / gcd, denom).ok()
+    }
+}
+
+impl Mul<u64> for Duration {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the product does not fit into a `u64` fraction, which [`Duration::checked_mul`]
+    /// reports as `None` instead.
+    fn mul(self, rhs: u64) -> Self::Output {
+        self.checked_mul(rhs).expect("duration overflow")
+    }
+}
+
+impl Mul<Duration> for u64 {
+    type Output = Duration;
+
+    fn mul(self, rhs: Duration) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Div<u64> for Duration {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient does not fit into a `u64` fraction, which
+    /// [`Duration::checked_div`] reports as `None` instead.
+    fn div(self, rhs: u64) -> Self::Output {
+        self.checked_div(rhs).expect("invalid duration division")
+    }
+}
+
+impl MulAssign<u64> for Duration {
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<u64> for Duration {
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
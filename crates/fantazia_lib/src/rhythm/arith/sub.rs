@@ -0,0 +1,35 @@
+use std::ops::{Sub, SubAssign};
+
+use malachite_base::num::arithmetic::traits::CheckedLcm as _;
+
+use crate::rhythm::Duration;
+
+impl Duration {
+    /// Difference of two durations, or `None` if `rhs` is longer than `self`.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let denom = self.denom.checked_lcm(rhs.denom)?;
+        let numer = self
+            .numer
+            .checked_mul(denom / self.denom)?
+            .checked_sub(rhs.numer.checked_mul(denom / rhs.denom)?)?;
+        Duration::new(numer, denom).ok()
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is longer than `self`, which [`Duration::checked_sub`] reports as `None`
+    /// instead.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("duration underflow")
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
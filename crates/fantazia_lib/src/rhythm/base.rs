@@ -1,123 +1,355 @@
-// use compact_str::CompactString;
-// use tinyvec::TinyVec;
-
-// type TimeSigVec = TinyVec<[SingleTimeSig; 3]>;
-// type TimeSigNumVec = TinyVec<[u8; 3]>;
-
-// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-// pub enum TimeSigNum {
-//     Simple(u8),
-//     Additive(TimeSigNumVec),
-// }
-
-// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-// pub struct SingleTimeSig {
-//     pub(crate) num: TimeSigNum,
-//     pub(crate) den: u8,
-//     pub(crate) sym: Option<CompactString>,
-// }
-
-// impl SingleTimeSig {
-//     pub fn new_simple(num: u8, den: u8, sym: Option<CompactString>) -> Self {
-//         Self {
-//             num: TimeSigNum::Simple(num),
-//             den,
-//             sym,
-//         }
-//     }
-
-//     pub fn new_additive(num: impl Iterator<Item = u8>, den: u8, sym: Option<CompactString>) -> Self {
-//         Self {
-//             num: TimeSigNum::Additive(num.collect()),
-//             den,
-//             sym,
-//         }
-//     }
-
-//     pub fn to_simple(&self) -> Self {
-//         use TimeSigNum::*;
-//         match &self.num {
-//             Simple(_) => self.clone(),
-//             Additive(nums) => {
-//                 let num = nums.into_iter().sum();
-//                 Self {
-//                     num: TimeSigNum::Simple(num),
-//                     den: self.den,
-//                     sym: self.sym.clone(),
-//                 }
-//             }
-//         }
-//     }
-
-//     pub fn into_simple(mut self) -> Self {
-//         use TimeSigNum::*;
-//         match self.num {
-//             Simple(_) => self,
-//             Additive(nums) => {
-//                 let num = nums.into_iter().sum();
-//                 self.num = TimeSigNum::Simple(num);
-//                 self
-//             }
-//         }
-//     }
-
-//     pub fn into_num(self) -> TimeSigNum {
-//         self.num
-//     }
-
-//     pub fn to_num(self) -> TimeSigNum {
-//         self.num.clone()
-//     }
-
-//     pub fn num_ref(&self) -> &TimeSigNum {
-//         &self.num
-//     }
-
-//     pub fn den(&self) -> u8 {
-//         self.den
-//     }
-
-//     pub fn into_sym(self) -> Option<CompactString> {
-//         self.sym
-//     }
-
-//     pub fn to_sym(self) -> Option<CompactString> {
-//         self.sym.clone()
-//     }
-
-//     pub fn sym_ref(&self) -> Option<&CompactString> {
-//         self.sym.as_ref()
-//     }
-// }
-
-// impl Default for SingleTimeSig {
-//     fn default() -> Self {
-//         Self {
-//             num: TimeSigNum::Simple(4),
-//             den: 4,
-//             sym: None,
-//         }
-//     }
-// }
-
-// pub struct InterchangeableTimeSig {
-//     pub(crate) main: Box<TimeSig>,
-//     pub(crate) alternative: Box<TimeSig>,
-// }
-// pub struct AggregateTimeSig(TimeSigVec);
-// pub struct AlternatingTimeSig(TimeSigVec);
-
-// pub struct OpenTimeSig {
-//     sym: Option<CompactString>,
-// }
-
-// pub enum TimeSig {
-//     Simple(SingleTimeSig),
-//     Interchangeable(InterchangeableTimeSig),
-//     Aggregate(AggregateTimeSig),
-//     Alternating(AlternatingTimeSig),
-//     Open(OpenTimeSig),
-// }
+use malachite_base::num::{arithmetic::traits::Gcd as _, basic::traits::Zero};
+
+use super::parsing::err::{DurationOverflow, InvalidDuration, InvalidTimeSig, NotBinaryDuration};
+
+use compact_str::CompactString;
+use tinyvec::TinyVec;
+
+type TimeSigVec = TinyVec<[SingleTimeSig; 3]>;
+type TimeSigNumVec = TinyVec<[u8; 3]>;
+
+/// Numerator of a time signature, either a single number or a sum such as `3+2+2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TimeSigNum {
+    Simple(u8),
+    Additive(TimeSigNumVec),
+}
+
+impl TimeSigNum {
+    /// Number of units in a measure, i.e. the sum of an additive numerator, which the
+    /// constructors of [`SingleTimeSig`] keep within `u8`.
+    pub fn total(&self) -> u8 {
+        match self {
+            TimeSigNum::Simple(num) => *num,
+            TimeSigNum::Additive(nums) => nums.iter().sum(),
+        }
+    }
+}
+
+/// How the beats of a time signature divide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Meter {
+    /// Beats of one unit, dividing into two, e.g. `2/4`, `3/4`, `4/4` or `5/4`.
+    Simple,
+    /// Dotted beats of three units, e.g. `6/8`, `9/8` or `12/8`.
+    Compound,
+    /// Beats of two and three units, e.g. `5/8`, `7/8` or `3+2+2/8`.
+    Irregular,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SingleTimeSig {
+    pub(crate) num: TimeSigNum,
+    pub(crate) den: u8,
+    pub(crate) sym: Option<CompactString>,
+}
+
+impl SingleTimeSig {
+    pub const COMMON_TIME: &str = "C";
+    pub const CUT_TIME: &str = "C|";
+
+    /// Creates the time signature `num/den`, or an error if `num` is zero or `den` is not a
+    /// power of two.
+    pub fn new_simple(
+        num: u8,
+        den: u8,
+        sym: Option<CompactString>,
+    ) -> Result<Self, InvalidTimeSig> {
+        if num == 0 {
+            return Err(InvalidTimeSig::Numerator);
+        }
+        if !den.is_power_of_two() {
+            return Err(InvalidTimeSig::Denominator(den));
+        }
+        Ok(Self {
+            num: TimeSigNum::Simple(num),
+            den,
+            sym,
+        })
+    }
+
+    /// Creates a time signature with the additive numerator `num`, e.g. `3+2+2/8`, or an error
+    /// if a part is zero, the parts add up to more than `255` or `den` is not a power of two.
+    pub fn new_additive(
+        num: impl Iterator<Item = u8>,
+        den: u8,
+        sym: Option<CompactString>,
+    ) -> Result<Self, InvalidTimeSig> {
+        let nums: TimeSigNumVec = num.collect();
+        let total = nums.iter().try_fold(0u8, |total, &num| {
+            (num > 0).then(|| total.checked_add(num))?
+        });
+        if nums.is_empty() || total.is_none() {
+            return Err(InvalidTimeSig::Numerator);
+        }
+        if !den.is_power_of_two() {
+            return Err(InvalidTimeSig::Denominator(den));
+        }
+        Ok(Self {
+            num: TimeSigNum::Additive(nums),
+            den,
+            sym,
+        })
+    }
+
+    /// `4/4` written as `C`.
+    pub fn common_time() -> Self {
+        Self {
+            num: TimeSigNum::Simple(4),
+            den: 4,
+            sym: Some(Self::COMMON_TIME.into()),
+        }
+    }
+
+    /// `2/2` written as `C|`.
+    pub fn cut_time() -> Self {
+        Self {
+            num: TimeSigNum::Simple(2),
+            den: 2,
+            sym: Some(Self::CUT_TIME.into()),
+        }
+    }
+
+    pub fn to_simple(&self) -> Self {
+        use TimeSigNum::*;
+        match &self.num {
+            Simple(_) => self.clone(),
+            Additive(nums) => {
+                let num = nums.into_iter().sum();
+                Self {
+                    num: TimeSigNum::Simple(num),
+                    den: self.den,
+                    sym: self.sym.clone(),
+                }
+            }
+        }
+    }
+
+    pub fn into_simple(mut self) -> Self {
+        use TimeSigNum::*;
+        match self.num {
+            Simple(_) => self,
+            Additive(nums) => {
+                let num = nums.into_iter().sum();
+                self.num = TimeSigNum::Simple(num);
+                self
+            }
+        }
+    }
+
+    pub fn into_num(self) -> TimeSigNum {
+        self.num
+    }
+
+    pub fn to_num(&self) -> TimeSigNum {
+        self.num.clone()
+    }
+
+    pub fn num_ref(&self) -> &TimeSigNum {
+        &self.num
+    }
+
+    pub fn den(&self) -> u8 {
+        self.den
+    }
+
+    pub fn into_sym(self) -> Option<CompactString> {
+        self.sym
+    }
+
+    pub fn to_sym(&self) -> Option<CompactString> {
+        self.sym.clone()
+    }
+
+    pub fn sym_ref(&self) -> Option<&CompactString> {
+        self.sym.as_ref()
+    }
+
+    /// Length of the unit that the denominator counts, e.g. `1/8` for `6/8`.
+    pub fn unit(&self) -> Duration {
+        // the denominator is a power of two, hence positive
+        Duration {
+            numer: 1,
+            denom: self.den as u64,
+        }
+    }
+
+    /// Length of a measure.
+    pub fn measure_len(&self) -> Duration {
+        self.unit() * self.num.total() as u64
+    }
+
+    pub fn meter(&self) -> Meter {
+        match self.num {
+            TimeSigNum::Additive(_) => Meter::Irregular,
+            TimeSigNum::Simple(num) if num > 3 && num % 3 == 0 => Meter::Compound,
+            // quintuple and septuple meters in quarters or longer are counted in single units
+            TimeSigNum::Simple(num) if num <= 4 || self.den <= 4 => Meter::Simple,
+            TimeSigNum::Simple(_) => Meter::Irregular,
+        }
+    }
+
+    /// Numbers of units in each beat of a measure, e.g. `[3, 3]` for `6/8`, `[3, 2, 2]` for
+    /// `3+2+2/8` and `[2, 2, 3]` for `7/8`.
+    pub fn beat_groups(&self) -> Vec<u8> {
+        match (&self.num, self.meter()) {
+            (TimeSigNum::Additive(nums), _) => nums.to_vec(),
+            (TimeSigNum::Simple(num), Meter::Simple) => vec![1; *num as usize],
+            (TimeSigNum::Simple(num), Meter::Compound) => vec![3; *num as usize / 3],
+            (TimeSigNum::Simple(num), Meter::Irregular) => {
+                // groups of two, the last of which takes the odd unit
+                let mut groups = vec![2; *num as usize / 2];
+                if num % 2 == 1 {
+                    *groups.last_mut().unwrap() = 3;
+                }
+                groups
+            }
+        }
+    }
+
+    /// Lengths of the beats of a measure, following [`SingleTimeSig::beat_groups`].
+    pub fn beats(&self) -> Vec<Duration> {
+        let unit = self.unit();
+        self.beat_groups()
+            .into_iter()
+            .map(|units| unit * units as u64)
+            .collect()
+    }
+}
+
+impl Default for SingleTimeSig {
+    fn default() -> Self {
+        Self {
+            num: TimeSigNum::Simple(4),
+            den: 4,
+            sym: None,
+        }
+    }
+}
+
+/// Time signature of two meters that measures may freely switch between, e.g. `3/4(6/8)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InterchangeableTimeSig {
+    pub(crate) main: Box<TimeSig>,
+    pub(crate) alternative: Box<TimeSig>,
+}
+
+impl InterchangeableTimeSig {
+    pub fn new(main: TimeSig, alternative: TimeSig) -> Self {
+        InterchangeableTimeSig {
+            main: Box::new(main),
+            alternative: Box::new(alternative),
+        }
+    }
+
+    pub fn main(&self) -> &TimeSig {
+        &self.main
+    }
+
+    pub fn alternative(&self) -> &TimeSig {
+        &self.alternative
+    }
+}
+
+/// Time signature whose measures consist of parts in different meters, e.g. `2/4+3/8`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AggregateTimeSig(pub(crate) TimeSigVec);
+
+impl AggregateTimeSig {
+    pub fn new(sigs: impl IntoIterator<Item = SingleTimeSig>) -> Self {
+        AggregateTimeSig(sigs.into_iter().collect())
+    }
+
+    pub fn sigs(&self) -> &[SingleTimeSig] {
+        &self.0
+    }
+}
+
+/// Time signatures taking turns measure by measure, e.g. `3/4 2/4`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlternatingTimeSig(pub(crate) TimeSigVec);
+
+impl AlternatingTimeSig {
+    pub fn new(sigs: impl IntoIterator<Item = SingleTimeSig>) -> Self {
+        AlternatingTimeSig(sigs.into_iter().collect())
+    }
+
+    pub fn sigs(&self) -> &[SingleTimeSig] {
+        &self.0
+    }
+}
+
+/// Absence of a meter, written as `X` unless another symbol is given.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct OpenTimeSig {
+    pub(crate) sym: Option<CompactString>,
+}
+
+impl OpenTimeSig {
+    pub fn new(sym: Option<CompactString>) -> Self {
+        OpenTimeSig { sym }
+    }
+
+    pub fn sym_ref(&self) -> Option<&CompactString> {
+        self.sym.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TimeSig {
+    Simple(SingleTimeSig),
+    Interchangeable(InterchangeableTimeSig),
+    Aggregate(AggregateTimeSig),
+    Alternating(AlternatingTimeSig),
+    Open(OpenTimeSig),
+}
+
+impl TimeSig {
+    /// Length of the `measure`-th measure (counting from `0`), or `None` without a meter.
+    ///
+    /// Interchangeable time signatures use their main meter.
+    pub fn measure_len(&self, measure: usize) -> Option<Duration> {
+        match self {
+            TimeSig::Simple(sig) => Some(sig.measure_len()),
+            TimeSig::Interchangeable(sig) => sig.main.measure_len(measure),
+            TimeSig::Aggregate(sig) => Some(sig.0.iter().map(SingleTimeSig::measure_len).sum()),
+            TimeSig::Alternating(sig) => {
+                let i = measure.checked_rem(sig.0.len())?;
+                Some(sig.0[i].measure_len())
+            }
+            TimeSig::Open(_) => None,
+        }
+    }
+
+    /// Lengths of the beats of the `measure`-th measure (counting from `0`), empty without a
+    /// meter.
+    ///
+    /// Interchangeable time signatures use their main meter.
+    pub fn beats(&self, measure: usize) -> Vec<Duration> {
+        match self {
+            TimeSig::Simple(sig) => sig.beats(),
+            TimeSig::Interchangeable(sig) => sig.main.beats(measure),
+            TimeSig::Aggregate(sig) => sig.0.iter().flat_map(SingleTimeSig::beats).collect(),
+            TimeSig::Alternating(sig) => match measure.checked_rem(sig.0.len()) {
+                Some(i) => sig.0[i].beats(),
+                None => Vec::new(),
+            },
+            TimeSig::Open(_) => Vec::new(),
+        }
+    }
+}
+
+impl Default for TimeSig {
+    fn default() -> Self {
+        TimeSig::Simple(SingleTimeSig::default())
+    }
+}
+
+impl From<SingleTimeSig> for TimeSig {
+    fn from(value: SingleTimeSig) -> Self {
+        TimeSig::Simple(value)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct BinaryDuration {
@@ -149,3 +381,99 @@ impl BinaryDuration {
         }
     }
 }
+
+/// Exact length of a note or rest in whole notes, kept in lowest terms.
+///
+/// A quarter note is `1/4` and a dotted half note `3/4`.
+///
+/// Unlike the just intonation `Ratio`, the numerator and denominator are `u64`, so that
+/// durations are `Copy` and cheap to add up over long sequences. Note values with dots or
+/// nested tuplets stay far below the limit, and the `checked_*` methods report the rare
+/// lengths that do not fit instead of wrapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Duration {
+    pub(crate) numer: u64,
+    pub(crate) denom: u64,
+}
+
+impl Duration {
+    pub const WHOLE: Self = Duration { numer: 1, denom: 1 };
+
+    /// Creates the duration of `numer/denom` whole notes, reduced to lowest terms.
+    pub fn new(numer: u64, denom: u64) -> Result<Self, InvalidDuration> {
+        if denom == 0 {
+            return Err(InvalidDuration(numer, denom));
+        }
+        let gcd = numer.gcd(denom);
+        Ok(Duration {
+            numer: numer / gcd,
+            denom: denom / gcd,
+        })
+    }
+
+    pub const fn numer(&self) -> u64 {
+        self.numer
+    }
+
+    pub const fn denom(&self) -> u64 {
+        self.denom
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+}
+
+impl Zero for Duration {
+    const ZERO: Self = Duration { numer: 0, denom: 1 };
+}
+
+impl TryFrom<BinaryDuration> for Duration {
+    type Error = DurationOverflow;
+
+    /// Length of the note value, where every dot adds half of the previous value, or an error
+    /// if it does not fit into a `u64` fraction.
+    fn try_from(value: BinaryDuration) -> Result<Self, Self::Error> {
+        // `dots` dots make `2^(dots + 1) - 1` notes of the value of the last dot
+        let dots = value.dots as u32;
+        let numer = u64::MAX >> 63u32.checked_sub(dots).ok_or(DurationOverflow)?;
+        let shift = value.kind as i32 + dots as i32;
+        let (numer, denom) = if shift >= 0 {
+            (Some(numer), 1u64.checked_shl(shift as u32))
+        } else {
+            let factor = 1u64.checked_shl(shift.unsigned_abs());
+            (factor.and_then(|factor| numer.checked_mul(factor)), Some(1))
+        };
+        match (numer, denom) {
+            // odd over a power of two, or a whole number, hence in lowest terms
+            (Some(numer), Some(denom)) => Ok(Duration { numer, denom }),
+            _ => Err(DurationOverflow),
+        }
+    }
+}
+
+impl TryFrom<Duration> for BinaryDuration {
+    type Error = NotBinaryDuration;
+
+    /// Recovers the (dotted) note value of the given length, if there is one.
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        let err = || NotBinaryDuration(value);
+        if value.is_zero() || !value.denom.is_power_of_two() {
+            return Err(err());
+        }
+        // a dotted value is `(2^(dots + 1) - 1) / 2^(kind + dots)`
+        let twos = value.numer.trailing_zeros();
+        let odd = value.numer >> twos;
+        // `2^(dots + 1) - 1` is all ones
+        let ones = odd.trailing_ones();
+        if ones + odd.leading_zeros() != u64::BITS {
+            return Err(err());
+        }
+        let dots = ones - 1;
+        let kind = value.denom.trailing_zeros() as i32 - twos as i32 - dots as i32;
+        Ok(BinaryDuration {
+            kind: kind.try_into().map_err(|_| err())?,
+            dots: dots.try_into().map_err(|_| err())?,
+        })
+    }
+}
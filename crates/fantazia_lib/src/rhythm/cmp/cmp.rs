@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::rhythm::BinaryDuration;
+use crate::rhythm::{BinaryDuration, Duration};
 
 impl Ord for BinaryDuration {
     fn cmp(&self, other: &Self) -> Ordering {
@@ -16,3 +16,15 @@ impl PartialOrd for BinaryDuration {
         Some(self.cmp(other))
     }
 }
+
+impl Ord for Duration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer as u128 * other.denom as u128).cmp(&(other.numer as u128 * self.denom as u128))
+    }
+}
+
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -4,7 +4,58 @@ use anyhow::anyhow;
 use malachite_base::num::arithmetic::traits::CheckedLogBase2;
 use phf::phf_map;
 
-use crate::rhythm::BinaryDuration;
+use crate::rhythm::{
+    AggregateTimeSig, AlternatingTimeSig, BinaryDuration, Duration, InterchangeableTimeSig,
+    OpenTimeSig, SingleTimeSig, TimeSig,
+};
+
+pub mod err {
+    use thiserror::Error;
+
+    use crate::rhythm::Duration;
+
+    #[derive(Debug, Error)]
+    #[error("A duration needs a nonzero denominator, found {0}/{1}.")]
+    pub struct InvalidDuration(pub(crate) u64, pub(crate) u64);
+
+    #[derive(Debug, Error)]
+    #[error("No dotted note value lasts {0}.")]
+    pub struct NotBinaryDuration(pub(crate) Duration);
+
+    #[derive(Debug, Error)]
+    #[error("The duration does not fit into 64-bit numerator and denominator.")]
+    pub struct DurationOverflow;
+
+    #[derive(Debug, Error)]
+    pub enum ParseDurationError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error("Invalid number: `{0}`.")]
+        InvalidNumber(String),
+        #[error(transparent)]
+        InvalidDuration(#[from] InvalidDuration),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum InvalidTimeSig {
+        #[error("A time signature needs a power of two as denominator, found {0}.")]
+        Denominator(u8),
+        #[error("A time signature needs positive numerators adding up to at most 255.")]
+        Numerator,
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ParseTimeSigError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error("Invalid number in time signature: `{0}`.")]
+        InvalidNumber(String),
+        #[error("Invalid time signature: `{0}`.")]
+        InvalidTimeSig(String),
+        #[error(transparent)]
+        OutOfRange(#[from] InvalidTimeSig),
+    }
+}
 
 static COMMON_DURATIONS: phf::Map<&'static str, i8> = phf_map! {
     "1/8" | "/8" => -3,
@@ -26,6 +77,12 @@ static COMMON_DURATIONS: phf::Map<&'static str, i8> = phf_map! {
 impl FromStr for BinaryDuration {
     type Err = anyhow::Error;
 
+    /// Parses a note value by its name in the American system followed by its dots, e.g. `4` for
+    /// a quarter, `8.` for a dotted eighth, or `1/2` (also `/2`) for a breve.
+    ///
+    /// The number is the reciprocal of the length, unlike in [`Duration`]'s `FromStr`: `1/4` is
+    /// a longa here but a quarter as a [`Duration`], and `2` is a half note here but a breve as
+    /// a [`Duration`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (duration_src, dots) = if let Some((i1, i2)) = s
             .char_indices()
@@ -37,15 +94,15 @@ impl FromStr for BinaryDuration {
         {
             (&s[..i2], u8::try_from(i1 + 1)?)
         } else {
-            (&s[..], 0u8)
+            (s, 0u8)
         };
         match COMMON_DURATIONS.get(duration_src) {
             Some(&kind) => Ok(BinaryDuration { kind, dots }),
             None => {
-                let (sign, duration_src) = if duration_src.starts_with("1/") {
-                    (false, &duration_src[2..])
-                } else if duration_src.starts_with('/') {
-                    (false, &duration_src[1..])
+                let (sign, duration_src) = if let Some(src) = duration_src.strip_prefix("1/") {
+                    (false, src)
+                } else if let Some(src) = duration_src.strip_prefix('/') {
+                    (false, src)
                 } else {
                     (true, duration_src)
                 };
@@ -60,3 +117,124 @@ impl FromStr for BinaryDuration {
         }
     }
 }
+
+impl FromStr for Duration {
+    type Err = err::ParseDurationError;
+
+    /// Parses a length in whole notes such as `3/8` or `2`.
+    ///
+    /// Unlike in [`BinaryDuration`]'s `FromStr`, which names note values by the reciprocal of
+    /// their length, `1/4` is a quarter and `2` is a breve here.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use err::ParseDurationError;
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseDurationError::EmptyInput);
+        }
+        let parse = |s: &str| {
+            s.trim()
+                .parse::<u64>()
+                .map_err(|_| ParseDurationError::InvalidNumber(s.to_string()))
+        };
+        let (numer, denom) = match s.split_once('/') {
+            Some((numer, denom)) => (parse(numer)?, parse(denom)?),
+            None => (parse(s)?, 1),
+        };
+        Ok(Duration::new(numer, denom)?)
+    }
+}
+
+impl FromStr for SingleTimeSig {
+    type Err = err::ParseTimeSigError;
+
+    /// Parses `num/den` with a simple or additive numerator, e.g. `4/4` or `3+2+2/8`, or the
+    /// symbols `C` for common time and `C|` for cut time.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use err::ParseTimeSigError;
+        let s = s.trim();
+        match s {
+            "" => return Err(ParseTimeSigError::EmptyInput),
+            SingleTimeSig::COMMON_TIME => return Ok(SingleTimeSig::common_time()),
+            SingleTimeSig::CUT_TIME | "¢" => return Ok(SingleTimeSig::cut_time()),
+            _ => {}
+        }
+        let parse = |s: &str| match s.trim().parse::<u8>() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(ParseTimeSigError::InvalidNumber(s.to_string())),
+        };
+        let (num, den) = s
+            .split_once('/')
+            .ok_or_else(|| ParseTimeSigError::InvalidTimeSig(s.to_string()))?;
+        let den = parse(den)?;
+        let nums = num.split('+').map(parse).collect::<Result<Vec<_>, _>>()?;
+        Ok(match nums[..] {
+            [num] => SingleTimeSig::new_simple(num, den, None)?,
+            _ => SingleTimeSig::new_additive(nums.into_iter(), den, None)?,
+        })
+    }
+}
+
+/// Splits an aggregate time signature such as `2/4+3/8` or `3+2/8+3/4` into its parts.
+fn split_aggregate(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut has_slash = false;
+    for (i, ch) in s.char_indices() {
+        match ch {
+            '/' => has_slash = true,
+            // a `+` after a denominator starts the next part
+            '+' if has_slash => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+                has_slash = false;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+impl FromStr for TimeSig {
+    type Err = err::ParseTimeSigError;
+
+    /// Parses a time signature:
+    ///
+    /// - a single meter as in [`SingleTimeSig`]'s `FromStr`, e.g. `4/4`, `3+2+2/8` or `C`;
+    /// - an aggregate of meters within a measure joined by `+`, e.g. `2/4+3/8`;
+    /// - alternating meters separated by whitespace, e.g. `3/4 2/4`;
+    /// - interchangeable meters with the alternative in parentheses, e.g. `3/4(6/8)`;
+    /// - `X` for no meter.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use err::ParseTimeSigError;
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseTimeSigError::EmptyInput);
+        }
+        if s == "X" {
+            return Ok(TimeSig::Open(OpenTimeSig::default()));
+        }
+        if let Some((main, alternative)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            return Ok(TimeSig::Interchangeable(InterchangeableTimeSig::new(
+                main.parse()?,
+                alternative.parse()?,
+            )));
+        }
+        if s.contains(char::is_whitespace) {
+            let sigs = s
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(TimeSig::Alternating(AlternatingTimeSig::new(sigs)));
+        }
+        let parts = split_aggregate(s);
+        if parts.len() > 1 {
+            let sigs = parts
+                .into_iter()
+                .map(str::parse)
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(TimeSig::Aggregate(AggregateTimeSig::new(sigs)));
+        }
+        Ok(TimeSig::Simple(s.parse()?))
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt::{Display, Formatter, Write};
 
-use crate::rhythm::BinaryDuration;
+use crate::rhythm::{
+    AggregateTimeSig, AlternatingTimeSig, BinaryDuration, Duration, InterchangeableTimeSig,
+    OpenTimeSig, SingleTimeSig, TimeSig, TimeSigNum,
+};
 
 const COMMON_DURATIONS: [&str; 11] = [
     "1", "2", "4", "8", "16", "32", "64", "128", "256", "512", "1024",
@@ -14,7 +17,7 @@ impl Display for BinaryDuration {
             }
             -10..0 => {
                 f.write_char('/')?;
-                f.write_str(COMMON_DURATIONS[(-self.kind - 1) as usize])?;
+                f.write_str(COMMON_DURATIONS[(-self.kind) as usize])?;
             }
             n if n > 0 => {
                 write!(f, "{}", 1u128 << n)?;
@@ -29,3 +32,87 @@ impl Display for BinaryDuration {
         Ok(())
     }
 }
+
+impl Display for Duration {
+    /// Writes the length in whole notes, e.g. `3/8`, or `2` for a breve.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.denom {
+            1 => write!(f, "{}", self.numer),
+            denom => write!(f, "{}/{denom}", self.numer),
+        }
+    }
+}
+
+impl Display for TimeSigNum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeSigNum::Simple(num) => write!(f, "{num}"),
+            TimeSigNum::Additive(nums) => {
+                for (i, num) in nums.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char('+')?;
+                    }
+                    write!(f, "{num}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for SingleTimeSig {
+    /// Writes the symbol if there is one, e.g. `C`, and `num/den` otherwise, e.g. `3+2+2/8`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.sym {
+            Some(sym) => f.write_str(sym),
+            None => write!(f, "{}/{}", self.num, self.den),
+        }
+    }
+}
+
+/// Writes `sigs` separated by `sep`.
+fn fmt_sigs(sigs: &[SingleTimeSig], sep: char, f: &mut Formatter<'_>) -> std::fmt::Result {
+    for (i, sig) in sigs.iter().enumerate() {
+        if i > 0 {
+            f.write_char(sep)?;
+        }
+        write!(f, "{sig}")?;
+    }
+    Ok(())
+}
+
+impl Display for InterchangeableTimeSig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.main, self.alternative)
+    }
+}
+
+impl Display for AggregateTimeSig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_sigs(&self.0, '+', f)
+    }
+}
+
+impl Display for AlternatingTimeSig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_sigs(&self.0, ' ', f)
+    }
+}
+
+impl Display for OpenTimeSig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.sym.as_deref().unwrap_or("X"))
+    }
+}
+
+impl Display for TimeSig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeSig::Simple(sig) => sig.fmt(f),
+            TimeSig::Interchangeable(sig) => sig.fmt(f),
+            TimeSig::Aggregate(sig) => sig.fmt(f),
+            TimeSig::Alternating(sig) => sig.fmt(f),
+            TimeSig::Open(sig) => sig.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod base;
+mod beaming;
+mod parsing;
+mod repr;
+mod split;
+
+pub use base::*;
@@ -0,0 +1,198 @@
+use crate::rhythm::{Duration, NoteValue, TimeSig, Tuplet};
+
+use super::parsing::err::RhythmEventError;
+
+/// Note or rest of a [`RhythmSeq`], which may be tied to the next event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RhythmEvent {
+    pub(crate) value: NoteValue,
+    pub(crate) rest: bool,
+    pub(crate) tied: bool,
+}
+
+impl RhythmEvent {
+    pub fn note(value: impl Into<NoteValue>) -> Self {
+        RhythmEvent {
+            value: value.into(),
+            rest: false,
+            tied: false,
+        }
+    }
+
+    pub fn rest(value: impl Into<NoteValue>) -> Self {
+        RhythmEvent {
+            value: value.into(),
+            rest: true,
+            tied: false,
+        }
+    }
+
+    /// The note tied to the next one. Rests cannot be tied.
+    pub fn tied(self) -> Result<Self, RhythmEventError> {
+        if self.rest {
+            return Err(RhythmEventError::TiedRest);
+        }
+        Ok(RhythmEvent { tied: true, ..self })
+    }
+
+    pub const fn value(&self) -> &NoteValue {
+        &self.value
+    }
+
+    pub const fn is_rest(&self) -> bool {
+        self.rest
+    }
+
+    /// Whether the note is tied to the next event.
+    pub const fn is_tied(&self) -> bool {
+        self.tied
+    }
+
+    /// Actual length of the event.
+    pub fn duration(&self) -> Duration {
+        self.value.duration()
+    }
+
+    pub(crate) fn in_tuplet(self, tuplet: Tuplet) -> Self {
+        RhythmEvent {
+            value: self.value.in_tuplet(tuplet),
+            ..self
+        }
+    }
+}
+
+/// Events between two barlines, with the time signature if it changes at this measure.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Measure {
+    pub(crate) time_sig: Option<TimeSig>,
+    pub(crate) events: Vec<RhythmEvent>,
+}
+
+impl Measure {
+    pub fn new(events: Vec<RhythmEvent>) -> Self {
+        Measure {
+            time_sig: None,
+            events,
+        }
+    }
+
+    /// The measure changing the time signature to `time_sig`.
+    pub fn with_time_sig(self, time_sig: TimeSig) -> Self {
+        Measure {
+            time_sig: Some(time_sig),
+            ..self
+        }
+    }
+
+    /// Time signature set at the start of this measure, if any.
+    pub fn time_sig(&self) -> Option<&TimeSig> {
+        self.time_sig.as_ref()
+    }
+
+    pub fn events(&self) -> &[RhythmEvent] {
+        &self.events
+    }
+
+    /// Total length of the events in the measure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length does not fit into a `u64` fraction, which cannot happen for parsed
+    /// measures.
+    pub fn duration(&self) -> Duration {
+        self.events.iter().map(RhythmEvent::duration).sum()
+    }
+}
+
+/// Measure whose events do not add up to the length required by its time signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MeasureMismatch {
+    pub measure: usize,
+    pub expected: Duration,
+    pub actual: Duration,
+}
+
+impl MeasureMismatch {
+    pub fn is_underfilled(&self) -> bool {
+        self.actual < self.expected
+    }
+
+    pub fn is_overfilled(&self) -> bool {
+        self.actual > self.expected
+    }
+}
+
+/// Sequence of rhythmic events divided into measures.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RhythmSeq {
+    pub(crate) measures: Vec<Measure>,
+}
+
+impl RhythmSeq {
+    pub fn new(measures: Vec<Measure>) -> Self {
+        RhythmSeq { measures }
+    }
+
+    pub fn measures(&self) -> &[Measure] {
+        &self.measures
+    }
+
+    /// All events of the sequence in order, across barlines.
+    pub fn events(&self) -> impl Iterator<Item = &RhythmEvent> {
+        self.measures.iter().flat_map(|measure| &measure.events)
+    }
+
+    /// Total length of the sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length does not fit into a `u64` fraction, which cannot happen for parsed
+    /// sequences.
+    pub fn duration(&self) -> Duration {
+        self.measures.iter().map(Measure::duration).sum()
+    }
+
+    /// Time signature in effect at `measure`, with the number of measures since it was set.
+    pub(crate) fn time_sig_and_offset(&self, measure: usize) -> Option<(&TimeSig, usize)> {
+        self.measures[..=measure]
+            .iter()
+            .rev()
+            .enumerate()
+            .find_map(|(offset, m)| Some((m.time_sig.as_ref()?, offset)))
+    }
+
+    /// Time signature in effect at `measure`, i.e. the last one set at or before it.
+    pub fn time_sig_at(&self, measure: usize) -> Option<&TimeSig> {
+        if measure >= self.measures.len() {
+            return None;
+        }
+        self.time_sig_and_offset(measure).map(|(sig, _)| sig)
+    }
+
+    /// Length required by the time signature at `measure`, if there is one with a fixed
+    /// length.
+    pub fn expected_len(&self, measure: usize) -> Option<Duration> {
+        if measure >= self.measures.len() {
+            return None;
+        }
+        let (sig, offset) = self.time_sig_and_offset(measure)?;
+        sig.measure_len(offset)
+    }
+
+    /// Measures that are under- or over-filled relative to their time signature. Measures
+    /// without a time signature or under an open one are not checked, and an anacrusis is
+    /// reported as an underfilled first measure.
+    pub fn mismatches(&self) -> Vec<MeasureMismatch> {
+        (0..self.measures.len())
+            .filter_map(|measure| {
+                let expected = self.expected_len(measure)?;
+                let actual = self.measures[measure].duration();
+                (actual != expected).then_some(MeasureMismatch {
+                    measure,
+                    expected,
+                    actual,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,52 @@
+use std::ops::Range;
+
+use malachite_base::num::basic::traits::Zero;
+
+use crate::rhythm::Duration;
+
+use super::{RhythmSeq, split::beat_starts};
+
+impl RhythmSeq {
+    /// Groups of consecutive events in the `measure`-th measure that are beamed together, as
+    /// ranges of their indices in the measure.
+    ///
+    /// Notes shorter than a quarter are beamed within each beat of the time signature, and
+    /// rests, longer notes and notes across a beat break the beams. Without a time signature
+    /// or under an open one there are no beams.
+    pub fn beams(&self, measure: usize) -> Vec<Range<usize>> {
+        let Some((time_sig, offset)) = self
+            .measures
+            .get(measure)
+            .and_then(|_| self.time_sig_and_offset(measure))
+        else {
+            return Vec::new();
+        };
+        let starts = beat_starts(time_sig, offset);
+        if starts.len() < 2 {
+            return Vec::new();
+        }
+        let mut beams = Vec::new();
+        let mut group: Option<(usize, Range<usize>)> = None;
+        let mut pos = Duration::ZERO;
+        for (i, event) in self.measures[measure].events.iter().enumerate() {
+            let end = pos + event.duration();
+            // the beat the event lies in, if it is a beamable note
+            let beat = starts
+                .windows(2)
+                .position(|w| w[0] <= pos && end <= w[1])
+                .filter(|_| !event.rest && event.value.value().kind >= 3);
+            group = match (group.take(), beat) {
+                (Some((prev, range)), Some(beat)) if prev == beat => {
+                    Some((beat, range.start..i + 1))
+                }
+                (prev, beat) => {
+                    beams.extend(prev.map(|(_, range)| range).filter(|r| r.len() > 1));
+                    beat.map(|beat| (beat, i..i + 1))
+                }
+            };
+            pos = end;
+        }
+        beams.extend(group.map(|(_, range)| range).filter(|r| r.len() > 1));
+        beams
+    }
+}
@@ -0,0 +1,196 @@
+use std::str::FromStr;
+
+use crate::rhythm::{
+    Duration, NoteValue, Tuplet,
+    parsing::err::DurationOverflow,
+    tuplet::parsing::{InTuplet, parse_tuplet_groups},
+};
+
+use super::{Measure, RhythmEvent, RhythmSeq};
+
+pub mod err {
+    use thiserror::Error;
+
+    use crate::rhythm::{
+        parsing::err::{DurationOverflow, NotBinaryDuration, ParseTimeSigError},
+        tuplet::parsing::err::ParseNoteValueError,
+    };
+
+    #[derive(Debug, Error)]
+    pub enum RhythmEventError {
+        #[error("A rest cannot be tied.")]
+        TiedRest,
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ParseRhythmSeqError {
+        #[error(transparent)]
+        InvalidNoteValue(#[from] ParseNoteValueError),
+        #[error(transparent)]
+        InvalidTimeSig(#[from] ParseTimeSigError),
+        #[error(transparent)]
+        InvalidEvent(#[from] RhythmEventError),
+        #[error("Missing `}}` to close a time signature.")]
+        UnclosedTimeSig,
+        #[error("A time signature can only start a measure, found it in `{0}`.")]
+        MisplacedTimeSig(String),
+        #[error("A tie at the end of the sequence has no note to continue into.")]
+        DanglingTie,
+        #[error("A note cannot be tied into a rest.")]
+        TieIntoRest,
+        #[error(transparent)]
+        DurationOverflow(#[from] DurationOverflow),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum SplitError {
+        #[error("An event to split has zero length.")]
+        ZeroLength,
+        #[error(transparent)]
+        NotBinaryDuration(#[from] NotBinaryDuration),
+        #[error(transparent)]
+        DurationOverflow(#[from] DurationOverflow),
+    }
+}
+
+use err::ParseRhythmSeqError;
+
+impl InTuplet for RhythmEvent {
+    fn checked_duration(&self) -> Option<Duration> {
+        self.value.checked_duration()
+    }
+
+    fn in_tuplet(self, tuplet: Tuplet) -> Self {
+        RhythmEvent::in_tuplet(self, tuplet)
+    }
+}
+
+impl FromStr for RhythmEvent {
+    type Err = ParseRhythmSeqError;
+
+    /// Parses a [`NoteValue`], prefixed by `r` for a rest and suffixed by `~` for a note tied
+    /// to the next one, e.g. `4.`, `r8`, `2~` or `8*2/3~`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (s, tied) = match s.strip_suffix('~') {
+            Some(s) => (s, true),
+            None => (s, false),
+        };
+        let event = match s.strip_prefix('r') {
+            Some(s) => RhythmEvent::rest(s.parse::<NoteValue>()?),
+            None => RhythmEvent::note(s.parse::<NoteValue>()?),
+        };
+        Ok(if tied { event.tied()? } else { event })
+    }
+}
+
+impl FromStr for Measure {
+    type Err = ParseRhythmSeqError;
+
+    /// Parses events separated by whitespace, optionally starting with a time signature in
+    /// braces, e.g. `{3/4} 4 r8 3:2[16 16 16] 4~`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (time_sig, s) = match s.strip_prefix('{') {
+            Some(s) => {
+                let (time_sig, s) = s
+                    .split_once('}')
+                    .ok_or(ParseRhythmSeqError::UnclosedTimeSig)?;
+                (Some(time_sig.parse()?), s)
+            }
+            None => (None, s),
+        };
+        if s.contains(['{', '}']) {
+            return Err(ParseRhythmSeqError::MisplacedTimeSig(s.trim().to_string()));
+        }
+        let (events, _) = parse_tuplet_groups(s, false, &str::parse)?;
+        Ok(Measure { time_sig, events })
+    }
+}
+
+/// Splits `s` at the barlines outside of time signatures and tuplet groups, treating a run of
+/// `|` as a single barline even if there is whitespace between them.
+fn split_measures(s: &str) -> Vec<&str> {
+    let mut measures = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut prev_bar = false;
+    for (i, ch) in s.char_indices() {
+        match ch {
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => {
+                if !prev_bar {
+                    measures.push(&s[start..i]);
+                }
+                start = i + 1;
+                prev_bar = true;
+                continue;
+            }
+            _ if ch.is_whitespace() => continue,
+            _ => {}
+        }
+        prev_bar = false;
+    }
+    measures.push(&s[start..]);
+    measures
+}
+
+/// Checks that the lengths of the measures and of the whole sequence fit into `u64` fractions.
+fn check_durations(seq: &RhythmSeq) -> Result<(), DurationOverflow> {
+    let measure_lens = seq
+        .measures
+        .iter()
+        .map(|measure| Duration::checked_sum(measure.events.iter().map(RhythmEvent::duration)))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(DurationOverflow)?;
+    Duration::checked_sum(measure_lens)
+        .map(|_| ())
+        .ok_or(DurationOverflow)
+}
+
+/// Checks that every tie continues into a note.
+fn check_ties(seq: &RhythmSeq) -> Result<(), ParseRhythmSeqError> {
+    let mut events = seq.events().peekable();
+    while let Some(event) = events.next() {
+        if event.tied {
+            match events.peek() {
+                None => return Err(ParseRhythmSeqError::DanglingTie),
+                Some(next) if next.rest => return Err(ParseRhythmSeqError::TieIntoRest),
+                Some(_) => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+impl FromStr for RhythmSeq {
+    type Err = ParseRhythmSeqError;
+
+    /// Parses measures separated by barlines `|`, each as in [`Measure`]'s `FromStr`, e.g.
+    /// `{3/4} 8. 16 4 4~ | 4 r4 3:2[8 8 8] | 2.`.
+    ///
+    /// Rests are prefixed by `r`, ties are written as `~` after the first note and tuplet
+    /// groups as `actual:normal[...]` within a measure. Opening and final barlines are optional,
+    /// and an empty string is an empty sequence.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut measures = split_measures(s)
+            .into_iter()
+            .map(str::parse)
+            .collect::<Result<Vec<Measure>, _>>()?;
+        // an opening barline, possibly after the time signature of the first measure
+        if measures.len() > 1 && measures[0].events.is_empty() {
+            let first = measures.remove(0);
+            if measures[0].time_sig.is_none() {
+                measures[0].time_sig = first.time_sig;
+            }
+        }
+        if measures.last() == Some(&Measure::default()) {
+            measures.pop();
+        }
+        let seq = RhythmSeq { measures };
+        check_durations(&seq)?;
+        check_ties(&seq)?;
+        Ok(seq)
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+use super::{Measure, RhythmEvent, RhythmSeq};
+
+impl Display for RhythmEvent {
+    /// Writes the note value with `r` before a rest and `~` after a tied note, e.g. `r8` or
+    /// `4.~`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.rest {
+            write!(f, "r")?;
+        }
+        write!(f, "{}", self.value)?;
+        if self.tied {
+            write!(f, "~")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Measure {
+    /// Writes the events separated by spaces, after the time signature in braces if it changes,
+    /// e.g. `{3/4} 4 r8 8 4`. Tupleted notes are written with their factors, e.g. `8*2/3`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut sep = "";
+        if let Some(time_sig) = &self.time_sig {
+            write!(f, "{{{time_sig}}}")?;
+            sep = " ";
+        }
+        for event in &self.events {
+            write!(f, "{sep}{event}")?;
+            sep = " ";
+        }
+        Ok(())
+    }
+}
+
+impl Display for RhythmSeq {
+    /// Writes the measures separated by ` | `.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut sep = "";
+        for measure in &self.measures {
+            write!(f, "{sep}{measure}")?;
+            sep = " | ";
+        }
+        Ok(())
+    }
+}
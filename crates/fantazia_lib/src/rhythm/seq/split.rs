@@ -0,0 +1,187 @@
+use malachite_base::num::basic::traits::Zero;
+
+use crate::rhythm::{
+    BinaryDuration, Duration, NoteValue, TimeSig,
+    parsing::err::{DurationOverflow, NotBinaryDuration},
+};
+
+use super::{Measure, RhythmEvent, RhythmSeq, parsing::err::SplitError};
+
+/// Positions of the beats of the `measure`-th measure under `time_sig`, starting with `0`.
+pub(crate) fn beat_starts(time_sig: &TimeSig, measure: usize) -> Vec<Duration> {
+    let mut pos = Duration::ZERO;
+    let mut starts = vec![pos];
+    for beat in time_sig.beats(measure) {
+        pos += beat;
+        starts.push(pos);
+    }
+    starts
+}
+
+/// Middle of a measure of four or more equal beats in an even number, which only notes from
+/// the downbeat may cross.
+fn half_bar(starts: &[Duration]) -> Option<Duration> {
+    let beats = starts.len() - 1;
+    let equal = starts.windows(3).all(|w| w[1] - w[0] == w[2] - w[1]);
+    (beats >= 4 && beats.is_multiple_of(2) && equal).then(|| starts[beats / 2])
+}
+
+fn is_multiple(d: Duration, of: Duration) -> bool {
+    (d.numer as u128 * of.denom as u128).is_multiple_of(d.denom as u128 * of.numer as u128)
+}
+
+/// `end - start` for `start <= end`.
+fn distance(start: Duration, end: Duration) -> Result<Duration, DurationOverflow> {
+    end.checked_sub(start).ok_or(DurationOverflow)
+}
+
+/// `pos + len`.
+fn advance(pos: Duration, len: Duration) -> Result<Duration, DurationOverflow> {
+    pos.checked_add(len).ok_or(DurationOverflow)
+}
+
+/// Fills `[start, end)` within a beat starting at `beat` with one note value if possible, or
+/// else with the longest undotted values aligned to their own length from the beat.
+fn fill_beat(
+    beat: Duration,
+    mut start: Duration,
+    end: Duration,
+    out: &mut Vec<BinaryDuration>,
+) -> Result<(), SplitError> {
+    if let Ok(value) = BinaryDuration::try_from(distance(start, end)?) {
+        out.push(value);
+        return Ok(());
+    }
+    while start < end {
+        let left = distance(start, end)?;
+        let offset = distance(beat, start)?;
+        let value = (-3..=30)
+            .map(BinaryDuration::new_undotted)
+            .find(|&value| {
+                Duration::try_from(value).is_ok_and(|len| len <= left && is_multiple(offset, len))
+            })
+            .ok_or(NotBinaryDuration(left))?;
+        out.push(value);
+        start = advance(start, Duration::try_from(value)?)?;
+    }
+    Ok(())
+}
+
+/// Splits `[start, end)` within a measure with beats at `starts` into note values.
+///
+/// A note from a beat may last whole beats, but not across the [`half_bar`] unless it starts on
+/// the downbeat. Other notes are split at the next beat.
+fn split_in_measure(
+    starts: &[Duration],
+    mut start: Duration,
+    end: Duration,
+    out: &mut Vec<BinaryDuration>,
+) -> Result<(), SplitError> {
+    let half_bar = half_bar(starts);
+    while start < end {
+        if let Some(i) = starts.iter().position(|&pos| pos == start) {
+            let whole_beats = starts[i + 1..].iter().rev().find(|&&pos| {
+                pos <= end
+                    && (i == 0 || half_bar.is_none_or(|mid| start >= mid || pos <= mid))
+                    && BinaryDuration::try_from(pos - start).is_ok()
+            });
+            if let Some(&pos) = whole_beats {
+                out.push(BinaryDuration::try_from(pos - start)?);
+                start = pos;
+                continue;
+            }
+        }
+        let beat = starts.iter().rev().find(|&&pos| pos <= start).copied();
+        let beat_end = starts.iter().find(|&&pos| pos > start).copied();
+        let piece_end = beat_end.map_or(end, |pos| pos.min(end));
+        fill_beat(beat.unwrap_or(Duration::ZERO), start, piece_end, out)?;
+        start = piece_end;
+    }
+    Ok(())
+}
+
+impl TimeSig {
+    /// Splits events given by their lengths and whether they are rests or tied to the next one.
+    fn split(
+        &self,
+        events: impl IntoIterator<Item = (Duration, bool, bool)>,
+    ) -> Result<RhythmSeq, SplitError> {
+        let mut measures = vec![Measure::new(Vec::new()).with_time_sig(self.clone())];
+        let mut measure_start = Duration::ZERO;
+        let mut pos = Duration::ZERO;
+        for (len, rest, tied) in events {
+            if len.is_zero() {
+                return Err(SplitError::ZeroLength);
+            }
+            if !len.denom.is_power_of_two() {
+                return Err(NotBinaryDuration(len).into());
+            }
+            let end = advance(pos, len)?;
+            while pos < end {
+                let index = measures.len() - 1;
+                let measure_end = match self.measure_len(index) {
+                    Some(len) => Some(advance(measure_start, len)?),
+                    None => None,
+                };
+                let piece_end = measure_end.map_or(end, |measure_end| measure_end.min(end));
+                let mut values = Vec::new();
+                split_in_measure(
+                    &beat_starts(self, index),
+                    distance(measure_start, pos)?,
+                    distance(measure_start, piece_end)?,
+                    &mut values,
+                )?;
+                let last = values.len() - 1;
+                let measure = measures.last_mut().expect("there is always a measure");
+                measure
+                    .events
+                    .extend(
+                        values
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, value)| RhythmEvent {
+                                value: NoteValue::Binary(value),
+                                rest,
+                                tied: !rest && (i < last || piece_end < end || tied),
+                            }),
+                    );
+                pos = piece_end;
+                if Some(pos) == measure_end {
+                    measure_start = pos;
+                    measures.push(Measure::default());
+                }
+            }
+        }
+        if measures.len() > 1 && measures.last() == Some(&Measure::default()) {
+            measures.pop();
+        }
+        Ok(RhythmSeq { measures })
+    }
+
+    /// Notates `events` in this meter, splitting notes and rests at barlines and beats into
+    /// dotted note values with the pieces of a note tied together, e.g. a half note on the
+    /// second beat of `4/4` becomes two tied quarters.
+    ///
+    /// Notes from a beat are kept whole as long as they last whole beats with a (dotted) note
+    /// value, except that in a measure of four (or six, ...) equal beats only notes from the
+    /// downbeat may cross its middle. Events must have positive lengths in powers of two, i.e.
+    /// no tuplets, and positions in the sequence must fit into `u64` fractions.
+    pub fn split_events<'a>(
+        &self,
+        events: impl IntoIterator<Item = &'a RhythmEvent>,
+    ) -> Result<RhythmSeq, SplitError> {
+        self.split(
+            events
+                .into_iter()
+                .map(|event| (event.duration(), event.rest, event.tied)),
+        )
+    }
+
+    /// Notates notes of the given lengths in this meter as in [`TimeSig::split_events`].
+    pub fn split_durations(
+        &self,
+        durations: impl IntoIterator<Item = Duration>,
+    ) -> Result<RhythmSeq, SplitError> {
+        self.split(durations.into_iter().map(|len| (len, false, false)))
+    }
+}
@@ -0,0 +1,5 @@
+mod base;
+pub(crate) mod parsing;
+mod repr;
+
+pub use base::*;
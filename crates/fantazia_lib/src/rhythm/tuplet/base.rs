@@ -0,0 +1,199 @@
+use std::hash::{Hash, Hasher};
+
+use crate::rhythm::{BinaryDuration, Duration};
+
+use super::parsing::err::InvalidTuplet;
+
+/// Tuplet playing `actual` notes in the time of `normal` notes, e.g. `3:2` for a triplet.
+///
+/// `unit` is the note value that the ratio counts in, e.g. an eighth for three eighths in the
+/// time of two. A tuplet can be nested in an `outer` tuplet, which scales it further.
+///
+/// The unit does not take part in comparisons, since the written form `4*2/3` of a note does
+/// not tell whether its triplet counts in quarters or, as in `3:2[4 8]`, in eighths.
+#[derive(Debug, Clone)]
+pub struct Tuplet {
+    pub(crate) actual: u64,
+    pub(crate) normal: u64,
+    pub(crate) unit: Option<BinaryDuration>,
+    pub(crate) outer: Option<Box<Tuplet>>,
+}
+
+impl PartialEq for Tuplet {
+    fn eq(&self, other: &Self) -> bool {
+        (self.actual, self.normal, &self.outer) == (other.actual, other.normal, &other.outer)
+    }
+}
+
+impl Eq for Tuplet {}
+
+impl Hash for Tuplet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.actual, self.normal, &self.outer).hash(state);
+    }
+}
+
+impl Tuplet {
+    pub fn new(actual: u64, normal: u64) -> Result<Self, InvalidTuplet> {
+        if actual == 0 || normal == 0 {
+            return Err(InvalidTuplet(actual, normal));
+        }
+        Ok(Tuplet {
+            actual,
+            normal,
+            unit: None,
+            outer: None,
+        })
+    }
+
+    pub fn with_unit(self, unit: BinaryDuration) -> Self {
+        Tuplet {
+            unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// The tuplet nested in `outer`, below any tuplets it is already nested in.
+    pub fn nested_in(mut self, outer: Tuplet) -> Self {
+        self.nest_in(outer);
+        self
+    }
+
+    pub(crate) fn nest_in(&mut self, outer: Tuplet) {
+        match &mut self.outer {
+            Some(tuplet) => tuplet.nest_in(outer),
+            None => self.outer = Some(Box::new(outer)),
+        }
+    }
+
+    pub const fn actual(&self) -> u64 {
+        self.actual
+    }
+
+    pub const fn normal(&self) -> u64 {
+        self.normal
+    }
+
+    pub const fn unit(&self) -> Option<BinaryDuration> {
+        self.unit
+    }
+
+    /// The tuplet this one is nested in.
+    pub fn outer(&self) -> Option<&Tuplet> {
+        self.outer.as_deref()
+    }
+
+    /// Scales `duration` by this tuplet and the tuplets it is nested in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into a `u64` fraction.
+    pub fn scale(&self, duration: Duration) -> Duration {
+        self.checked_scale(duration).expect("duration overflow")
+    }
+
+    /// Scales `duration` as in [`Tuplet::scale`], or returns `None` if the result does not fit
+    /// into a `u64` fraction.
+    pub fn checked_scale(&self, duration: Duration) -> Option<Duration> {
+        let duration = duration
+            .checked_mul(self.normal)?
+            .checked_div(self.actual)?;
+        match &self.outer {
+            Some(outer) => outer.checked_scale(duration),
+            None => Some(duration),
+        }
+    }
+
+    /// Actual length of the whole tuplet, i.e. `normal` units scaled by the outer tuplets, if
+    /// the unit is known and the length fits into a `u64` fraction.
+    pub fn span(&self) -> Option<Duration> {
+        let span = Duration::try_from(self.unit?)
+            .ok()?
+            .checked_mul(self.normal)?;
+        match &self.outer {
+            Some(outer) => outer.checked_scale(span),
+            None => Some(span),
+        }
+    }
+}
+
+/// Note value of a note or rest: a plain or dotted [`BinaryDuration`], or one played in a
+/// [`Tuplet`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NoteValue {
+    Binary(BinaryDuration),
+    Tupleted {
+        value: BinaryDuration,
+        tuplet: Tuplet,
+    },
+}
+
+impl NoteValue {
+    /// Written note value, without the scaling of tuplets.
+    pub const fn value(&self) -> BinaryDuration {
+        match self {
+            NoteValue::Binary(value) | NoteValue::Tupleted { value, .. } => *value,
+        }
+    }
+
+    /// Innermost tuplet the note is played in.
+    pub fn tuplet(&self) -> Option<&Tuplet> {
+        match self {
+            NoteValue::Binary(_) => None,
+            NoteValue::Tupleted { tuplet, .. } => Some(tuplet),
+        }
+    }
+
+    /// Actual length of the note.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length does not fit into a `u64` fraction, which cannot happen for parsed
+    /// note values.
+    pub fn duration(&self) -> Duration {
+        self.checked_duration().expect("duration overflow")
+    }
+
+    /// Actual length of the note, or `None` if it does not fit into a `u64` fraction.
+    pub fn checked_duration(&self) -> Option<Duration> {
+        match self {
+            NoteValue::Binary(value) => Duration::try_from(*value).ok(),
+            NoteValue::Tupleted { value, tuplet } => {
+                tuplet.checked_scale(Duration::try_from(*value).ok()?)
+            }
+        }
+    }
+
+    /// The note value played in `tuplet`, which becomes the outermost tuplet if the note is
+    /// already in one.
+    pub fn in_tuplet(self, tuplet: Tuplet) -> Self {
+        match self {
+            NoteValue::Binary(value) => NoteValue::Tupleted { value, tuplet },
+            NoteValue::Tupleted {
+                value,
+                tuplet: inner,
+            } => NoteValue::Tupleted {
+                value,
+                tuplet: inner.nested_in(tuplet),
+            },
+        }
+    }
+}
+
+impl From<BinaryDuration> for NoteValue {
+    fn from(value: BinaryDuration) -> Self {
+        NoteValue::Binary(value)
+    }
+}
+
+impl From<&NoteValue> for Duration {
+    fn from(value: &NoteValue) -> Self {
+        value.duration()
+    }
+}
+
+impl From<NoteValue> for Duration {
+    fn from(value: NoteValue) -> Self {
+        value.duration()
+    }
+}
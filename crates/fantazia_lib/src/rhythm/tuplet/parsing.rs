@@ -0,0 +1,190 @@
+use std::str::FromStr;
+
+use malachite_base::num::basic::traits::Zero as _;
+
+use crate::rhythm::{BinaryDuration, Duration, parsing::err::DurationOverflow};
+
+use super::{NoteValue, Tuplet};
+
+pub mod err {
+    use thiserror::Error;
+
+    use crate::rhythm::parsing::err::DurationOverflow;
+
+    #[derive(Debug, Error)]
+    #[error("A tuplet needs positive numbers of notes, found {0}:{1}.")]
+    pub struct InvalidTuplet(pub(crate) u64, pub(crate) u64);
+
+    #[derive(Debug, Error)]
+    pub enum ParseNoteValueError {
+        #[error("Empty input.")]
+        EmptyInput,
+        #[error("Invalid note value: `{0}`.")]
+        InvalidValue(String),
+        #[error("Invalid tuplet ratio: `{0}`.")]
+        InvalidRatio(String),
+        #[error(transparent)]
+        InvalidTuplet(#[from] InvalidTuplet),
+        #[error("Empty tuplet group `{0}[]`.")]
+        EmptyGroup(String),
+        #[error("Missing `]` to close a tuplet group.")]
+        UnclosedGroup,
+        #[error("Unmatched `]`.")]
+        UnmatchedBracket,
+        #[error(transparent)]
+        DurationOverflow(#[from] DurationOverflow),
+    }
+}
+
+use err::ParseNoteValueError;
+
+/// Parses two positive numbers separated by `sep`.
+fn parse_pair(s: &str, sep: char) -> Result<(u64, u64), ParseNoteValueError> {
+    let invalid = || ParseNoteValueError::InvalidRatio(s.to_string());
+    let (a, b) = s.split_once(sep).ok_or_else(invalid)?;
+    Ok((
+        a.trim().parse().map_err(|_| invalid())?,
+        b.trim().parse().map_err(|_| invalid())?,
+    ))
+}
+
+impl FromStr for Tuplet {
+    type Err = ParseNoteValueError;
+
+    /// Parses `actual:normal`, e.g. `3:2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (actual, normal) = parse_pair(s, ':')?;
+        Ok(Tuplet::new(actual, normal)?)
+    }
+}
+
+impl FromStr for NoteValue {
+    type Err = ParseNoteValueError;
+
+    /// Parses a [`BinaryDuration`] followed by the factors `*normal/actual` of the tuplets it is
+    /// played in, innermost first, e.g. `8`, `4.`, `8*2/3` or `16*2/3*4/5`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseNoteValueError::EmptyInput);
+        }
+        let mut parts = s.split('*');
+        let value_src = parts.next().unwrap_or_default();
+        let value: BinaryDuration = value_src
+            .parse()
+            .map_err(|_| ParseNoteValueError::InvalidValue(value_src.to_string()))?;
+        parts
+            .try_fold(NoteValue::Binary(value), |note, factor| {
+                let (normal, actual) = parse_pair(factor, '/')?;
+                let tuplet = Tuplet::new(actual, normal)?;
+                // only the innermost tuplet is known to count in the written note value
+                let tuplet = match note {
+                    NoteValue::Binary(_) => tuplet.with_unit(value),
+                    NoteValue::Tupleted { .. } => tuplet,
+                };
+                Ok(note.in_tuplet(tuplet))
+            })
+            .and_then(|note| match note.checked_duration() {
+                Some(_) => Ok(note),
+                None => Err(DurationOverflow.into()),
+            })
+    }
+}
+
+/// Items of a sequence that can be grouped into tuplets by [`parse_tuplet_groups`].
+pub(crate) trait InTuplet: Sized {
+    /// Actual length of the item, or `None` if it does not fit into a `u64` fraction.
+    fn checked_duration(&self) -> Option<Duration>;
+
+    fn in_tuplet(self, tuplet: Tuplet) -> Self;
+}
+
+impl InTuplet for NoteValue {
+    fn checked_duration(&self) -> Option<Duration> {
+        NoteValue::checked_duration(self)
+    }
+
+    fn in_tuplet(self, tuplet: Tuplet) -> Self {
+        NoteValue::in_tuplet(self, tuplet)
+    }
+}
+
+/// Parses items separated by whitespace with `parse` up to the end of `s`, or up to the `]`
+/// closing a tuplet group if `in_group`, where `actual:normal[...]` plays the items inside in
+/// a tuplet. Returns the items and the rest of `s` after the `]`.
+pub(crate) fn parse_tuplet_groups<'a, T, E>(
+    mut s: &'a str,
+    in_group: bool,
+    parse: &impl Fn(&str) -> Result<T, E>,
+) -> Result<(Vec<T>, &'a str), E>
+where
+    T: InTuplet,
+    E: From<ParseNoteValueError>,
+{
+    let mut items = Vec::new();
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return if in_group {
+                Err(ParseNoteValueError::UnclosedGroup.into())
+            } else {
+                Ok((items, s))
+            };
+        }
+        if let Some(rest) = s.strip_prefix(']') {
+            return if in_group {
+                Ok((items, rest))
+            } else {
+                Err(ParseNoteValueError::UnmatchedBracket.into())
+            };
+        }
+        let end = s
+            .find(|ch: char| ch.is_whitespace() || ch == '[' || ch == ']')
+            .unwrap_or(s.len());
+        let (token, rest) = s.split_at(end);
+        s = match rest.strip_prefix('[') {
+            Some(rest) => {
+                let tuplet: Tuplet = token.parse()?;
+                let (group, rest) = parse_tuplet_groups(rest, true, parse)?;
+                if group.is_empty() {
+                    return Err(ParseNoteValueError::EmptyGroup(token.to_string()).into());
+                }
+                // the unit is the part of the group that each of the `actual` notes takes
+                let content = group
+                    .iter()
+                    .try_fold(Duration::ZERO, |sum, item| {
+                        sum.checked_add(item.checked_duration()?)
+                    })
+                    .ok_or(ParseNoteValueError::DurationOverflow(DurationOverflow))?;
+                let unit = content
+                    .checked_div(tuplet.actual)
+                    .and_then(|unit| BinaryDuration::try_from(unit).ok());
+                let tuplet = match unit {
+                    Some(unit) => tuplet.with_unit(unit),
+                    None => tuplet,
+                };
+                let start = items.len();
+                items.extend(group.into_iter().map(|item| item.in_tuplet(tuplet.clone())));
+                if items[start..]
+                    .iter()
+                    .any(|item| item.checked_duration().is_none())
+                {
+                    return Err(ParseNoteValueError::DurationOverflow(DurationOverflow).into());
+                }
+                rest
+            }
+            None => {
+                items.push(parse(token)?);
+                rest
+            }
+        };
+    }
+}
+
+impl NoteValue {
+    /// Parses note values separated by whitespace, where `actual:normal[...]` plays the note
+    /// values inside in a tuplet, e.g. `4 3:2[8 8 8] 4` or `3:2[8 5:4[16 16 16 16 16]]`.
+    pub fn parse_many(s: &str) -> Result<Vec<NoteValue>, ParseNoteValueError> {
+        parse_tuplet_groups(s, false, &str::parse).map(|(values, _)| values)
+    }
+}
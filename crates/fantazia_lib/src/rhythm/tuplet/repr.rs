@@ -0,0 +1,24 @@
+use std::fmt::{Display, Formatter};
+
+use super::{NoteValue, Tuplet};
+
+impl Display for Tuplet {
+    /// Writes `actual:normal`, e.g. `3:2`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.actual, self.normal)
+    }
+}
+
+impl Display for NoteValue {
+    /// Writes the note value followed by the factors of its tuplets, innermost first, e.g.
+    /// `8*2/3`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())?;
+        let mut tuplet = self.tuplet();
+        while let Some(t) = tuplet {
+            write!(f, "*{}/{}", t.normal, t.actual)?;
+            tuplet = t.outer();
+        }
+        Ok(())
+    }
+}
@@ -4,4 +4,7 @@ pub mod pitch;
 #[cfg(feature = "rhythm")]
 pub mod rhythm;
 
+#[cfg(feature = "midi")]
+pub mod midi;
+
 pub use fantazia_lib::traits;
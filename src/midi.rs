@@ -0,0 +1,163 @@
+pub use fantazia_lib::midi::*;
+
+#[cfg(test)]
+mod tests {
+    use super::{EventKind, Format, MetaEvent, MidiMessage, Smf, Timing, Track, TrackEvent};
+    use crate::pitch::edo12::{Pitch, SpellingPolicy, pitch};
+
+    #[rustfmt::skip]
+    const FIXTURE: &[u8] = &[
+        b'M', b'T', b'h', b'd', 0, 0, 0, 6, 0, 1, 0, 2, 0x01, 0xe0,
+        // tempo track
+        b'M', b'T', b'r', b'k', 0, 0, 0, 0x27,
+        0x00, 0xff, 0x03, 0x04, b'T', b'e', b's', b't',
+        0x00, 0xff, 0x51, 0x03, 0x07, 0xa1, 0x20,
+        0x00, 0xff, 0x58, 0x04, 0x04, 0x02, 0x18, 0x08,
+        0x00, 0xff, 0x59, 0x02, 0xfd, 0x01,
+        0x00, 0xf0, 0x03, 0x7e, 0x7f, 0xf7,
+        0x00, 0xff, 0x2f, 0x00,
+        // note track, using running status and note-on with zero velocity
+        b'M', b'T', b'r', b'k', 0, 0, 0, 0x1f,
+        0x00, 0xc0, 0x00,
+        0x00, 0x90, 0x3c, 0x64,
+        0x00, 0x3f, 0x64,
+        0x83, 0x60, 0x3c, 0x00,
+        0x00, 0x3f, 0x00,
+        0x00, 0xe0, 0x00, 0x40,
+        0x81, 0x80, 0x00, 0x80, 0x43, 0x40,
+        0x00, 0xff, 0x2f, 0x00,
+    ];
+
+    #[test]
+    fn test_smf_round_trip() {
+        let smf = Smf::parse(FIXTURE).unwrap();
+        assert_eq!(smf.format, Format::MultiTrack);
+        assert_eq!(smf.timing, Timing::TicksPerQuarter(480));
+        assert_eq!(smf.tracks.len(), 2);
+        assert_eq!(
+            smf.tracks[0].events[1].kind,
+            EventKind::Meta(MetaEvent::Tempo(500_000))
+        );
+        assert_eq!(
+            smf.tracks[0].events[3].kind,
+            EventKind::Meta(MetaEvent::KeySignature {
+                sharps: -3,
+                minor: true
+            })
+        );
+        let note_on = &smf.tracks[1].events[2];
+        assert!(note_on.running_status);
+        assert_eq!(
+            note_on.kind,
+            EventKind::Midi {
+                channel: 0,
+                message: MidiMessage::NoteOn {
+                    pitch: pitch!("D+_0"),
+                    velocity: 100
+                }
+            }
+        );
+        let times: Vec<_> = smf.tracks[1].iter_absolute().map(|(t, _)| t).collect();
+        assert_eq!(times, [0, 0, 0, 480, 480, 480, 16864, 16864]);
+        assert_eq!(smf.to_bytes().unwrap(), FIXTURE);
+
+        let smf = Smf::parse_with_spelling(FIXTURE, SpellingPolicy::Key(-3)).unwrap();
+        let EventKind::Midi { message, .. } = smf.tracks[1].events[2].kind else {
+            panic!()
+        };
+        assert_eq!(
+            message,
+            MidiMessage::NoteOn {
+                pitch: pitch!("E-_0"),
+                velocity: 100
+            }
+        );
+        assert_eq!(smf.to_bytes().unwrap(), FIXTURE);
+    }
+
+    #[test]
+    fn test_smf_raw_round_trip() {
+        use super::{RawChunk, VlqWidths};
+
+        #[rustfmt::skip]
+        let bytes: &[u8] = &[
+            b'M', b'T', b'h', b'd', 0, 0, 0, 6, 0, 0, 0, 1, 0x01, 0xe0,
+            b'X', b'F', b'I', b'H', 0, 0, 0, 2, 0x12, 0x34,
+            // padded delta time and meta event length
+            b'M', b'T', b'r', b'k', 0, 0, 0, 0x0e,
+            0x80, 0x00, 0xff, 0x58, 0x80, 0x04, 0x04, 0x02, 0x18, 0x08,
+            0x00, 0xff, 0x2f, 0x00,
+            b'X', b'F', b'K', b'M', 0, 0, 0, 0,
+        ];
+        let smf = Smf::parse(bytes).unwrap();
+        assert_eq!(
+            smf.other_chunks,
+            [
+                (
+                    0,
+                    RawChunk {
+                        id: *b"XFIH",
+                        data: vec![0x12, 0x34]
+                    }
+                ),
+                (
+                    1,
+                    RawChunk {
+                        id: *b"XFKM",
+                        data: vec![]
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            smf.tracks[0].events[0].vlq_widths,
+            VlqWidths { delta: 2, len: 2 }
+        );
+        assert_eq!(smf.tracks[0].events[1].vlq_widths, VlqWidths::default());
+        assert_eq!(smf.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_smf_write() {
+        let mut smf = Smf::new(Format::SingleTrack, Timing::default());
+        let mut track = Track::new();
+        let note = |delta, on: bool, pitch: Pitch| {
+            let message = if on {
+                MidiMessage::NoteOn {
+                    pitch,
+                    velocity: 64,
+                }
+            } else {
+                MidiMessage::NoteOff {
+                    pitch,
+                    velocity: 64,
+                }
+            };
+            TrackEvent::new(
+                delta,
+                EventKind::Midi {
+                    channel: 9,
+                    message,
+                },
+            )
+        };
+        track.events.push(note(0, true, pitch!("C_-1")));
+        track.events.push(note(480, false, pitch!("C_-1")));
+        track.events.push(note(0, true, pitch!("C_6")));
+        smf.tracks.push(track);
+        assert!(smf.to_bytes().is_err());
+        smf.tracks[0].events.pop();
+        smf.tracks[0]
+            .events
+            .push(TrackEvent::new(0, EventKind::Meta(MetaEvent::EndOfTrack)));
+        let bytes = smf.to_bytes().unwrap();
+        assert_eq!(
+            &bytes[14..],
+            &[
+                b'M', b'T', b'r', b'k', 0, 0, 0, 13, 0x00, 0x99, 0x30, 0x40, 0x83, 0x60, 0x89,
+                0x30, 0x40, 0x00, 0xff, 0x2f, 0x00
+            ]
+        );
+        assert_eq!(Smf::parse(&bytes).unwrap(), smf);
+    }
+}
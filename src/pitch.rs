@@ -1,2 +1,3 @@
 pub mod edo12;
+pub mod ji;
 pub mod xen;
@@ -1,6 +1,6 @@
 pub use fantazia_lib::pitch::edo12::*;
 
-#[cfg(feature = "proc-macro")]
+#[cfg(any(feature = "proc-macro", test))]
 pub use fantazia_proc_macro::{acci, interval, ointerval, opitch, ostep, pitch, qual, step};
 
 #[cfg(test)]
@@ -23,7 +23,7 @@ mod test {
     }
     #[test]
     fn test_step_display() {
-        use crate::pitch::edo12::{OStep, ostep};
+        use crate::pitch::edo12::{OStep, Step, ostep};
         use OStep::*;
         let steps = [
             C,
@@ -58,6 +58,15 @@ mod test {
             ostep!("ti"),
         ];
         steps.into_iter().for_each(|step| println!("{}", step));
+        // an octave is seven steps
+        assert_eq!(
+            "C_1".parse::<Step>().unwrap(),
+            Step::from_ostep_and_octave(C, 1)
+        );
+        assert_eq!("B_-1".parse::<Step>().unwrap(), Step(-1));
+        for step in [Step(-8), Step(0), Step(13)] {
+            assert_eq!(step.to_string().parse::<Step>().unwrap(), step);
+        }
     }
     #[test]
     fn test_opitch_parse() {
@@ -70,6 +79,10 @@ mod test {
         println!("{}", opitch!("E") + opitch!("E-"));
         println!("{}", opitch!("E-") + opitch!("E-"));
         println!("{}", opitch!("E") + opitch!("E"));
+        // steps adding up to exactly seven wrap into the next octave
+        assert_eq!(opitch!("G") + opitch!("F"), opitch!("C"));
+        assert_eq!(opitch!("E") + opitch!("A"), opitch!("C+"));
+        assert_eq!(opitch!("B") + opitch!("D-"), opitch!("C"));
     }
     #[test]
     fn test_ointerval_from_opitch() {
@@ -142,4 +155,1172 @@ mod test {
         let p = opitch!("E");
         println!("{}", p * 2);
     }
+
+    #[test]
+    fn test_freq() {
+        use crate::pitch::edo12::{ConcertPitch, Pitch, interval, pitch};
+        let concert = ConcertPitch::default();
+        assert_eq!(pitch!("A_0").freq(&concert), 440.0);
+        assert_eq!(pitch!("A_1").freq(&concert), 880.0);
+        assert!((pitch!("C_0").freq(&concert) - 261.6256).abs() < 1e-4);
+        assert!((pitch!("A_0").freq(&ConcertPitch::with_a4(415.0)) - 415.0).abs() < 1e-9);
+        assert_eq!(interval!("P5").cents(), 700.0);
+        assert_eq!(interval!("-m3").cents(), -300.0);
+
+        let (p, dev) = Pitch::from_freq(450.0, &concert).unwrap();
+        assert_eq!(p, pitch!("A_0"));
+        assert!((dev - 38.9058).abs() < 1e-4);
+        let (p, _) = Pitch::from_freq(277.18, &concert).unwrap();
+        assert_eq!(p, pitch!("C+_0"));
+        let (p, dev) = Pitch::from_freq(pitch!("B-_-2").freq(&concert), &concert).unwrap();
+        assert_eq!(p, pitch!("A+_-2"));
+        assert!(dev.abs() < 1e-9);
+        assert!(Pitch::from_freq(0.0, &concert).is_none());
+    }
+
+    #[test]
+    fn test_midi_key() {
+        use crate::pitch::edo12::{OPitch, Pitch, SpellingPolicy, opitch, pitch};
+        assert_eq!(pitch!("C_0").midi_key(), Some(60));
+        assert_eq!(pitch!("A_0").midi_key(), Some(69));
+        assert_eq!(pitch!("B+_-1").midi_key(), Some(60));
+        assert_eq!(pitch!("C_4").midi_key_with_middle_c(4), Some(60));
+        assert_eq!(pitch!("C-_-5").midi_key(), None);
+        assert_eq!(pitch!("G_5").midi_key(), Some(127));
+        assert_eq!(pitch!("G+_5").midi_key(), None);
+
+        use SpellingPolicy::*;
+        assert_eq!(Pitch::from_midi_key(61, Sharps), Some(pitch!("C+_0")));
+        assert_eq!(Pitch::from_midi_key(61, Flats), Some(pitch!("D-_0")));
+        assert_eq!(Pitch::from_midi_key(0, Flats), Some(pitch!("C_-5")));
+        assert_eq!(Pitch::from_midi_key(128, Sharps), None);
+        assert_eq!(
+            Pitch::from_midi_key_with_middle_c(70, Flats, 4),
+            Some(pitch!("B-_4"))
+        );
+        // E major: four sharps
+        assert_eq!(Pitch::from_midi_key(63, Key(4)), Some(pitch!("D+_0")));
+        assert_eq!(Pitch::from_midi_key(72, Key(7)), Some(pitch!("B+_0")));
+        assert_eq!(Pitch::from_midi_key(59, Key(-7)), Some(pitch!("C-_0")));
+        assert_eq!(OPitch::from_midi_key(70, Key(0)), opitch!("B-"));
+        // the window reaches eight fifths above the tonic, so G sharp is a raised step in C
+        assert_eq!(OPitch::from_midi_key(68, Key(0)), opitch!("G+"));
+        assert_eq!(OPitch::from_midi_key(63, Key(0)), opitch!("E-"));
+        assert_eq!(OPitch::from_midi_key(68, Key(-3)), opitch!("A-"));
+        for key in 0..=127 {
+            for policy in [Sharps, Flats, Key(-7), Key(0), Key(7)] {
+                assert_eq!(
+                    Pitch::from_midi_key(key, policy).unwrap().midi_key(),
+                    Some(key)
+                );
+            }
+        }
+        assert_eq!(Key(i8::MIN).co5_start(), i8::MIN);
+        assert_eq!(Key(i8::MAX).co5_start(), 115);
+        for key in 0..12 {
+            for n in [i8::MIN, -100, 100, i8::MAX] {
+                let p = OPitch::from_midi_key(key, Key(n));
+                assert!(
+                    p.is_enharmonic(&OPitch::from_midi_key(key, Sharps)),
+                    "{key} {n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_chord() {
+        use crate::pitch::edo12::{Chord, OInterval, OPitch, opitch, pitch};
+        let c7 = Chord::dominant7(OPitch::C);
+        assert_eq!(
+            c7.pitches(),
+            [opitch!("C"), opitch!("E"), opitch!("G"), opitch!("B-")]
+        );
+        assert_eq!(
+            Chord::diminished7(opitch!("C+")).pitches(),
+            [opitch!("C+"), opitch!("E"), opitch!("G"), opitch!("B-")]
+        );
+        assert_eq!(
+            Chord::minor9(opitch!("E")).pitches(),
+            [
+                opitch!("E"),
+                opitch!("G"),
+                opitch!("B"),
+                opitch!("D"),
+                opitch!("F+")
+            ]
+        );
+        assert_eq!(
+            Chord::augmented(opitch!("B-")).pitches(),
+            [opitch!("B-"), opitch!("D"), opitch!("F+")]
+        );
+        assert_eq!(
+            Chord::sus4(opitch!("G"))
+                .with_interval(OInterval::MINOR_SEVENTH)
+                .pitches(),
+            [opitch!("G"), opitch!("C"), opitch!("D"), opitch!("F")]
+        );
+
+        let first = Chord::major(opitch!("A-")).inverted(1).unwrap();
+        assert_eq!(first.bass(), opitch!("C"));
+        assert_eq!(first.inversion(), Some(1));
+        assert_eq!(
+            first.pitches(),
+            [opitch!("C"), opitch!("E-"), opitch!("A-")]
+        );
+        assert_eq!(
+            first.voicing(0),
+            [pitch!("C_0"), pitch!("E-_0"), pitch!("A-_0")]
+        );
+        assert!(Chord::major(OPitch::C).inverted(3).is_none());
+
+        let slash = Chord::minor7(opitch!("D")).with_bass(opitch!("G"));
+        assert_eq!(slash.inversion(), None);
+        assert_eq!(
+            slash.voicing(-1),
+            [
+                pitch!("G_-1"),
+                pitch!("D_0"),
+                pitch!("F_0"),
+                pitch!("A_0"),
+                pitch!("C_1")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chord_symbol() {
+        use crate::pitch::edo12::{Chord, ChordNotation, OInterval, opitch};
+        let parse = |s: &str| s.parse::<Chord>().unwrap();
+        assert_eq!(parse("Cmaj7"), Chord::major7(opitch!("C")));
+        assert_eq!(parse("F#m7b5"), Chord::half_diminished7(opitch!("F+")));
+        assert_eq!(parse("F+m7b5"), Chord::half_diminished7(opitch!("F+")));
+        assert_eq!(
+            parse("Bb13(#11)"),
+            Chord::dominant9(opitch!("B-"))
+                .with_interval(OInterval::AUGMENTED_FOURTH)
+                .with_interval(OInterval::MAJOR_SIXTH)
+        );
+        assert_eq!(
+            parse("G7sus4/D"),
+            Chord::dominant7_sus4(opitch!("G")).with_bass(opitch!("D"))
+        );
+        assert_eq!(
+            parse("C/E"),
+            Chord::major(opitch!("C")).with_bass(opitch!("E"))
+        );
+        assert_eq!(parse("Dm(add9)"), Chord::minor_add9(opitch!("D")));
+        assert_eq!(parse("Ebm(maj7)"), Chord::minor_major7(opitch!("E-")));
+        assert_eq!(parse("CΔ"), Chord::major7(opitch!("C")));
+        // two alterations of the same degree
+        assert_eq!(
+            parse("C7b9#9"),
+            Chord::dominant7(opitch!("C"))
+                .with_interval(OInterval::MINOR_SECOND)
+                .with_interval(OInterval::AUGMENTED_SECOND)
+        );
+        assert_eq!(parse("C7b9#9"), parse("C7(b9,#9)"));
+        assert_eq!(parse("C7b9#9").to_string(), "C7(b9,#9)");
+        let altered = parse("C7b5#5");
+        assert!(altered.contains(&OInterval::DIMINISHED_FIFTH));
+        assert!(altered.contains(&OInterval::AUGMENTED_FIFTH));
+        assert!(!altered.contains(&OInterval::PERFECT_FIFTH));
+        assert_eq!(altered.to_string(), "C7b5#5");
+        assert_eq!(parse(&altered.to_string()), altered);
+        assert!("H7".parse::<Chord>().is_err());
+        assert!("C7(b9".parse::<Chord>().is_err());
+        assert!("Cm7q".parse::<Chord>().is_err());
+
+        let symbols = [
+            "C",
+            "Cm",
+            "C5",
+            "Cdim",
+            "Caug",
+            "C6",
+            "Cm6",
+            "C6/9",
+            "C7",
+            "Cmaj7",
+            "Cm7",
+            "CmMaj7",
+            "Cm7b5",
+            "Cdim7",
+            "C7#5",
+            "C9",
+            "Cmaj9",
+            "Cm9",
+            "C11",
+            "Cm11",
+            "C13",
+            "Cm13",
+            "Cmaj13",
+            "Csus2",
+            "Csus4",
+            "C7sus4",
+            "C9sus4",
+            "C(add9)",
+            "Cm(add9)",
+            "C7(b9)",
+            "C7(#9,b13)",
+            "C7(b9,#9)",
+            "C7b5#5",
+            "Bb13(#11)",
+            "F#m7b5",
+            "G7sus4/D",
+            "C/E",
+            "Dm(add9)",
+            "C7(no3)",
+            "C6/9/E",
+            "Abm11/Gb",
+        ];
+        for s in symbols {
+            let chord = parse(s);
+            assert_eq!(chord.to_string(), s);
+            let jazz = chord.display_with(ChordNotation::Jazz).to_string();
+            assert_eq!(parse(&jazz), chord, "{jazz}");
+        }
+        assert_eq!(
+            parse("F#m7b5")
+                .display_with(ChordNotation::Jazz)
+                .to_string(),
+            "F♯ø7"
+        );
+        assert_eq!(
+            parse("Bbmaj7")
+                .display_with(ChordNotation::Jazz)
+                .to_string(),
+            "B♭Δ7"
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        use crate::pitch::edo12::{Mode, OPitch, Scale, opitch};
+        let spelled = |scale: Scale| scale.to_string();
+
+        assert_eq!(spelled(Scale::major(opitch!("F+"))), "F+ G+ A+ B C+ D+ E+");
+        assert_eq!(spelled(Scale::major(opitch!("C-"))), "C- D- E- F- G- A- B-");
+        assert_eq!(spelled(Scale::minor(opitch!("A"))), "A B C D E F G");
+        assert_eq!(
+            spelled(Scale::diatonic(opitch!("D"), Mode::Dorian)),
+            "D E F G A B C"
+        );
+        assert_eq!(
+            spelled(Scale::diatonic(opitch!("F"), Mode::Lydian)),
+            "F G A B C D E"
+        );
+        assert_eq!(
+            spelled(Scale::diatonic(opitch!("B"), Mode::Locrian)),
+            "B C D E F G A"
+        );
+        assert_eq!(
+            spelled(Scale::harmonic_minor(opitch!("E-"))),
+            "E- F G- A- B- C- D"
+        );
+        assert_eq!(
+            spelled(Scale::harmonic_minor(opitch!("G+"))),
+            "G+ A+ B C+ D+ E F++"
+        );
+        assert_eq!(
+            spelled(Scale::melodic_minor(opitch!("C"))),
+            "C D E- F G A B"
+        );
+        assert_eq!(spelled(Scale::altered(opitch!("G"))), "G A- B- C- D- E- F");
+        assert_eq!(
+            spelled(Scale::super_locrian_double_flat7(opitch!("B"))),
+            "B C D E- F G A-"
+        );
+        assert_eq!(
+            spelled(Scale::major_pentatonic(opitch!("G-"))),
+            "G- A- B- D- E-"
+        );
+        assert_eq!(spelled(Scale::minor_pentatonic(opitch!("E"))), "E G A B D");
+        assert_eq!(spelled(Scale::whole_tone(opitch!("C"))), "C D E F+ G+ A+");
+        assert_eq!(
+            spelled(Scale::octatonic_half_whole(opitch!("C"))),
+            "C D- D+ E F+ G A B-"
+        );
+        assert_eq!(
+            spelled(Scale::octatonic_whole_half(opitch!("C"))),
+            "C D E- F G- A- A B"
+        );
+        assert_eq!(spelled(Scale::blues(opitch!("A"))), "A C D E- E G");
+
+        for (n, mode) in [
+            Mode::Ionian,
+            Mode::Dorian,
+            Mode::Phrygian,
+            Mode::Lydian,
+            Mode::Mixolydian,
+            Mode::Aeolian,
+            Mode::Locrian,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let tonic = OPitch::from(mode.ostep());
+            assert_eq!(
+                Scale::major(opitch!("C")).mode(n + 1),
+                Some(Scale::diatonic(tonic, mode))
+            );
+        }
+        let harmonic = Scale::harmonic_minor(opitch!("A"));
+        let melodic = Scale::melodic_minor(opitch!("A"));
+        let harmonic_modes = [
+            Scale::locrian_natural6,
+            Scale::ionian_augmented,
+            Scale::dorian_sharp4,
+            Scale::phrygian_dominant,
+            Scale::lydian_sharp2,
+            Scale::super_locrian_double_flat7,
+        ];
+        let melodic_modes = [
+            Scale::dorian_flat2,
+            Scale::lydian_augmented,
+            Scale::lydian_dominant,
+            Scale::mixolydian_flat6,
+            Scale::locrian_natural2,
+            Scale::altered,
+        ];
+        for (i, (h, m)) in harmonic_modes.into_iter().zip(melodic_modes).enumerate() {
+            let n = i + 2;
+            assert_eq!(harmonic.mode(n), Some(h(harmonic.degree(n).unwrap())));
+            assert_eq!(melodic.mode(n), Some(m(melodic.degree(n).unwrap())));
+        }
+
+        let scale = Scale::major(opitch!("E-"));
+        assert_eq!(scale.degree(1), Some(opitch!("E-")));
+        assert_eq!(scale.degree(5), Some(opitch!("B-")));
+        assert_eq!(scale.degree(7), Some(opitch!("D")));
+        assert_eq!(scale.degree(0), None);
+        assert_eq!(scale.degree(8), None);
+        assert_eq!(scale.degree_of(&opitch!("A-")), Some(4));
+        assert!(scale.contains(&opitch!("G")));
+        assert!(!scale.contains(&opitch!("G-")));
+        assert!(!scale.contains(&opitch!("D+")));
+        assert!(scale.contains_enharmonic(&opitch!("D+")));
+        assert!(!scale.contains_enharmonic(&opitch!("E")));
+    }
+
+    #[test]
+    fn test_key() {
+        use crate::pitch::edo12::{Acci, Key, KeySignature, Mode, OStep, Scale, opitch};
+        let names = |pitches: Vec<_>| {
+            pitches
+                .into_iter()
+                .map(|p: crate::pitch::edo12::OPitch| p.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        assert_eq!(names(KeySignature::new(0).accidentals()), "");
+        assert_eq!(names(KeySignature::new(4).accidentals()), "F+ C+ G+ D+");
+        assert_eq!(names(KeySignature::new(-3).accidentals()), "B- E- A-");
+        assert_eq!(
+            names(KeySignature::new(9).accidentals()),
+            "F+ C+ G+ D+ A+ E+ B+ F++ C++"
+        );
+        assert_eq!(
+            KeySignature::new(-2).altered_steps(),
+            vec![OStep::B, OStep::E]
+        );
+        assert_eq!(KeySignature::new(2).acci_of(OStep::C), Acci::SHARP);
+        assert_eq!(KeySignature::new(2).acci_of(OStep::G), Acci::NATURAL);
+        assert_eq!(KeySignature::new(-7).acci_of(OStep::F), Acci::FLAT);
+        assert_eq!(KeySignature::new(8).acci_of(OStep::F), Acci::DOUBLE_SHARP);
+
+        assert_eq!(Key::major(opitch!("C")).signature(), KeySignature::new(0));
+        assert_eq!(Key::major(opitch!("E")).signature(), KeySignature::new(4));
+        assert_eq!(Key::minor(opitch!("C")).signature(), KeySignature::new(-3));
+        assert_eq!(Key::minor(opitch!("G+")).signature(), KeySignature::new(5));
+        assert_eq!(
+            Key::new(opitch!("E"), Mode::Phrygian).signature(),
+            KeySignature::new(0)
+        );
+        assert_eq!(
+            Key::new(opitch!("G"), Mode::Dorian).signature(),
+            KeySignature::new(-1)
+        );
+        for sharps in -7..=7 {
+            let sig = KeySignature::new(sharps);
+            for mode in [Mode::Ionian, Mode::Dorian, Mode::Lydian, Mode::Aeolian] {
+                let key = sig.key(mode);
+                assert_eq!(key.signature(), sig);
+                assert_eq!(key.mode(), mode);
+                for pitch in key.scale().pitches() {
+                    assert_eq!(sig.pitch_of(pitch.step), pitch);
+                    assert_eq!(sig.spell(pitch.tone), pitch);
+                }
+            }
+        }
+
+        // theoretical keys up to the ends of the `i8` range
+        for sharps in i8::MIN..=i8::MAX {
+            let sig = KeySignature::new(sharps);
+            assert_eq!(sig.accidentals().len(), sharps.unsigned_abs() as usize);
+            for mode in [Mode::Ionian, Mode::Lydian, Mode::Locrian] {
+                assert_eq!(sig.key(mode).signature(), sig);
+            }
+        }
+        // 18 sharps on C and 19 flats on B
+        assert_eq!(KeySignature::new(127).pitch_of(OStep::C).tone, 18);
+        assert_eq!(KeySignature::new(-128).pitch_of(OStep::B).tone, 11 - 19);
+
+        let key = Key::major(opitch!("E-"));
+        assert_eq!(key.relative(Mode::MINOR), Key::minor(opitch!("C")));
+        assert_eq!(key.parallel(Mode::MINOR), Key::minor(opitch!("E-")));
+        assert_eq!(
+            Key::minor(opitch!("F+")).relative(Mode::MAJOR),
+            Key::major(opitch!("A"))
+        );
+        assert_eq!(key.scale(), Scale::major(opitch!("E-")));
+
+        let sig = KeySignature::new(-3);
+        assert_eq!(sig.spell(1), opitch!("D-"));
+        assert_eq!(sig.spell(6), opitch!("G-"));
+        assert_eq!(sig.spell(11), opitch!("B"));
+        assert_eq!(KeySignature::new(3).spell(6), opitch!("F+"));
+        assert_eq!(KeySignature::new(3).spell(10), opitch!("A+"));
+        assert_eq!(KeySignature::new(0).spell(8), opitch!("G+"));
+        assert_eq!(KeySignature::new(0).spell(3), opitch!("E-"));
+    }
+
+    #[test]
+    fn test_enharmonic() {
+        use crate::pitch::edo12::{
+            OIntervalDeg, OPitch, OStep, interval, ointerval, opitch, pitch,
+        };
+
+        assert_eq!(opitch!("C+").respell_to_step(OStep::D), opitch!("D-"));
+        assert_eq!(opitch!("E+").respell_to_step(OStep::F), opitch!("F"));
+        assert_eq!(opitch!("C").respell_to_step(OStep::B), opitch!("B+"));
+        assert_eq!(opitch!("C").respell_to_step(OStep::D), opitch!("D--"));
+        assert_eq!(opitch!("C").respell_to_step(OStep::F), opitch!("F[-5]"));
+        assert_eq!(
+            opitch!("G+").enharmonics(2).collect::<Vec<_>>(),
+            vec![opitch!("G+"), opitch!("A-")]
+        );
+        assert_eq!(
+            opitch!("D").enharmonics(2).collect::<Vec<_>>(),
+            vec![opitch!("C++"), opitch!("D"), opitch!("E--")]
+        );
+        assert_eq!(
+            opitch!("D").enharmonics(0).collect::<Vec<_>>(),
+            vec![opitch!("D")]
+        );
+        for pitch in opitch!("F+").enharmonics(6) {
+            assert!(pitch.is_enharmonic(&opitch!("F+")));
+        }
+
+        let simplest = |p: OPitch| p.simplest_spelling();
+        assert_eq!(simplest(opitch!("E+")), opitch!("F"));
+        assert_eq!(simplest(opitch!("C-")), opitch!("B"));
+        assert_eq!(simplest(opitch!("E--")), opitch!("D"));
+        assert_eq!(simplest(opitch!("C--")), opitch!("B-"));
+        assert_eq!(simplest(opitch!("B++")), opitch!("C+"));
+        assert_eq!(simplest(opitch!("G-")), opitch!("G-"));
+        assert_eq!(simplest(opitch!("F+")), opitch!("F+"));
+        assert_eq!(simplest(opitch!("A")), opitch!("A"));
+
+        assert_eq!(pitch!("B+_0").respell_to_step(OStep::C), pitch!("C_1"));
+        assert_eq!(pitch!("C_0").respell_to_step(OStep::B), pitch!("B+_-1"));
+        assert_eq!(pitch!("C-_2").simplest_spelling(), pitch!("B_1"));
+        assert_eq!(pitch!("E+_-1").simplest_spelling(), pitch!("F_-1"));
+        assert_eq!(
+            pitch!("C-_0").enharmonics(1).collect::<Vec<_>>(),
+            vec![pitch!("C-_0"), pitch!("B_-1")]
+        );
+
+        assert_eq!(
+            ointerval!("A4").respell_to_deg(OIntervalDeg::Fifth),
+            ointerval!("d5")
+        );
+        assert_eq!(
+            ointerval!("d5").respell_to_deg(OIntervalDeg::Fourth),
+            ointerval!("A4")
+        );
+        assert_eq!(
+            ointerval!("m3").enharmonics(1).collect::<Vec<_>>(),
+            vec![ointerval!("A2"), ointerval!("m3")]
+        );
+        assert_eq!(ointerval!("A4").simplest_spelling(), ointerval!("A4"));
+        assert_eq!(ointerval!("d5").simplest_spelling(), ointerval!("d5"));
+        assert_eq!(ointerval!("AA4").simplest_spelling(), ointerval!("P5"));
+        assert_eq!(ointerval!("d4").simplest_spelling(), ointerval!("M3"));
+        assert_eq!(ointerval!("A6").simplest_spelling(), ointerval!("m7"));
+        assert_eq!(ointerval!("d7").simplest_spelling(), ointerval!("M6"));
+
+        assert_eq!(
+            interval!("A11").respell_to_deg(OIntervalDeg::Fifth),
+            interval!("d12")
+        );
+        assert_eq!(
+            interval!("A7").respell_to_deg(OIntervalDeg::Unison),
+            interval!("P8")
+        );
+        assert_eq!(interval!("d8").simplest_spelling(), interval!("M7"));
+        assert_eq!(interval!("-A6").simplest_spelling(), interval!("-m7"));
+        assert_eq!(
+            interval!("M3").enharmonics(1).collect::<Vec<_>>(),
+            vec![interval!("M3"), interval!("d4")]
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        use crate::pitch::edo12::{
+            Chord, Key, Scale, SpellingPolicy, Transpose, TransposeIter, Transposition, interval,
+            ointerval, opitch, pitch,
+        };
+
+        assert_eq!(pitch!("E_0") + interval!("m3"), pitch!("G_0"));
+        assert_eq!(pitch!("E_0") - interval!("M10"), pitch!("C_-1"));
+
+        let up_m3 = Transposition::from(interval!("m3"));
+        assert_eq!(pitch!("B_0").transpose(up_m3), pitch!("D_1"));
+        assert_eq!(pitch!("F+_0").transpose(up_m3), pitch!("A_0"));
+        assert_eq!(opitch!("B").transpose(up_m3), opitch!("D"));
+        assert_eq!(
+            opitch!("D").transpose(Transposition::from(interval!("-M2"))),
+            opitch!("C")
+        );
+        assert_eq!(
+            opitch!("E").transpose(Transposition::from(ointerval!("A4"))),
+            opitch!("A+")
+        );
+
+        let up_1 = |policy| Transposition::chromatic(1, policy);
+        assert_eq!(
+            pitch!("B_0").transpose(up_1(SpellingPolicy::Sharps)),
+            pitch!("C_1")
+        );
+        assert_eq!(
+            pitch!("C_0").transpose(up_1(SpellingPolicy::Sharps)),
+            pitch!("C+_0")
+        );
+        assert_eq!(
+            pitch!("C_0").transpose(up_1(SpellingPolicy::Flats)),
+            pitch!("D-_0")
+        );
+        assert_eq!(
+            opitch!("G").transpose(up_1(SpellingPolicy::Key(-2))),
+            opitch!("A-")
+        );
+
+        let c_major = Key::major(opitch!("C"));
+        let up_step = Transposition::diatonic(1, c_major);
+        assert_eq!(pitch!("B_0").transpose(up_step), pitch!("C_1"));
+        assert_eq!(pitch!("E_0").transpose(up_step), pitch!("F_0"));
+        assert_eq!(pitch!("F+_0").transpose(up_step), pitch!("G+_0"));
+        assert_eq!(pitch!("B-_0").transpose(up_step), pitch!("C-_1"));
+        assert_eq!(
+            pitch!("C_0").transpose(Transposition::diatonic(-9, c_major)),
+            pitch!("A_-2")
+        );
+        let e_flat = Key::major(opitch!("E-"));
+        assert_eq!(
+            opitch!("G").transpose(Transposition::diatonic(2, e_flat)),
+            opitch!("B-")
+        );
+        assert_eq!(
+            opitch!("C").transpose(Transposition::diatonic(-1, e_flat)),
+            opitch!("B-")
+        );
+
+        let chord = Chord::dominant7(opitch!("G")).with_bass(opitch!("B"));
+        assert_eq!(
+            chord.transpose(Transposition::from(interval!("M2"))),
+            Chord::dominant7(opitch!("A")).with_bass(opitch!("C+"))
+        );
+        assert_eq!(
+            chord.transpose(Transposition::chromatic(-1, SpellingPolicy::Flats)),
+            Chord::dominant7(opitch!("G-")).with_bass(opitch!("B-"))
+        );
+        assert_eq!(
+            Chord::major(opitch!("C")).transpose(up_step),
+            Chord::minor(opitch!("D"))
+        );
+        assert_eq!(
+            chord.transpose(up_step),
+            Chord::minor7(opitch!("A")).with_bass(opitch!("C"))
+        );
+
+        assert_eq!(
+            Scale::major(opitch!("C")).transpose(Transposition::from(interval!("-m3"))),
+            Scale::major(opitch!("A"))
+        );
+        assert_eq!(
+            Scale::harmonic_minor(opitch!("A"))
+                .transpose(Transposition::chromatic(-2, SpellingPolicy::Flats)),
+            Scale::harmonic_minor(opitch!("G"))
+        );
+        assert_eq!(
+            Scale::major(opitch!("C")).transpose(up_step),
+            Scale::major(opitch!("C")).mode(2).unwrap()
+        );
+
+        let melody = [pitch!("C_0"), pitch!("E_0"), pitch!("G_0"), pitch!("C_1")];
+        let transposed = melody.transpose(Transposition::from(interval!("P4")));
+        assert_eq!(
+            transposed,
+            vec![pitch!("F_0"), pitch!("A_0"), pitch!("C_1"), pitch!("F_1")]
+        );
+        assert_eq!(
+            melody
+                .iter()
+                .copied()
+                .transposed(up_step)
+                .collect::<Vec<_>>(),
+            vec![pitch!("D_0"), pitch!("F_0"), pitch!("A_0"), pitch!("D_1")]
+        );
+    }
+
+    #[test]
+    fn test_pitch_notation() {
+        use crate::pitch::edo12::{
+            Acci, OPitch, OStep, Pitch, Step,
+            notation::{Fantazia, Helmholtz, LilyPond, Scientific, Unicode},
+            opitch, pitch,
+            traits::PitchNotation,
+        };
+
+        let fmt =
+            |pitch: Pitch, notation: &dyn PitchNotation| pitch.display_with(notation).to_string();
+        assert_eq!(fmt(pitch!("F+_0"), &Scientific), "F#4");
+        assert_eq!(fmt(pitch!("E-_-1"), &Scientific), "Eb3");
+        assert_eq!(fmt(pitch!("B--_-5"), &Scientific), "Bbb-1");
+        assert_eq!(fmt(pitch!("F+_0"), &Unicode), "F♯4");
+        assert_eq!(fmt(pitch!("C++_1"), &Unicode), "C𝄪5");
+        assert_eq!(fmt(pitch!("A---_-1"), &Unicode), "A♭𝄫3");
+        assert_eq!(fmt(pitch!("C_0"), &Helmholtz), "c'");
+        assert_eq!(fmt(pitch!("C_1"), &Helmholtz), "c''");
+        assert_eq!(fmt(pitch!("B-_-1"), &Helmholtz), "bb");
+        assert_eq!(fmt(pitch!("G_-2"), &Helmholtz), "G");
+        assert_eq!(fmt(pitch!("B-_-3"), &Helmholtz), "Bb,");
+        assert_eq!(fmt(pitch!("F+_1"), &LilyPond), "fis''");
+        assert_eq!(fmt(pitch!("E-_-1"), &LilyPond), "es");
+        assert_eq!(fmt(pitch!("A--_-3"), &LilyPond), "ases,,");
+        assert_eq!(fmt(pitch!("B-_0"), &LilyPond), "bes'");
+        assert_eq!(fmt(pitch!("C_0"), &Fantazia), "C_0");
+        assert_eq!(opitch!("G-").display_with(&LilyPond).to_string(), "ges");
+        assert_eq!(opitch!("G-").display_with(&Unicode).to_string(), "G♭");
+
+        let parse = |s: &str, notation: &dyn PitchNotation| Pitch::parse_with(s, notation).unwrap();
+        assert_eq!(parse("F#4", &Scientific), pitch!("F+_0"));
+        assert_eq!(parse("Fx4", &Scientific), pitch!("F++_0"));
+        assert_eq!(parse("Cb-1", &Scientific), pitch!("C-_-5"));
+        assert_eq!(parse("E♮2", &Unicode), pitch!("E_-2"));
+        assert_eq!(parse("C,,", &Helmholtz), pitch!("C_-4"));
+        assert_eq!(parse("fis'", &LilyPond), pitch!("F+_0"));
+        assert_eq!(parse("ees'", &LilyPond), pitch!("E-_0"));
+        assert_eq!(parse("aeses", &LilyPond), pitch!("A--_-1"));
+        assert_eq!(
+            OPitch::parse_with("Db", &Scientific).unwrap(),
+            opitch!("D-")
+        );
+        for (s, notation) in [
+            ("", &Scientific as &dyn PitchNotation),
+            ("H4", &Scientific),
+            ("F#", &Scientific),
+            ("F?4", &Scientific),
+            ("C'", &Helmholtz),
+            ("c,", &Helmholtz),
+            ("c',", &Helmholtz),
+            ("Fis'", &LilyPond),
+            ("fes'", &Unicode),
+            ("cus", &LilyPond),
+            ("C#4", &Fantazia),
+        ] {
+            assert!(Pitch::parse_with(s, notation).is_err(), "{s}");
+        }
+
+        let notations: [&dyn PitchNotation; 5] =
+            [&Fantazia, &Scientific, &Unicode, &Helmholtz, &LilyPond];
+        for step in -35..35 {
+            for acci in -4..=4 {
+                let pitch = Pitch::from_step_and_acci(Step(step), Acci::from(acci));
+                let opitch = OPitch::from(pitch);
+                for notation in notations {
+                    let s = fmt(pitch, notation);
+                    assert_eq!(Pitch::parse_with(&s, notation).unwrap(), pitch, "{s}");
+                    let s = opitch.display_with(notation).to_string();
+                    assert_eq!(OPitch::parse_with(&s, notation).unwrap(), opitch, "{s}");
+                }
+            }
+        }
+        for step in -35..35 {
+            let step = Step(step);
+            assert_eq!(step.to_string().parse::<Step>().unwrap(), step);
+        }
+        assert_eq!(
+            "B_-1".parse::<Step>().unwrap(),
+            Step::from_ostep_and_octave(OStep::B, -1)
+        );
+    }
+
+    #[test]
+    fn test_naming_system() {
+        use crate::pitch::edo12::{Acci, OPitch, OStep, naming::NamingSystem, opitch};
+        use NamingSystem::*;
+
+        let name = |pitch: OPitch, system| pitch.display_in(system).to_string();
+        assert_eq!(name(opitch!("F+"), English), "F sharp");
+        assert_eq!(name(opitch!("B--"), English), "B double flat");
+        assert_eq!(name(opitch!("B"), German), "H");
+        assert_eq!(name(opitch!("B-"), German), "B");
+        assert_eq!(name(opitch!("B--"), German), "Heses");
+        assert_eq!(name(opitch!("E-"), German), "Es");
+        assert_eq!(name(opitch!("A-"), German), "As");
+        assert_eq!(name(opitch!("F+"), German), "Fis");
+        assert_eq!(name(opitch!("C++"), German), "Cisis");
+        assert_eq!(name(opitch!("C+"), Dutch), "cis");
+        assert_eq!(name(opitch!("E-"), Dutch), "es");
+        assert_eq!(name(opitch!("B-"), Dutch), "bes");
+        assert_eq!(name(opitch!("F+"), French), "fa dièse");
+        assert_eq!(name(opitch!("B-"), French), "si bémol");
+        assert_eq!(name(opitch!("D--"), French), "ré double bémol");
+        assert_eq!(name(opitch!("G+"), Italian), "Sol diesis");
+        assert_eq!(name(opitch!("F+"), Japanese), "嬰ヘ");
+        assert_eq!(name(opitch!("B-"), Japanese), "変ロ");
+        assert_eq!(name(opitch!("C++"), Japanese), "重嬰ハ");
+        assert_eq!(name(opitch!("F+"), Chinese), "升F");
+        assert_eq!(name(opitch!("B-"), Chinese), "降B");
+        assert_eq!(name(opitch!("C[+4]"), English), "C[+4]");
+        assert_eq!(OStep::B.display_in(German).to_string(), "H");
+        assert_eq!(OStep::G.display_in(French).to_string(), "sol");
+        assert_eq!(Acci::FLAT.display_in(Italian).to_string(), "bemolle");
+        assert_eq!(Acci::NATURAL.display_in(English).to_string(), "natural");
+        assert_eq!(Acci::DOUBLE_SHARP.display_in(Dutch).to_string(), "isis");
+
+        assert_eq!(OPitch::parse_in("fis", German).unwrap(), opitch!("F+"));
+        assert_eq!(OPitch::parse_in("b", German).unwrap(), opitch!("B-"));
+        assert_eq!(OPitch::parse_in("b", Dutch).unwrap(), opitch!("B"));
+        assert_eq!(OPitch::parse_in("re bemol", French).unwrap(), opitch!("D-"));
+        assert_eq!(OPitch::parse_in("f sharp", English).unwrap(), opitch!("F+"));
+        assert_eq!(
+            OPitch::parse_in("C[-5]", English).unwrap(),
+            opitch!("C[-5]")
+        );
+        assert_eq!(OStep::parse_in("h", German).unwrap(), OStep::B);
+        assert_eq!(Acci::parse_in("dièse", French).unwrap(), Acci::SHARP);
+        assert_eq!(Acci::parse_in("", Japanese).unwrap(), Acci::NATURAL);
+        assert!(OStep::parse_in("B", German).is_err());
+        assert!(OPitch::parse_in("Hes", German).is_err());
+        assert!(OPitch::parse_in("fis", English).is_err());
+        assert!(Acci::parse_in("sharp", French).is_err());
+
+        for system in [English, German, Dutch, French, Italian, Japanese, Chinese] {
+            for step in [
+                OStep::C,
+                OStep::D,
+                OStep::E,
+                OStep::F,
+                OStep::G,
+                OStep::A,
+                OStep::B,
+            ] {
+                let s = step.display_in(system).to_string();
+                assert_eq!(OStep::parse_in(&s, system).unwrap(), step, "{s}");
+                for acci in -5..=5 {
+                    let pitch = OPitch::new(step, Acci::from(acci));
+                    let s = name(pitch, system);
+                    assert_eq!(OPitch::parse_in(&s, system).unwrap(), pitch, "{s}");
+                    let acci = Acci::from(acci);
+                    let s = acci.display_in(system).to_string();
+                    assert_eq!(Acci::parse_in(&s, system).unwrap(), acci, "{s}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_solfege() {
+        use crate::pitch::edo12::{Key, OPitch, ScaleDegree, opitch, pitch};
+
+        let d = opitch!("D");
+        assert_eq!(opitch!("D").solfege(d), Some("do"));
+        assert_eq!(opitch!("F+").solfege(d), Some("mi"));
+        assert_eq!(opitch!("F").solfege(d), Some("me"));
+        assert_eq!(opitch!("G+").solfege(d), Some("fi"));
+        assert_eq!(opitch!("C").solfege(d), Some("te"));
+        assert_eq!(opitch!("E-").solfege(d), Some("ra"));
+        assert_eq!(opitch!("D+").solfege(d), Some("di"));
+        assert_eq!(opitch!("G-").solfege(d), None);
+        assert_eq!(pitch!("A-_-2").solfege(opitch!("E-")), Some("fa"));
+
+        assert_eq!(
+            OPitch::from_solfege("le", opitch!("C")).unwrap(),
+            opitch!("A-")
+        );
+        assert_eq!(
+            OPitch::from_solfege("So", opitch!("B-")).unwrap(),
+            opitch!("F")
+        );
+        assert_eq!(
+            OPitch::from_solfege("si", opitch!("E")).unwrap(),
+            opitch!("B+")
+        );
+        assert!(OPitch::from_solfege("xa", opitch!("C")).is_err());
+        for tonic in [opitch!("C"), opitch!("F+"), opitch!("A-")] {
+            for syllable in [
+                "do", "di", "ra", "re", "ri", "me", "mi", "fa", "fi", "se", "sol", "si", "le",
+                "la", "li", "te", "ti",
+            ] {
+                let pitch = OPitch::from_solfege(syllable, tonic).unwrap();
+                assert_eq!(pitch.solfege(tonic), Some(syllable));
+            }
+        }
+
+        let e_flat = Key::major(opitch!("E-"));
+        let c_minor = Key::minor(opitch!("C"));
+        let degree = |pitch, key| ScaleDegree::of(pitch, key).to_string();
+        assert_eq!(degree(opitch!("G"), &e_flat), "^3");
+        assert_eq!(degree(opitch!("D-"), &e_flat), "♭^7");
+        assert_eq!(degree(opitch!("A"), &e_flat), "♯^4");
+        assert_eq!(degree(opitch!("E-"), &c_minor), "^3");
+        assert_eq!(degree(opitch!("B"), &c_minor), "♯^7");
+        assert_eq!(degree(opitch!("C-"), &e_flat), "♭^6");
+        assert_eq!(degree(opitch!("E--"), &e_flat), "♭^1");
+
+        let parse = |s: &str| s.parse::<ScaleDegree>().unwrap();
+        assert_eq!(parse("^5").pitch(&e_flat), opitch!("B-"));
+        assert_eq!(parse("b^7").pitch(&e_flat), opitch!("D-"));
+        assert_eq!(parse("♭^2").pitch(&Key::major(opitch!("C"))), opitch!("D-"));
+        assert_eq!(parse("#^4").pitch(&Key::major(opitch!("F"))), opitch!("B"));
+        assert_eq!(parse("♯^7").pitch(&c_minor), opitch!("B"));
+        assert!("3".parse::<ScaleDegree>().is_err());
+        assert!("^8".parse::<ScaleDegree>().is_err());
+        assert!("x^3".parse::<ScaleDegree>().is_err());
+        for key in [e_flat, c_minor] {
+            for pitch in key.scale().pitches() {
+                let degree = ScaleDegree::of(pitch, &key);
+                assert_eq!(degree.to_string().parse::<ScaleDegree>().unwrap(), degree);
+                assert_eq!(degree.pitch(&key), pitch);
+            }
+        }
+    }
+
+    #[test]
+    fn test_roman_numeral() {
+        use crate::pitch::edo12::{Chord, Key, RomanNumeral, opitch};
+
+        for s in [
+            "I",
+            "V7/V",
+            "bII6",
+            "viiø7",
+            "vii°7",
+            "vii°42",
+            "It+6",
+            "Fr+6",
+            "Ger+6",
+            "Cad64",
+            "V65",
+            "IV64",
+            "iii",
+            "III+",
+            "IM7",
+            "ivM7",
+            "V7/V/V",
+            "viiø43/ii",
+            "bVI",
+            "#iv°",
+        ] {
+            assert_eq!(s.parse::<RomanNumeral>().unwrap().to_string(), s);
+        }
+        assert_eq!(
+            "viio7".parse::<RomanNumeral>().unwrap().to_string(),
+            "vii°7"
+        );
+        assert_eq!(
+            "Ger65".parse::<RomanNumeral>().unwrap().to_string(),
+            "Ger+6"
+        );
+        assert_eq!("V2".parse::<RomanNumeral>().unwrap().to_string(), "V42");
+        for s in ["", "VIII", "Vi", "v+", "I°", "V63", "IM", "viiøM7", "V/x"] {
+            assert!(s.parse::<RomanNumeral>().is_err(), "{s}");
+        }
+
+        let c_major = Key::major(opitch!("C"));
+        let a_minor = Key::minor(opitch!("A"));
+        let realize = |s: &str, key: &Key| s.parse::<RomanNumeral>().unwrap().realize(key);
+        let c = opitch!("C");
+        let d = opitch!("D");
+        let g = opitch!("G");
+        assert_eq!(realize("V7", &c_major), Chord::dominant7(g));
+        assert_eq!(realize("V7/V", &c_major), Chord::dominant7(d));
+        assert_eq!(realize("V7/V/V", &c_major), Chord::dominant7(opitch!("A")));
+        assert_eq!(
+            realize("bII6", &c_major),
+            Chord::major(opitch!("D-")).inverted(1).unwrap()
+        );
+        assert_eq!(
+            realize("viiø7", &c_major),
+            Chord::half_diminished7(opitch!("B"))
+        );
+        assert_eq!(
+            realize("vii°7", &a_minor),
+            Chord::diminished7(opitch!("G+"))
+        );
+        assert_eq!(
+            realize("vii°65/ii", &c_major),
+            Chord::diminished7(opitch!("C+")).inverted(1).unwrap()
+        );
+        assert_eq!(realize("V/iv", &a_minor), Chord::major(opitch!("A")));
+        assert_eq!(realize("III+", &a_minor), Chord::augmented(c));
+        assert_eq!(
+            realize("Cad64", &c_major),
+            Chord::major(c).inverted(2).unwrap()
+        );
+        assert_eq!(
+            realize("Ger+6", &a_minor).pitches(),
+            [opitch!("F"), opitch!("A"), opitch!("C"), opitch!("D+")]
+        );
+        assert_eq!(
+            realize("It+6", &c_major).pitches(),
+            [opitch!("A-"), c, opitch!("F+")]
+        );
+        assert_eq!(
+            realize("Fr+6", &c_major).pitches(),
+            [opitch!("A-"), c, d, opitch!("F+")]
+        );
+
+        let analyze =
+            |chord: &Chord, key: &Key| RomanNumeral::analyze(chord, key).map(|rn| rn.to_string());
+        let analyzed = |chord: Chord, key: &Key| analyze(&chord, key).unwrap();
+        assert_eq!(analyzed(Chord::major(c), &c_major), "I");
+        assert_eq!(analyzed(Chord::minor(d), &c_major), "ii");
+        assert_eq!(
+            analyzed(Chord::dominant7(g).inverted(1).unwrap(), &c_major),
+            "V65"
+        );
+        assert_eq!(analyzed(Chord::major(d), &c_major), "V/V");
+        assert_eq!(analyzed(Chord::dominant7(opitch!("E")), &c_major), "V7/vi");
+        assert_eq!(
+            analyzed(Chord::diminished7(opitch!("C+")), &c_major),
+            "vii°7/ii"
+        );
+        assert_eq!(
+            analyzed(Chord::major(opitch!("D-")).inverted(1).unwrap(), &c_major),
+            "bII6"
+        );
+        assert_eq!(analyzed(Chord::major(opitch!("A-")), &c_major), "bVI");
+        assert_eq!(analyzed(Chord::dominant7(opitch!("E")), &a_minor), "V7");
+        assert_eq!(
+            analyzed(Chord::diminished7(opitch!("G+")), &a_minor),
+            "vii°7"
+        );
+        assert_eq!(analyzed(Chord::major(opitch!("A")), &a_minor), "V/iv");
+        assert_eq!(
+            analyzed(Chord::half_diminished7(opitch!("B")), &c_major),
+            "viiø7"
+        );
+        assert_eq!(analyzed(Chord::major7(opitch!("F")), &c_major), "IVM7");
+        for s in ["It+6", "Fr+6", "Ger+6"] {
+            assert_eq!(analyzed(realize(s, &a_minor), &a_minor), s);
+        }
+        assert_eq!(analyze(&Chord::sus4(c), &c_major), None);
+    }
+
+    #[test]
+    fn test_pcset() {
+        use std::collections::BTreeSet;
+
+        use crate::pitch::edo12::{Chord, ForteName, PcSet, Scale, opitch};
+
+        let c_major: PcSet = (&Chord::major(opitch!("C"))).into();
+        assert_eq!(c_major, PcSet::new([0, 4, 7]));
+        assert_eq!(c_major, PcSet::new([12, -8, 19]));
+        assert_eq!(PcSet::from(&Chord::major(opitch!("B+"))), c_major);
+        assert_eq!(c_major.to_string(), "[0,4,7]");
+        assert_eq!("{0, 4, 7}".parse::<PcSet>().unwrap(), c_major);
+        assert_eq!("[047]".parse::<PcSet>().unwrap(), c_major);
+        assert_eq!("0TE".parse::<PcSet>().unwrap(), PcSet::new([0, 10, 11]));
+        assert!("[0,12]".parse::<PcSet>().is_err());
+        assert!("[0,4".parse::<PcSet>().is_err());
+
+        assert_eq!(c_major.transpose(2), PcSet::new([2, 6, 9]));
+        assert_eq!(c_major.transpose(-1), PcSet::new([11, 3, 6]));
+        assert_eq!(c_major.invert(0), PcSet::new([0, 8, 5]));
+        assert_eq!(c_major.invert(7), PcSet::new([7, 3, 0]));
+        assert_eq!(c_major.complement().len(), 9);
+        assert_eq!(c_major.union(c_major.complement()), PcSet::CHROMATIC);
+
+        assert_eq!(PcSet::new([7, 11, 2, 5]).normal_form(), [11, 2, 5, 7]);
+        assert_eq!(PcSet::new([0, 4, 7]).normal_form(), [0, 4, 7]);
+        assert_eq!(PcSet::new([0, 4, 7]).prime_form(), PcSet::new([0, 3, 7]));
+        assert_eq!(
+            PcSet::new([7, 11, 2, 5]).prime_form().to_string(),
+            "[0,2,5,8]"
+        );
+        assert_eq!(
+            PcSet::new([0, 1, 3, 7, 8]).prime_form(),
+            PcSet::new([0, 1, 3, 7, 8])
+        );
+        assert_eq!(
+            PcSet::new([0, 1, 3, 7, 8]).prime_form_rahn(),
+            PcSet::new([0, 1, 5, 6, 8])
+        );
+        assert_eq!(
+            PcSet::new([0, 1, 3, 5, 8, 9]).normal_form_rahn(),
+            [8, 9, 0, 1, 3, 5]
+        );
+
+        let name = |s: &str| {
+            s.parse::<PcSet>()
+                .unwrap()
+                .forte_name()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(name("047"), "3-11");
+        assert_eq!(name("0258"), "4-27");
+        assert_eq!(name("0146"), "4-Z15");
+        assert_eq!(name("0137"), "4-Z29");
+        assert_eq!(name("02468T"), "6-35");
+        assert_eq!(name("024579"), "6-32");
+        let diatonic: PcSet = (&Scale::major(opitch!("C"))).into();
+        assert_eq!(diatonic.forte_name().unwrap().to_string(), "7-35");
+        assert_eq!(PcSet::EMPTY.forte_name().unwrap().to_string(), "0-1");
+        assert_eq!(PcSet::CHROMATIC.forte_name().unwrap().to_string(), "12-1");
+
+        assert_eq!(c_major.interval_vector(), [0, 0, 1, 1, 1, 0]);
+        assert_eq!(diatonic.interval_vector(), [2, 5, 4, 3, 6, 1]);
+        let z15 = "0146".parse::<PcSet>().unwrap();
+        let z29 = "0137".parse::<PcSet>().unwrap();
+        assert!(z15.is_z_related_to(z29));
+        assert!(!z15.is_z_related_to(z15.transpose(3)));
+        assert_eq!(z15.z_partner(), Some(z29));
+        assert_eq!(c_major.z_partner(), None);
+
+        assert!(c_major.is_subset_of(diatonic));
+        assert!(diatonic.is_superset_of(c_major));
+        assert!(!PcSet::new([0, 3, 7]).is_subset_of(diatonic.transpose(6)));
+        assert!(PcSet::new([0, 3, 7]).is_abstract_subset_of(diatonic));
+        assert!(!PcSet::new([0, 1, 2]).is_abstract_subset_of(diatonic));
+
+        let forte = |s: &str| s.parse::<ForteName>().unwrap();
+        assert_eq!(forte("4-Z15").prime_form(), Some(z15));
+        assert_eq!(
+            forte("8-Z15")
+                .prime_form()
+                .unwrap()
+                .complement()
+                .prime_form(),
+            z15
+        );
+        assert_eq!(
+            forte("7-35").prime_form().unwrap().to_string(),
+            "[0,1,3,5,6,8,10]"
+        );
+        for s in ["4-15", "3-Z11", "3-13", "13-1", "4-0", "Z15", "4Z15"] {
+            assert!(s.parse::<ForteName>().is_err(), "{s}");
+        }
+
+        // every set belongs to exactly one of the 224 set classes, whose names round-trip
+        let mut names = BTreeSet::new();
+        let mut z_classes = 0;
+        for bits in 0..4096 {
+            let set = PcSet::from_bits(bits);
+            let name = set.forte_name().unwrap();
+            assert_eq!(name.cardinality() as usize, set.len());
+            assert_eq!(name.prime_form(), Some(set.prime_form()));
+            assert_eq!(name.to_string().parse::<ForteName>().unwrap(), name);
+            let complement = set.complement();
+            if set.len() == 6 && name.is_z() {
+                // Z-related hexachords are complements of each other
+                assert_eq!(set.z_partner(), Some(complement.prime_form()));
+            } else {
+                assert_eq!(complement.forte_name().unwrap().ordinal(), name.ordinal());
+            }
+            if names.insert(name) && name.is_z() {
+                z_classes += 1;
+            }
+        }
+        assert_eq!(names.len(), 224);
+        assert_eq!(z_classes, 46);
+    }
+
+    #[test]
+    fn test_tone_row() {
+        use crate::pitch::edo12::{RowForm, RowLabel, SpellingPolicy, ToneRow, opitch};
+
+        // Berg, Violin Concerto
+        let berg: ToneRow = "7 10 2 6 9 0 4 8 11 1 3 5".parse().unwrap();
+        assert_eq!(berg.to_string(), "7 10 2 6 9 0 4 8 11 1 3 5");
+        assert_eq!("7T269048E135".parse::<ToneRow>().unwrap(), berg);
+        assert!("0 1 2".parse::<ToneRow>().is_err());
+        assert!("0 1 2 3 4 5 6 7 8 9 10 10".parse::<ToneRow>().is_err());
+        assert!("0 1 2 3 4 5 6 7 8 9 10 12".parse::<ToneRow>().is_err());
+        assert!(ToneRow::new([0, 13, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]).is_ok());
+
+        let label = |s: &str| s.parse::<RowLabel>().unwrap();
+        assert_eq!(berg.prime(0).pcs(), [0, 3, 7, 11, 2, 5, 9, 1, 4, 6, 8, 10]);
+        assert_eq!(
+            berg.inversion(7).pcs(),
+            [7, 4, 0, 8, 5, 2, 10, 6, 3, 1, 11, 9]
+        );
+        assert_eq!(
+            berg.form(label("R7")).pcs(),
+            [5, 3, 1, 11, 8, 4, 0, 9, 6, 2, 10, 7]
+        );
+        assert_eq!(
+            berg.form(label("RI7")).pcs(),
+            [9, 11, 1, 3, 6, 10, 2, 5, 8, 0, 4, 7]
+        );
+        assert_eq!(berg.label_of(&berg), Some(label("P7")));
+        assert_eq!(
+            berg.label_of(&berg.retrograde_inversion(3)),
+            Some(label("RI3"))
+        );
+        assert_eq!(label("RI5").to_string(), "RI5");
+        assert_eq!(label("PE"), RowLabel::new(RowForm::Prime, 11));
+        for s in ["", "X3", "P12", "P-1", "RI"] {
+            assert!(s.parse::<RowLabel>().is_err(), "{s}");
+        }
+
+        let matrix = berg.matrix();
+        assert_eq!(matrix[0], berg.pcs());
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(
+                berg.label_of(&ToneRow::new(*row).unwrap()).unwrap().form,
+                RowForm::Prime
+            );
+            assert_eq!(row[0], berg.inversion(7).pcs()[i]);
+        }
+        let column: Vec<_> = matrix.iter().map(|row| row[3]).collect();
+        assert_eq!(column, berg.inversion(6).pcs());
+
+        assert_eq!(berg.rotated(2).pcs()[..3], [2, 6, 9]);
+        assert_eq!(berg.rotated(2).pcs()[10..], [7, 10]);
+        assert_eq!(berg.rotated(12), berg);
+
+        // only the trivial retrograde completes Berg's first hexachord
+        assert_eq!(berg.combinatorial_forms(), [label("R7")]);
+        assert!(!berg.is_combinatorial(RowForm::Inversion));
+        // Schoenberg, Suite op. 25
+        let op25: ToneRow = "4 5 7 1 6 3 8 2 11 0 9 10".parse().unwrap();
+        assert_eq!(op25.combinatorial_forms(), [label("I11"), label("R4")]);
+        assert!(op25.is_combinatorial(RowForm::Inversion));
+        assert!(!op25.is_all_combinatorial());
+        let chromatic = ToneRow::new(0..12).unwrap();
+        assert!(chromatic.is_combinatorial_with(label("P6")));
+        assert!(chromatic.is_combinatorial_with(label("I11")));
+        assert!(chromatic.is_combinatorial_with(label("RI5")));
+        assert!(chromatic.is_all_combinatorial());
+
+        assert_eq!(
+            berg.spell(SpellingPolicy::Flats)[..4],
+            [opitch!("G"), opitch!("B-"), opitch!("D"), opitch!("G-")]
+        );
+        assert_eq!(
+            berg.spell(SpellingPolicy::Sharps)[..4],
+            [opitch!("G"), opitch!("A+"), opitch!("D"), opitch!("F+")]
+        );
+        assert_eq!(
+            ToneRow::from_pitches(berg.spell(SpellingPolicy::Key(-2))).unwrap(),
+            berg
+        );
+    }
 }
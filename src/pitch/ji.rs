@@ -0,0 +1,173 @@
+pub use fantazia_lib::pitch::ji::*;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_ratio() {
+        use super::{Monzo, Ratio};
+        use crate::pitch::edo12::interval;
+
+        let r = |s: &str| s.parse::<Ratio>().unwrap();
+        assert_eq!(r("6/4"), r("3/2"));
+        assert_eq!(
+            (r("6/4").numer(), r("6/4").denom()),
+            (&3u32.into(), &2u32.into())
+        );
+        assert_eq!(r("7"), Ratio::from(7));
+        assert_eq!(r("81/80").to_string(), "81/80");
+        for s in ["", "0/1", "1/0", "a/2", "3/", "-3/2"] {
+            assert!(s.parse::<Ratio>().is_err(), "{s}");
+        }
+
+        assert!((r("3/2").cents() - 701.955).abs() < 1e-3);
+        assert!((r("81/80").cents() - 21.506).abs() < 1e-3);
+        assert!((r("2/3").cents() + 701.955).abs() < 1e-3);
+        assert!((r("5/4").tenney_height() - 20f64.log2()).abs() < 1e-12);
+
+        // stacking
+        assert_eq!(r("3/2").pow(4) / r("5"), r("81/80"));
+        assert_eq!(r("3/2").pow(-1), r("2/3"));
+        assert_eq!(r("5/4") * r("6/5"), r("3/2"));
+        assert_eq!(
+            [r("9/8"), r("10/9"), r("16/15")]
+                .into_iter()
+                .product::<Ratio>(),
+            r("4/3")
+        );
+        assert!(r("5/4") < r("4/3"));
+        assert!(r("2/3") < r("1"));
+
+        assert_eq!(r("3").octaves(), 1);
+        assert_eq!(r("1/3").octaves(), -2);
+        assert_eq!(r("3").octave_reduced(), r("3/2"));
+        assert_eq!(r("1/3").octave_reduced(), r("4/3"));
+        assert_eq!(r("2").octave_reduced(), r("1"));
+        assert_eq!(r("7/1").octave_reduced(), r("7/4"));
+
+        assert_eq!(r("1").prime_limit(), 1u32);
+        assert_eq!(r("2").prime_limit(), 2u32);
+        assert_eq!(r("81/80").prime_limit(), 5u32);
+        assert_eq!(r("11/8").prime_limit(), 11u32);
+        assert_eq!(r("7/6").prime_limit(), 7u32);
+
+        assert_eq!(r("81/80").monzo(), Monzo::new(vec![-4, 4, -1]));
+        assert_eq!(r("81/80").monzo().to_string(), "[-4 4 -1>");
+        assert_eq!(r("7/4").monzo().to_string(), "[-2 0 0 1>");
+        assert_eq!(r("1").monzo().to_string(), "[>");
+        assert_eq!(r("13/11").monzo().exponent(13), 1);
+        assert_eq!(r("13/11").monzo().exponent(7), 0);
+        assert_eq!(
+            r("13/11")
+                .monzo()
+                .primes()
+                .map(|(p, exp)| format!("{p}^{exp}"))
+                .collect::<Vec<_>>(),
+            ["11^-1", "13^1"]
+        );
+        for s in ["[-4 4 -1>", "[-4, 4, -1⟩", "[ -4 4 -1 0 0 >"] {
+            assert_eq!(s.parse::<Monzo>().unwrap(), r("81/80").monzo());
+        }
+        for ratio in ["81/80", "7/4", "1", "1024/1001", "3/2"] {
+            assert_eq!(Ratio::from_monzo(&r(ratio).monzo()), r(ratio));
+        }
+        assert_eq!(
+            Ratio::from_monzo(&"[64>".parse().unwrap()).to_string(),
+            "18446744073709551616/1"
+        );
+
+        // arbitrary precision
+        let commas = r("81/80").pow(13);
+        assert_eq!(commas.monzo(), Monzo::new(vec![-52, 52, -13]));
+        assert_eq!(&commas / &r("81/80").pow(12), r("81/80"));
+        assert!((commas.cents() - 13.0 * r("81/80").cents()).abs() < 1e-9);
+        assert_eq!(commas.octaves(), 0);
+        let big = r("6461081889226673298932241/18446744073709551616");
+        assert_eq!(big, r("3").pow(52) / r("2").pow(64));
+        assert_eq!(
+            big.to_string(),
+            "6461081889226673298932241/18446744073709551616"
+        );
+        assert_eq!((big.octaves(), big.recip().octaves()), (18, -19));
+
+        // large prime factors
+        assert_eq!(r("100000007/100000000").prime_limit(), 100000007u32);
+        assert_eq!(
+            r("100000007/100000000")
+                .monzo()
+                .primes()
+                .map(|(p, exp)| format!("{p}^{exp}"))
+                .collect::<Vec<_>>(),
+            ["2^-8", "5^-8", "100000007^1"]
+        );
+        assert_eq!(r("100000007/100000000").monzo().exponent(100000007), 1);
+        assert_eq!(
+            Ratio::from_monzo(&r("100000007/100000000").monzo()),
+            r("100000007/100000000")
+        );
+        // spelled from its prime factors, `5^-8` takes it 24 fifths down
+        assert_eq!(
+            r("100000007/100000000").nearest_interval(),
+            Some(interval!("ddd3"))
+        );
+        let factors = |s: &str| {
+            r(s).monzo()
+                .primes()
+                .map(|(p, exp)| format!("{p}^{exp}"))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            r("1000000000000000003").prime_limit(),
+            1000000000000000003u64
+        );
+        // primes beyond `u64` are recognized without searching for factors
+        let mersenne = "170141183460469231731687303715884105727";
+        assert_eq!(r(mersenne).prime_limit().to_string(), mersenne);
+        assert_eq!(factors(mersenne), [format!("{mersenne}^1")]);
+        assert_eq!(
+            factors("243000001701000000729000005103/2"),
+            ["2^-1", "3^5", "1000000007^1", "1000000000000000003^1"]
+        );
+        assert_eq!(
+            factors("6277101735386680758391271552472651001868953346273154433024"),
+            ["2^70", "2305843009213693951^2"]
+        );
+
+        assert!("-4 4 -1>".parse::<Monzo>().is_err());
+        assert!("[-4 x>".parse::<Monzo>().is_err());
+
+        for (ratio, expected) in [
+            ("1", interval!("P1")),
+            ("3/2", interval!("P5")),
+            ("2/3", -interval!("P5")),
+            ("2", interval!("P8")),
+            ("3", interval!("P12")),
+            ("9/8", interval!("M2")),
+            ("5/4", interval!("M3")),
+            ("6/5", interval!("m3")),
+            ("16/15", interval!("m2")),
+            ("25/24", interval!("A1")),
+            ("7/4", interval!("m7")),
+            ("7/5", interval!("d5")),
+            ("11/8", interval!("A4")),
+            ("13/8", interval!("m6")),
+            ("81/80", interval!("P1")),
+            ("256/243", interval!("m2")),
+            ("2187/2048", interval!("A1")),
+        ] {
+            assert_eq!(r(ratio).nearest_interval(), Some(expected), "{ratio}");
+        }
+
+        let up = |ratio: &str| {
+            let edo = r(ratio).nearest_edo_interval::<31>().unwrap();
+            (edo.interval(), edo.ups(), edo.edosteps())
+        };
+        assert_eq!(up("5/4"), (interval!("M3"), 0, 10));
+        assert_eq!(up("7/4"), (interval!("m7"), -1, 25));
+        assert_eq!(up("3/2"), (interval!("P5"), 0, 18));
+        let edo = r("11/8").nearest_edo_interval::<24>().unwrap();
+        assert_eq!(edo.to_string(), "vA4");
+        assert_eq!(edo.edosteps(), 11);
+        let edo = r("7/4").nearest_edo_interval::<12>().unwrap();
+        assert_eq!(edo.to_string(), "m7");
+    }
+}
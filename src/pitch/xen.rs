@@ -6,7 +6,387 @@ mod tests {
     #[test]
     fn test_edo() {
         use super::edo::EDO;
+        // the step count is kept even when the fifth shares a factor with it
+        assert_eq!(EDO::new(35).edo(), 35);
+        assert_eq!(EDO::new(35).fifth_size(), 20);
+        assert_eq!(EDO::new(35).diatonic(), &[0, 5, 10, 15, 20, 25, 30]);
         let edo = EDO::new(5407372813);
         dbg!(edo.diatonic());
     }
+
+    #[test]
+    fn test_edo_pitch() {
+        use super::edo::{EDO, EdoInterval, EdoPitch};
+        use crate::pitch::edo12::{OPitch, Pitch, interval, ointerval, opitch, pitch};
+
+        assert_eq!(EDO::new(12).sharpness(), 1);
+        assert_eq!(EDO::new(19).sharpness(), 1);
+        assert_eq!(EDO::new(22).sharpness(), 3);
+        assert_eq!(EDO::new(31).sharpness(), 2);
+        assert_eq!(EDO::new(7).sharpness(), 0);
+        assert_eq!(EDO::new(24).edo(), 24);
+        assert_eq!(EDO::new(24).fifth_size(), 14);
+
+        // 12-EDO maps every spelled pitch to its semitones
+        for p in [
+            pitch!("C_0"),
+            pitch!("F+_1"),
+            pitch!("B-_-2"),
+            pitch!("E--_0"),
+        ] {
+            assert_eq!(EdoPitch::<12>::from(p).edosteps(), p.tone as i32);
+        }
+
+        let e = EdoPitch::<31>::from(pitch!("E_0"));
+        assert_eq!(e.edosteps(), 10);
+        assert_eq!(EdoPitch::<31>::from(pitch!("F+_0")).edosteps(), 15);
+        assert_eq!(EdoPitch::<31>::from(pitch!("G-_0")).edosteps(), 16);
+        assert_eq!(EdoPitch::<31>::from(pitch!("C_1")).edosteps(), 31);
+        assert_eq!(EdoPitch::<31>::from(opitch!("B")).edosteps(), 28);
+        assert_eq!(EdoPitch::<22>::from(pitch!("A_-1")).edosteps(), 17 - 22);
+        assert!((EdoPitch::<31>::from(pitch!("A_0")).cents() - 1200.0 * 23.0 / 31.0).abs() < 1e-9);
+
+        let up_e = EdoPitch::<22>::new(pitch!("E_0"), 1);
+        assert_eq!(up_e.ups(), 1);
+        assert_eq!(up_e.pitch(), pitch!("E_0"));
+        assert_eq!(up_e.edosteps(), 9);
+        assert!(
+            EdoPitch::<22>::new(pitch!("E_0"), 3)
+                .is_enharmonic(&EdoPitch::<22>::from(pitch!("E+_0")))
+        );
+
+        let down_major_third = EdoInterval::<31>::new(interval!("M3"), -1);
+        assert_eq!(down_major_third.edosteps(), 9);
+        let c = EdoPitch::<31>::from(pitch!("C_0"));
+        assert_eq!(c + down_major_third, EdoPitch::new(pitch!("E_0"), -1));
+        assert_eq!((c + down_major_third) - c, down_major_third);
+        assert_eq!(c + down_major_third - down_major_third, c);
+        assert_eq!(
+            -down_major_third + down_major_third,
+            EdoInterval::from(interval!("P1"))
+        );
+        assert_eq!(
+            EdoInterval::<31>::from(interval!("M2")) * 3,
+            EdoInterval::from(interval!("A4"))
+        );
+        assert_eq!(
+            (EdoInterval::<31>::from(interval!("M2")) * 3).edosteps(),
+            15
+        );
+        let fifths: EdoInterval<19> = [interval!("P5"); 4]
+            .into_iter()
+            .map(EdoInterval::from)
+            .sum();
+        assert_eq!(fifths.interval(), interval!("M17"));
+        assert_eq!(fifths.edosteps(), 44);
+        assert_eq!(
+            EdoPitch::<31>::from(OPitch::from(ointerval!("P5"))).pitch(),
+            Pitch::from(opitch!("G"))
+        );
+    }
+
+    #[test]
+    fn test_ups_and_downs() {
+        use super::edo::{EdoInterval, EdoPitch};
+        use crate::pitch::edo12::{interval, pitch};
+
+        let parse = |s: &str| s.parse::<EdoPitch<22>>().unwrap();
+        assert_eq!(parse("^Eb4"), EdoPitch::new(pitch!("E-_0"), 1));
+        assert_eq!(parse("vvF#"), EdoPitch::new(pitch!("F+_0"), -2));
+        assert_eq!(parse("Bbb-1"), EdoPitch::new(pitch!("B--_-5"), 0));
+        assert_eq!(parse("^Eb4").edosteps(), 5 + 1);
+        for s in ["^Eb4", "vvF#4", "C4", "^^^G##6", "vA2"] {
+            assert_eq!(parse(s).to_string(), s);
+        }
+        for s in ["", "^^", "H4", "^E$4"] {
+            assert!(s.parse::<EdoPitch<22>>().is_err(), "{s}");
+        }
+
+        let parse = |s: &str| s.parse::<EdoInterval<24>>().unwrap();
+        assert_eq!(parse("^m7"), EdoInterval::new(interval!("m7"), 1));
+        assert_eq!(parse("vM3"), EdoInterval::new(interval!("M3"), -1));
+        assert_eq!(parse("~3"), EdoInterval::new(interval!("m3"), 1));
+        assert_eq!(parse("~M3"), parse("~3"));
+        assert_eq!(parse("~m3").edosteps(), 7);
+        assert_eq!(parse("-vP5"), -EdoInterval::new(interval!("P5"), -1));
+        for s in ["vm7", "^^M3", "~3", "~6", "vvP5", "^A4", "-vvM3", "^M10"] {
+            assert_eq!(parse(s).to_string(), s);
+        }
+        assert_eq!(parse("^m3").to_string(), "~3");
+        // the major form with downs keeps its spelling, so that it parses back
+        assert_eq!(parse("vM3").to_string(), "vM3");
+        assert_eq!(parse("vM3").edosteps(), parse("~3").edosteps());
+        assert!("~5".parse::<EdoInterval<24>>().is_err());
+        assert!("~3".parse::<EdoInterval<22>>().is_err());
+        assert!("^P3".parse::<EdoInterval<24>>().is_err());
+        assert!("".parse::<EdoInterval<24>>().is_err());
+
+        // 31-EDO: a sharp is two edosteps, so ^m3 is the mid third
+        let parse = |s: &str| s.parse::<EdoInterval<31>>().unwrap();
+        assert_eq!(parse("^M3").name(), "upmajor 3rd");
+        assert_eq!(parse("vm7").name(), "downminor 7th");
+        assert_eq!(parse("^m3").name(), "mid 3rd");
+        assert_eq!(parse("v5").name(), "down 5th");
+        assert_eq!(parse("P8").name(), "perfect octave");
+        assert_eq!(parse("vvm2").name(), "dudminor 2nd");
+        assert_eq!(parse("^A4").name(), "upaugmented 4th");
+        assert_eq!(parse("dd7").name(), "doubly diminished 7th");
+        assert_eq!(parse("M13").name(), "major 13th");
+        assert_eq!(parse("-M3").name(), "major 3rd");
+        for name in [
+            "upmajor 3rd",
+            "downminor 7th",
+            "mid 3rd",
+            "down 5th",
+            "perfect octave",
+            "dudminor 2nd",
+            "up unison",
+            "trupmajor 6th",
+            "upaugmented 4th",
+            "perfect 11th",
+            "doubly augmented 2nd",
+        ] {
+            assert_eq!(parse(name).name(), name);
+            assert_eq!(EdoInterval::<31>::from_name(name).unwrap(), parse(name));
+        }
+        assert!(EdoInterval::<31>::from_name("mid 5th").is_err());
+        assert!(EdoInterval::<31>::from_name("upmajor").is_err());
+        assert!(EdoInterval::<31>::from_name("sideways 3rd").is_err());
+
+        // 22-EDO has no mid intervals, so every count of ups keeps its quality
+        assert_eq!(
+            EdoInterval::<22>::new(interval!("M3"), 4).name(),
+            "4-up major 3rd"
+        );
+        assert_eq!(
+            EdoInterval::<22>::new(interval!("P5"), -5).name(),
+            "5-down 5th"
+        );
+        for base in ["P1", "P5", "M3", "m7", "A4", "P8"] {
+            for ups in (-10..=10).chain([i8::MIN, i8::MAX]) {
+                let interval = EdoInterval::<22>::new(base.parse().unwrap(), ups);
+                let name = interval.name();
+                assert_eq!(
+                    EdoInterval::<22>::from_name(&name).unwrap(),
+                    interval,
+                    "{name}"
+                );
+            }
+        }
+        assert!(EdoInterval::<22>::from_name("4-sideways 3rd").is_err());
+        assert!(EdoInterval::<22>::from_name("200-up 3rd").is_err());
+        assert_eq!(
+            EdoInterval::<22>::from_name("AAA 4th").unwrap(),
+            EdoInterval::from(interval!("AAA4"))
+        );
+
+        // every interval round-trips through its symbol and its name
+        fn round_trip<const N: u16>() {
+            let quals = ["ddd", "dd", "d", "m", "M", "P", "A", "AA", "AAA"];
+            for deg in 1..=15 {
+                for qual in quals {
+                    let Ok(base) = format!("{qual}{deg}").parse() else {
+                        continue;
+                    };
+                    for ups in -4..=4 {
+                        for interval in [
+                            EdoInterval::<N>::new(base, ups),
+                            -EdoInterval::new(base, ups),
+                        ] {
+                            let s = interval.to_string();
+                            assert_eq!(s.parse::<EdoInterval<N>>().unwrap(), interval, "{s}");
+                            let name = interval.name();
+                            let named = EdoInterval::<N>::from_name(&name).unwrap();
+                            assert!(named == interval || named == -interval, "{name}");
+                        }
+                    }
+                }
+            }
+        }
+        round_trip::<24>();
+        round_trip::<34>();
+    }
+
+    #[test]
+    fn test_scala() {
+        use super::edo::EDO;
+        use super::scala::{KeyboardMapping, ScalaPitch, ScalaScale, Tuning};
+        use crate::pitch::{edo12::pitch, ji::Ratio};
+
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+
+        let meantone = "! meantone.scl\n\
+            !\n\
+            1/4-comma meantone, 4 notes\n \
+            4\n\
+            !\n \
+            193.157\n \
+            5/4   major third\n\
+            \n \
+            696.578\n \
+            2\n";
+        let scale: ScalaScale = meantone.parse().unwrap();
+        assert_eq!(scale.description(), "1/4-comma meantone, 4 notes");
+        assert_eq!(scale.len(), 4);
+        assert_eq!(
+            scale.pitches()[1],
+            ScalaPitch::Ratio(Ratio::new(5, 4).unwrap())
+        );
+        assert!(close(scale.period_cents(), 1200.0));
+        assert!(close(scale.degree_cents(0), 0.0));
+        assert!(close(scale.degree_cents(5), 1393.157));
+        assert!(close(scale.degree_cents(-1), 696.578 - 1200.0));
+        assert_eq!(scale.to_string().parse::<ScalaScale>().unwrap(), scale);
+        for scl in [
+            "",
+            "desc",
+            "desc\nfour\n",
+            "desc\n2\n100.0\n",
+            "desc\n1\n1.2.3\n",
+            "desc\n1\n3/0\n",
+            "desc\n1\n-3/2\n",
+        ] {
+            assert!(scl.parse::<ScalaScale>().is_err(), "{scl:?}");
+        }
+
+        let edo: ScalaScale = EDO::new(12).into();
+        assert_eq!(edo.len(), 12);
+        assert_eq!(edo.pitches()[0], ScalaPitch::Cents(100.0));
+        assert_eq!(edo.pitches()[11], ScalaPitch::Ratio(Ratio::OCTAVE));
+        assert!(edo.to_string().contains("\n 100.0\n"));
+        assert!(edo.to_string().ends_with("\n 2/1\n"));
+        assert_eq!(edo.to_string().parse::<ScalaScale>().unwrap(), edo);
+        let edo31 = ScalaScale::from(&EDO::new(31));
+        assert_eq!(edo31.to_string().parse::<ScalaScale>().unwrap(), edo31);
+
+        let tuning = Tuning::from(edo);
+        assert!(close(tuning.key_freq(69).unwrap(), 440.0));
+        assert!(close(tuning.key_freq(81).unwrap(), 880.0));
+        assert!(close(tuning.key_freq(60).unwrap(), 261.625_565_300_598_6));
+        assert!(close(tuning.freq(pitch!("A_0")).unwrap(), 440.0));
+        assert!(close(tuning.freq(pitch!("A_-1")).unwrap(), 220.0));
+        assert_eq!(tuning.freq(pitch!("C_6")), None);
+        assert!(tuning.table().iter().all(Option::is_some));
+
+        let tuning = Tuning::new(
+            EDO::new(12).into(),
+            KeyboardMapping::default().with_range(60, 72),
+        );
+        assert_eq!(tuning.key_freq(59), None);
+        assert!(tuning.key_freq(72).is_some());
+        assert_eq!(tuning.table().iter().flatten().count(), 13);
+
+        // JI major scale on the white keys
+        let major = "Ptolemy's intense diatonic\n7\n9/8\n5/4\n4/3\n3/2\n5/3\n15/8\n2/1\n";
+        let kbm = "! white keys\n12\n0\n127\n60\n69\n440.0\n7\n\
+            0\nx\n1\nx\n2\n3\nx\n4\nx\n5\nx\n6\n";
+        let mapping: KeyboardMapping = kbm.parse().unwrap();
+        assert_eq!(mapping.size(), 12);
+        assert_eq!(mapping.octave_degree(), 7);
+        assert_eq!(mapping.keys()[1], None);
+        assert_eq!(
+            mapping.to_string().parse::<KeyboardMapping>().unwrap(),
+            mapping
+        );
+        let tuning = Tuning::new(major.parse().unwrap(), mapping);
+        assert!(close(tuning.key_freq(69).unwrap(), 440.0));
+        assert!(close(tuning.key_freq(60).unwrap(), 264.0));
+        assert!(close(tuning.key_freq(71).unwrap(), 495.0));
+        assert!(close(tuning.key_freq(72).unwrap(), 528.0));
+        assert!(close(tuning.key_freq(48).unwrap(), 132.0));
+        assert!(close(tuning.freq(pitch!("F_1")).unwrap(), 704.0));
+        assert_eq!(tuning.key_freq(61), None);
+        assert_eq!(tuning.freq(pitch!("E-_0")), None);
+        assert_eq!(tuning.table().iter().flatten().count(), 75);
+
+        for kbm in [
+            "",
+            "12\n0\n127\n60\n69\n",
+            "2\n0\n127\n60\n69\n440.0\n2\n0\n1\n1\n",
+            "0\n0\n128\n60\n69\n440.0\n0\n",
+            "1\n0\n127\n60\n69\n440.0\n1\ny\n",
+            "0\n0\n127\n60\n69\nA4\n0\n",
+        ] {
+            assert!(kbm.parse::<KeyboardMapping>().is_err(), "{kbm:?}");
+        }
+    }
+
+    #[test]
+    fn test_temperament() {
+        use super::edo::{EDO, EdoPitch};
+        use super::temperament::Temperament;
+        use crate::pitch::{
+            edo12::{ConcertPitch, interval, opitch, pitch},
+            ji::Ratio,
+        };
+
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        let ratio = |s: &str| s.parse::<Ratio>().unwrap();
+        let pyth = Temperament::PYTHAGOREAN;
+        let qcm = Temperament::QUARTER_COMMA_MEANTONE;
+
+        assert!(close(pyth.generator(), ratio("3/2").cents()));
+        assert!(close(
+            Temperament::from_generator_ratio(ratio("3/2")).generator(),
+            pyth.generator()
+        ));
+        assert!(close(
+            Temperament::meantone(0.25).generator(),
+            qcm.generator()
+        ));
+        assert!(close(
+            pyth.interval_cents(interval!("M3")),
+            ratio("81/64").cents()
+        ));
+        assert!(close(
+            pyth.deviation(interval!("M3"), ratio("5/4")),
+            ratio("81/80").cents()
+        ));
+        assert!(close(qcm.deviation(interval!("M3"), ratio("5/4")), 0.0));
+        assert!(close(qcm.deviation(interval!("M17"), ratio("5")), 0.0));
+        assert!(close(
+            pyth.compare(&qcm, interval!("P5")),
+            ratio("81/80").cents() / 4.0
+        ));
+        assert!(close(
+            pyth.compare(&qcm, interval!("-M3")),
+            -ratio("81/80").cents()
+        ));
+        assert!(close(pyth.sharpness(), ratio("2187/2048").cents()));
+
+        // sharps are below their enharmonic flats in meantone, and above them in Pythagorean
+        assert!(qcm.cents(pitch!("G+_0")) < qcm.cents(pitch!("A-_0")));
+        assert!(pyth.cents(pitch!("G+_0")) > pyth.cents(pitch!("A-_0")));
+        assert!(close(qcm.ocents(opitch!("E")), ratio("5/4").cents()));
+
+        // Helmholtz's schismatic temperament has a pure 5/4 as diminished fourth
+        let helmholtz = Temperament::schismatic(1.0 / 8.0);
+        assert!(close(
+            helmholtz.deviation(interval!("d4"), ratio("5/4")),
+            0.0
+        ));
+
+        let edo12 = Temperament::from(EDO::new(12));
+        let edo31 = Temperament::from(&EDO::new(31));
+        for p in [
+            pitch!("C_0"),
+            pitch!("E_0"),
+            pitch!("F+_1"),
+            pitch!("B-_-2"),
+            pitch!("E--_3"),
+            pitch!("G++_-1"),
+        ] {
+            assert!(close(edo12.cents(p), p.tone as f64 * 100.0));
+            assert!(close(edo31.cents(p), EdoPitch::<31>::from(p).cents()));
+        }
+
+        let a440 = ConcertPitch::default();
+        assert!(close(qcm.freq(pitch!("A_0"), &a440), 440.0));
+        assert!(close(qcm.freq(pitch!("A_1"), &a440), 880.0));
+        assert!((qcm.freq(pitch!("C_0"), &a440) - 263.181_385).abs() < 1e-6);
+        assert!(close(
+            edo12.freq(pitch!("C_0"), &a440),
+            pitch!("C_0").freq(&a440)
+        ));
+    }
 }
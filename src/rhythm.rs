@@ -24,4 +24,426 @@ mod tests {
                 dbg!(d);
             });
     }
+
+    #[test]
+    fn test_duration() {
+        use super::{BinaryDuration, Duration};
+
+        let d = |s: &str| s.parse::<Duration>().unwrap();
+        let b = |s: &str| s.parse::<BinaryDuration>().unwrap();
+        assert_eq!(d("2/8"), d("1/4"));
+        assert_eq!((d("6/16").numer(), d("6/16").denom()), (3, 8));
+        assert_eq!(d("3/8").to_string(), "3/8");
+        assert_eq!(d("4/2").to_string(), "2");
+        assert_eq!(d("0/5"), d("0"));
+        assert!(d("0").is_zero());
+        for s in ["", "1/0", "a/4", "-1/4", "1/"] {
+            assert!(s.parse::<Duration>().is_err(), "{s}");
+        }
+
+        for (note, expected) in [
+            ("1", "1"),
+            ("4", "1/4"),
+            ("4.", "3/8"),
+            ("4..", "7/16"),
+            ("2...", "15/16"),
+            ("16", "1/16"),
+            ("/2", "2"),
+            ("/2.", "3"),
+            ("/4..", "7"),
+            ("1024", "1/1024"),
+        ] {
+            assert_eq!(Duration::try_from(b(note)).unwrap(), d(expected), "{note}");
+            assert_eq!(BinaryDuration::try_from(d(expected)).unwrap(), b(note));
+        }
+        for s in ["0", "5/8", "1/3", "5", "9/16"] {
+            assert!(BinaryDuration::try_from(d(s)).is_err(), "{s}");
+        }
+        // note values are named by the reciprocal of their length
+        assert_eq!(Duration::try_from(b("1/4")).unwrap(), d("4"));
+        assert_eq!(Duration::try_from(b("2")).unwrap(), d("1/2"));
+        assert!(Duration::try_from(BinaryDuration::new(2, 64)).is_err());
+        assert!(Duration::try_from(BinaryDuration::new(-70, 0)).is_err());
+
+        assert_eq!(d("1/4") + d("1/8"), d("3/8"));
+        assert_eq!(d("1/4") - d("1/6"), d("1/12"));
+        assert_eq!(d("3/8") * 2, d("3/4"));
+        assert_eq!(3 * d("1/12"), d("1/4"));
+        assert_eq!(d("1/4") / 3, d("1/12"));
+        let mut sum = d("1/2");
+        sum += d("1/4");
+        sum -= d("1/8");
+        assert_eq!(sum, d("5/8"));
+        let measure: Duration = "8. 16 4 4 4"
+            .split(' ')
+            .map(|s| Duration::try_from(b(s)).unwrap())
+            .sum();
+        assert_eq!(measure, d("1"));
+
+        let max = Duration::new(u64::MAX, 1).unwrap();
+        assert_eq!(d("1/4").checked_add(d("1/8")), Some(d("3/8")));
+        assert_eq!(max.checked_add(d("1/2")), None);
+        let max_value = BinaryDuration::try_from(max).unwrap();
+        assert_eq!(Duration::try_from(max_value).unwrap(), max);
+        assert_eq!(d("1/4").checked_sub(d("1/6")), Some(d("1/12")));
+        assert_eq!(d("1/8").checked_sub(d("1/4")), None);
+        assert_eq!(d("3/8").checked_mul(2), Some(d("3/4")));
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(d("1/4").checked_div(3), Some(d("1/12")));
+        assert_eq!(d("1/4").checked_div(0), None);
+        assert_eq!(Duration::checked_sum([d("1/2"), d("1/4")]), Some(d("3/4")));
+        assert_eq!(Duration::checked_sum([max, d("1")]), None);
+
+        assert!(d("3/8") > d("1/4"));
+        assert!(d("1/3") < d("3/8"));
+        assert_eq!(d("2/6").cmp(&d("1/3")), std::cmp::Ordering::Equal);
+        let mut durations = vec![d("1/2"), d("1/16"), d("3/8"), d("1")];
+        durations.sort();
+        assert_eq!(durations, [d("1/16"), d("3/8"), d("1/2"), d("1")]);
+    }
+
+    #[test]
+    fn test_tuplet() {
+        use super::{BinaryDuration, Duration, NoteValue, Tuplet};
+
+        let d = |s: &str| s.parse::<Duration>().unwrap();
+        let b = |s: &str| s.parse::<BinaryDuration>().unwrap();
+        let n = |s: &str| s.parse::<NoteValue>().unwrap();
+
+        assert_eq!(n("8"), NoteValue::Binary(b("8")));
+        assert_eq!(n("4.").duration(), d("3/8"));
+        assert_eq!(n("8*2/3").duration(), d("1/12"));
+        assert_eq!(n("4.*2/3").duration(), d("1/4"));
+        assert_eq!(n("16*2/3*4/5").duration(), d("1/30"));
+        assert_eq!(n("1/8*2/3").duration(), d("16/3"));
+        for s in ["8", "4..", "8*2/3", "16*2/3*4/5", "/2*3/2"] {
+            assert_eq!(n(s).to_string(), s);
+        }
+
+        let triplet = n("8*2/3");
+        let tuplet = triplet.tuplet().unwrap();
+        assert_eq!(triplet.value(), b("8"));
+        assert_eq!((tuplet.actual(), tuplet.normal()), (3, 2));
+        assert_eq!(tuplet.unit(), Some(b("8")));
+        assert_eq!(tuplet.span(), Some(d("1/4")));
+        assert_eq!(tuplet.to_string(), "3:2");
+        assert_eq!(tuplet.outer(), None);
+        let nested = n("16*2/3*4/5");
+        let inner = nested.tuplet().unwrap();
+        assert_eq!(inner.span(), Some(d("1/10")));
+        assert_eq!(inner.outer().unwrap().to_string(), "5:4");
+        assert_eq!(inner.outer().unwrap().unit(), None);
+
+        let quintuplet = Tuplet::new(5, 4).unwrap().with_unit(b("16"));
+        assert_eq!(quintuplet.span(), Some(d("1/4")));
+        let nested = quintuplet.nested_in(Tuplet::new(3, 2).unwrap());
+        assert_eq!(nested.span(), Some(d("1/6")));
+        assert_eq!(nested.scale(d("1/16")), d("1/30"));
+        assert!(Tuplet::new(0, 2).is_err());
+        assert!("3:0".parse::<Tuplet>().is_err());
+        assert_eq!("7:4".parse::<Tuplet>().unwrap().to_string(), "7:4");
+
+        for s in ["", "8*2", "8*0/3", "x*2/3", "8*2/3/4", "3", "8*a/3"] {
+            assert!(s.parse::<NoteValue>().is_err(), "{s}");
+        }
+        // 3^41 does not fit into a `u64` denominator
+        assert!(
+            format!("8{}", "*2/3".repeat(41))
+                .parse::<NoteValue>()
+                .is_err()
+        );
+        assert!(NoteValue::parse_many("1000003:1[1000033:1[1000037:1[1000039:1[1]]]]").is_err());
+
+        let values = NoteValue::parse_many("4 3:2[8 8 8] 4").unwrap();
+        assert_eq!(values.len(), 5);
+        assert_eq!(
+            values.iter().map(Duration::from).sum::<Duration>(),
+            d("3/4")
+        );
+        assert_eq!(values[1], values[2]);
+        assert_eq!(values[1].tuplet().unwrap().unit(), Some(b("8")));
+        assert_eq!(values[1].tuplet().unwrap().span(), Some(d("1/4")));
+        assert_eq!(values[4], n("4"));
+        let values = NoteValue::parse_many("3:2[8 4]").unwrap();
+        assert_eq!(values[0], n("8*2/3"));
+        assert_eq!(values[1].to_string(), "4*2/3");
+        assert_eq!(values[1].tuplet().unwrap().unit(), Some(b("8")));
+        // the unit is not part of the written form, so it does not take part in comparisons
+        let values = NoteValue::parse_many("3:2[4 8]").unwrap();
+        let written = values.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(written, ["4*2/3", "8*2/3"]);
+        let reparsed = NoteValue::parse_many(&written.join(" ")).unwrap();
+        assert_eq!(reparsed, values);
+        assert_eq!(values[0].tuplet().unwrap().unit(), Some(b("8")));
+        assert_eq!(reparsed[0].tuplet().unwrap().unit(), Some(b("4")));
+
+        let values = NoteValue::parse_many("3:2[8 5:4[16 16 16 16 16]]").unwrap();
+        assert_eq!(values[0].tuplet().unwrap().unit(), Some(b("8")));
+        assert_eq!(values[1].tuplet().unwrap().unit(), Some(b("16")));
+        assert_eq!(
+            values[1].tuplet().unwrap().outer().unwrap().unit(),
+            Some(b("8"))
+        );
+
+        let values = NoteValue::parse_many("3:2[8 5:4[32 32 32 32 32] 8] 4.").unwrap();
+        assert_eq!(values.len(), 8);
+        assert_eq!(values[1].to_string(), "32*4/5*2/3");
+        assert_eq!(values[1].duration(), d("1/60"));
+        assert_eq!(values[1].tuplet().unwrap().unit(), Some(b("32")));
+        assert_eq!(values[0].tuplet().unwrap().unit(), Some(b("8")));
+        assert_eq!(
+            values.iter().map(Duration::from).sum::<Duration>(),
+            d("5/8")
+        );
+
+        let values = NoteValue::parse_many("3:2[8 8]").unwrap();
+        assert_eq!(values[0].tuplet().unwrap().unit(), None);
+        assert_eq!(values[0].tuplet().unwrap().span(), None);
+
+        assert!(NoteValue::parse_many("").unwrap().is_empty());
+        for s in ["3:2[8 8 8", "8 8]", "3:2[]", "3:0[8]", "3-2[8]", "3:2[8 x]"] {
+            assert!(NoteValue::parse_many(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_time_sig() {
+        use super::{Duration, Meter, SingleTimeSig, TimeSig, TimeSigNum};
+
+        let d = |s: &str| s.parse::<Duration>().unwrap();
+        let ts = |s: &str| s.parse::<TimeSig>().unwrap();
+        let single = |s: &str| s.parse::<SingleTimeSig>().unwrap();
+
+        for s in [
+            "4/4",
+            "3+2+2/8",
+            "C",
+            "C|",
+            "2/4+3/8",
+            "3+2/8+3/4",
+            "3/4 2/4",
+            "3/4(6/8)",
+            "X",
+            "6/8",
+        ] {
+            assert_eq!(ts(s).to_string(), s);
+        }
+        assert_eq!(ts(" 4/4 "), ts("4/4"));
+        assert_eq!(ts("¢").to_string(), "C|");
+        assert_eq!(ts("3/4 (6/8)"), ts("3/4(6/8)"));
+        assert!(matches!(ts("2/4+3/8"), TimeSig::Aggregate(_)));
+        assert!(matches!(ts("3+2+2/8"), TimeSig::Simple(_)));
+        assert!(matches!(ts("3/4 2/4"), TimeSig::Alternating(_)));
+        assert!(matches!(ts("X"), TimeSig::Open(_)));
+        assert_eq!(TimeSig::default(), ts("4/4"));
+        for s in [
+            "",
+            "4",
+            "4/0",
+            "0/4",
+            "a/4",
+            "4/4/4",
+            "3+/8",
+            "3/4(6/8",
+            "C||",
+            "256/4",
+            "200+100/4",
+            "255+1/8",
+            "4/3",
+            "3/12",
+            "2/4+3/6",
+        ] {
+            assert!(s.parse::<TimeSig>().is_err(), "{s}");
+        }
+        assert!(SingleTimeSig::new_simple(4, 0, None).is_err());
+        assert!(SingleTimeSig::new_simple(0, 4, None).is_err());
+        assert!(SingleTimeSig::new_additive([3, 2].into_iter(), 3, None).is_err());
+        assert!(SingleTimeSig::new_additive([3, 0].into_iter(), 8, None).is_err());
+        assert!(SingleTimeSig::new_additive(std::iter::empty(), 8, None).is_err());
+        assert_eq!(ts("254+1/8").measure_len(0), Some(d("255/8")));
+
+        let c = single("C");
+        assert_eq!(c.num_ref(), &TimeSigNum::Simple(4));
+        assert_eq!(c.den(), 4);
+        assert_eq!(c.sym_ref().map(|sym| sym.as_str()), Some("C"));
+        assert_eq!(single("C|").measure_len(), d("1"));
+        let additive = single("3+2+2/8");
+        assert_eq!(additive.num_ref().total(), 7);
+        assert_eq!(additive.to_simple().to_string(), "7/8");
+        assert_eq!(additive.clone().into_simple(), single("7/8"));
+
+        for (sig, len) in [
+            ("4/4", "1"),
+            ("3/4", "3/4"),
+            ("6/8", "3/4"),
+            ("3+2+2/8", "7/8"),
+            ("C|", "1"),
+            ("2/4+3/8", "7/8"),
+            ("5/16", "5/16"),
+        ] {
+            assert_eq!(ts(sig).measure_len(0), Some(d(len)), "{sig}");
+        }
+        assert_eq!(ts("X").measure_len(0), None);
+        assert_eq!(ts("3/4 2/4").measure_len(0), Some(d("3/4")));
+        assert_eq!(ts("3/4 2/4").measure_len(1), Some(d("1/2")));
+        assert_eq!(ts("3/4 2/4").measure_len(4), Some(d("3/4")));
+        assert_eq!(ts("3/4(6/8)").measure_len(3), Some(d("3/4")));
+
+        for (sig, meter, groups) in [
+            ("2/4", Meter::Simple, vec![1, 1]),
+            ("3/8", Meter::Simple, vec![1, 1, 1]),
+            ("4/4", Meter::Simple, vec![1, 1, 1, 1]),
+            ("5/4", Meter::Simple, vec![1, 1, 1, 1, 1]),
+            ("6/8", Meter::Compound, vec![3, 3]),
+            ("9/8", Meter::Compound, vec![3, 3, 3]),
+            ("12/16", Meter::Compound, vec![3, 3, 3, 3]),
+            ("6/4", Meter::Compound, vec![3, 3]),
+            ("5/8", Meter::Irregular, vec![2, 3]),
+            ("7/8", Meter::Irregular, vec![2, 2, 3]),
+            ("3+2+2/8", Meter::Irregular, vec![3, 2, 2]),
+        ] {
+            let sig = single(sig);
+            assert_eq!(sig.meter(), meter, "{sig}");
+            assert_eq!(sig.beat_groups(), groups, "{sig}");
+            assert_eq!(sig.beats().into_iter().sum::<Duration>(), sig.measure_len());
+        }
+        assert_eq!(ts("6/8").beats(0), [d("3/8"), d("3/8")]);
+        assert_eq!(
+            ts("2/4+3/8").beats(0),
+            [d("1/4"), d("1/4"), d("1/8"), d("1/8"), d("1/8")]
+        );
+        assert_eq!(ts("3/4 6/8").beats(1), [d("3/8"), d("3/8")]);
+        assert!(ts("X").beats(0).is_empty());
+    }
+
+    #[test]
+    fn test_rhythm_seq() {
+        use super::{Duration, NoteValue, RhythmEvent, RhythmSeq, TimeSig};
+
+        let d = |s: &str| s.parse::<Duration>().unwrap();
+        let seq = |s: &str| s.parse::<RhythmSeq>().unwrap();
+
+        let rhythm =
+            seq("{3/4} 8. 16 | 4 4 4 | 2 8. 16 | 4 4 4 | 2 8. 16 | 4 4 4 | 4 4 8. 16 | 4 4 4 | 2.");
+        assert_eq!(rhythm.measures().len(), 9);
+        assert_eq!(rhythm.events().count(), 25);
+        assert_eq!(rhythm.duration(), d("25/4"));
+        assert_eq!(
+            rhythm.time_sig_at(8),
+            Some(&"3/4".parse::<TimeSig>().unwrap())
+        );
+        let mismatches = rhythm.mismatches();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].measure, 0);
+        assert_eq!(mismatches[0].expected, d("3/4"));
+        assert_eq!(mismatches[0].actual, d("1/4"));
+        assert!(mismatches[0].is_underfilled());
+
+        let rhythm = seq("{4/4} 2 r8 8 3:2[8 8 8~] | 8 4. 2 8 || {6/8} 4. r4 8~ | 2. |");
+        assert_eq!(rhythm.measures().len(), 4);
+        let events: Vec<&RhythmEvent> = rhythm.events().collect();
+        assert!(events[1].is_rest());
+        assert!(events[5].is_tied());
+        assert_eq!(events[5].value(), &"8*2/3".parse::<NoteValue>().unwrap());
+        assert_eq!(events[4].duration(), d("1/12"));
+        assert!(rhythm.measures()[2].time_sig().is_some());
+        assert_eq!(rhythm.expected_len(3), Some(d("3/4")));
+        let mismatches = rhythm.mismatches();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].measure, 1);
+        assert_eq!(mismatches[0].actual, d("9/8"));
+        assert!(mismatches[0].is_overfilled());
+        assert_eq!(
+            rhythm.to_string(),
+            "{4/4} 2 r8 8 8*2/3 8*2/3 8*2/3~ | 8 4. 2 8 | {6/8} 4. r4 8~ | 2."
+        );
+        assert_eq!(seq(&rhythm.to_string()), rhythm);
+        // a run of barlines is a single barline, even with whitespace in between
+        assert_eq!(seq("4 4 | | 4"), seq("4 4 || 4"));
+        assert_eq!(seq("4 4 | | 4").measures().len(), 2);
+        assert_eq!(seq("4 4 |\n| 4 |  |").to_string(), "4 4 | 4");
+        // an opening barline does not start an empty measure
+        assert_eq!(seq("| 4 4 4 4 |"), seq("4 4 4 4"));
+        assert_eq!(seq("| 4 4 4 4 |").to_string(), "4 4 4 4");
+        assert_eq!(seq("{4/4} | 4 4 4 4 |"), seq("{4/4} 4 4 4 4"));
+        assert!(seq("{4/4} | 4 4 4 4 |").mismatches().is_empty());
+        assert!(seq("").measures().is_empty());
+        assert!(seq(" | ").measures().is_empty());
+        assert_eq!(seq("").to_string(), "");
+
+        assert_eq!(seq("{3/4 2/4} 2. | 2 | 4 4 4").mismatches().len(), 0);
+        assert_eq!(seq("{X} 4 4 4 | 1").mismatches().len(), 0);
+        assert_eq!(seq("4 4 4 | 1").mismatches().len(), 0);
+        assert_eq!(seq("{C|} 1 |").measures().len(), 1);
+
+        for s in [
+            "4 r4~ 4",
+            "4 4~ | r2",
+            "4 4~",
+            "{3/4 4 4 4",
+            "4 {3/4} 4",
+            "4 3:2[8 8 | 8]",
+            "4 q",
+            "{3/0} 4",
+            // the measure adds up to a fraction over the product of the four primes
+            "1000003:1[1] 1000033:1[1] 1000037:1[1] 1000039:1[1]",
+        ] {
+            assert!(s.parse::<RhythmSeq>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_split_and_beams() {
+        use super::{Duration, RhythmSeq, TimeSig};
+
+        let d = |s: &str| s.parse::<Duration>().unwrap();
+        let ts = |s: &str| s.parse::<TimeSig>().unwrap();
+        let seq = |s: &str| s.parse::<RhythmSeq>().unwrap();
+        let split = |sig: &str, durations: &[&str]| {
+            ts(sig)
+                .split_durations(durations.iter().map(|s| d(s)))
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(split("4/4", &["1/4", "1/2", "1/4"]), "{4/4} 4 4~ 4 4");
+        assert_eq!(split("4/4", &["1/2", "1/2"]), "{4/4} 2 2");
+        assert_eq!(split("4/4", &["3/4", "1/4"]), "{4/4} 2. 4");
+        assert_eq!(split("4/4", &["1/4", "3/4"]), "{4/4} 4 4~ 2");
+        assert_eq!(split("3/4", &["1/4", "1/2"]), "{3/4} 4 2");
+        assert_eq!(split("4/4", &["3/4", "1"]), "{4/4} 2. 4~ | 2.");
+        assert_eq!(split("6/8", &["1/8", "1/4", "3/8"]), "{6/8} 8 4 4.");
+        assert_eq!(
+            split("4/4", &["1/8", "1/4", "1/4", "1/4", "1/8"]),
+            "{4/4} 8 8~ 8 8~ 8 8~ 8 8"
+        );
+        assert_eq!(split("4/4", &["5/8", "3/8"]), "{4/4} 2~ 8 8~ 4");
+        assert_eq!(split("2/4", &["3/16", "5/16"]), "{2/4} 8. 16~ 4");
+        assert_eq!(split("3/4 2/4", &["1", "1/4"]), "{3/4 2/4} 2.~ | 4 4");
+        assert_eq!(split("X", &["5/4"]), "{X} 1~ 4");
+        assert!(ts("4/4").split_durations([d("1/12")]).is_err());
+        // a zero-length note cannot be notated rather than silently disappearing
+        assert!(ts("4/4").split_durations([d("0"), d("1/4")]).is_err());
+        // the second note would end at `(2^66 - 3) / 2^62`
+        let long = Duration::new(u64::MAX, 1 << 60).unwrap();
+        let err = ts("X")
+            .split_durations([long, d("1/4611686018427387904")])
+            .unwrap_err();
+        assert!(err.to_string().contains("does not fit"), "{err}");
+
+        let notated = ts("4/4")
+            .split_events(seq("4 r2 4~ 4 2.").events())
+            .unwrap();
+        assert_eq!(notated.to_string(), "{4/4} 4 r4 r4 4~ | 4 4~ 2");
+        assert!(notated.mismatches().is_empty());
+
+        let beamed = seq("{4/4} 8 8 16 16 8 4 r8 8 | 8 8 8. 16 4.");
+        assert_eq!(beamed.beams(0), [0..2, 2..5]);
+        assert_eq!(beamed.beams(1), [0..2, 2..4]);
+        assert_eq!(seq("{6/8} 8 8 8 8 8 8").beams(0), [0..3, 3..6]);
+        assert_eq!(seq("{3/4} 3:2[8 8 8] 4 8 8").beams(0), [0..3, 4..6]);
+        assert!(seq("8 8 8 8").beams(0).is_empty());
+        assert!(seq("{X} 8 8 8 8").beams(0).is_empty());
+        assert!(beamed.beams(2).is_empty());
+    }
 }